@@ -26,10 +26,24 @@ pub trait ComputeAllocator<F, Mem: ComputeMemory<F>> {
 	fn subscope_allocator(&mut self) -> impl ComputeAllocator<F, Mem>;
 }
 
+/// A source of additional buffers for a [`BumpAllocator`] whose current buffer is exhausted.
+pub trait ChunkSource<'a, F, Mem: ComputeMemory<F>>: Sync {
+	/// Returns a new buffer with at least `min_len` elements, or `None` if no more memory is
+	/// available.
+	///
+	/// The returned buffer borrows the source for `'a`, so it cannot outlive the source, and it
+	/// must not overlap with any buffer returned by a previous call.
+	fn next_chunk(&'a self, min_len: usize) -> Option<Mem::FSliceMut<'a>>;
+}
+
 /// Basic bump allocator that allocates slices from an underlying memory buffer provided at
 /// construction.
+///
+/// If the allocator is constructed with a [`ChunkSource`], it requests a new buffer from the source
+/// whenever the current one is too small for an allocation, instead of failing.
 pub struct BumpAllocator<'a, F, Mem: ComputeMemory<F>> {
 	buffer: Mutex<Option<Mem::FSliceMut<'a>>>,
+	chunk_source: Option<&'a dyn ChunkSource<'a, F, Mem>>,
 }
 
 impl<'a, F, Mem> BumpAllocator<'a, F, Mem>
@@ -40,6 +54,21 @@ where
 	pub fn new(buffer: Mem::FSliceMut<'a>) -> Self {
 		Self {
 			buffer: Mutex::new(Some(buffer)),
+			chunk_source: None,
+		}
+	}
+
+	/// Creates an allocator that grows by requesting new buffers from `chunk_source`.
+	///
+	/// Slices handed out from previous buffers stay valid when the allocator moves on to a new
+	/// buffer. The unallocated remainder of the previous buffer is abandoned.
+	pub fn with_chunk_source(
+		buffer: Mem::FSliceMut<'a>,
+		chunk_source: &'a dyn ChunkSource<'a, F, Mem>,
+	) -> Self {
+		Self {
+			buffer: Mutex::new(Some(buffer)),
+			chunk_source: Some(chunk_source),
 		}
 	}
 
@@ -47,6 +76,7 @@ where
 		let buffer = Mem::slice_mut(buffer, ..);
 		BumpAllocator {
 			buffer: Mutex::new(Some(buffer)),
+			chunk_source: None,
 		}
 	}
 
@@ -68,10 +98,18 @@ where
 	fn alloc(&self, n: usize) -> Result<Mem::FSliceMut<'_>, Error> {
 		let mut buffer_lock = self.buffer.lock().expect("mutex is always available");
 
-		let buffer = buffer_lock
+		let mut buffer = buffer_lock
 			.take()
 			.expect("buffer is always Some by invariant");
 		// buffer temporarily contains None
+		if buffer.len() < n {
+			if let Some(chunk) = self
+				.chunk_source
+				.and_then(|source| source.next_chunk(n.max(Mem::ALIGNMENT)))
+			{
+				buffer = chunk;
+			}
+		}
 		if buffer.len() < n {
 			*buffer_lock = Some(buffer);
 			// buffer contains Some, invariant restored
@@ -99,6 +137,7 @@ where
 			.len()
 	}
 
+	/// The subscope allocator is limited to the remainder of the current buffer and does not grow.
 	fn subscope_allocator(&mut self) -> impl ComputeAllocator<F, Mem> {
 		BumpAllocator::<F, Mem>::new(self.remaining())
 	}
@@ -1,21 +1,26 @@
 // Copyright 2025 Irreducible Inc.
 
-use std::fmt::Debug;
+use std::{fmt::Debug, slice, sync::Mutex};
 
 use bytemuck::zeroed_vec;
 
-use crate::alloc::HostBumpAllocator;
+use super::CpuMemory;
+use crate::alloc::{ChunkSource, HostBumpAllocator};
 
 pub struct CpuComputeAllocator<F> {
 	data: Vec<F>,
+	chunks: Option<ChunkArena<F>>,
 }
 
 impl<F> CpuComputeAllocator<F>
 where
-	F: Sync + Debug + Send + 'static,
+	F: Sync + Debug + Send + 'static + bytemuck::Zeroable,
 {
 	pub fn into_bump_allocator(&mut self) -> HostBumpAllocator<'_, F> {
-		HostBumpAllocator::new(self.data.as_mut_slice())
+		match &self.chunks {
+			Some(chunks) => HostBumpAllocator::with_chunk_source(self.data.as_mut_slice(), chunks),
+			None => HostBumpAllocator::new(self.data.as_mut_slice()),
+		}
 	}
 }
 
@@ -26,6 +31,98 @@ where
 	pub fn new(capacity: usize) -> Self {
 		Self {
 			data: zeroed_vec(capacity),
+			chunks: None,
+		}
+	}
+
+	/// Creates an allocator that grows on demand.
+	///
+	/// The allocator starts with `initial_capacity` elements. When a bump allocator created with
+	/// [`Self::into_bump_allocator`] runs out of memory, a new chunk of at least
+	/// `initial_capacity` elements is allocated, as long as the total size of the additional
+	/// chunks stays within `max_growth` elements. Chunks are never reallocated or moved, so slices
+	/// that were already handed out remain valid.
+	pub fn new_growable(initial_capacity: usize, max_growth: usize) -> Self {
+		Self {
+			data: zeroed_vec(initial_capacity),
+			chunks: Some(ChunkArena {
+				min_chunk_len: initial_capacity,
+				max_growth,
+				chunks: Mutex::new(ChunkList::default()),
+			}),
+		}
+	}
+}
+
+/// The additional chunks of a growable [`CpuComputeAllocator`].
+struct ChunkArena<F> {
+	min_chunk_len: usize,
+	max_growth: usize,
+	chunks: Mutex<ChunkList<F>>,
+}
+
+struct ChunkList<F> {
+	// The chunks are `Vec`s rather than boxed slices so that moving them when the list grows does
+	// not invalidate the slices handed out into their heap allocations.
+	chunks: Vec<Vec<F>>,
+	total_len: usize,
+}
+
+impl<F> Default for ChunkList<F> {
+	fn default() -> Self {
+		Self {
+			chunks: Vec::new(),
+			total_len: 0,
 		}
 	}
 }
+
+impl<'a, F> ChunkSource<'a, F, CpuMemory> for ChunkArena<F>
+where
+	F: Sync + Debug + Send + bytemuck::Zeroable + 'static,
+{
+	fn next_chunk(&'a self, min_len: usize) -> Option<&'a mut [F]> {
+		let mut list = self.chunks.lock().expect("mutex is always available");
+		let len = min_len.max(self.min_chunk_len);
+		if list.total_len + len > self.max_growth {
+			return None;
+		}
+		let mut chunk = zeroed_vec(len);
+		let ptr = chunk.as_mut_ptr();
+		list.chunks.push(chunk);
+		list.total_len += len;
+		// SAFETY: Every call allocates a fresh chunk, so the returned slices never overlap. The
+		// chunk's heap allocation is owned by the arena, and it is never resized or accessed
+		// through the arena again. It is freed only when the arena is dropped, which cannot
+		// happen while the arena is borrowed for `'a`.
+		Some(unsafe { slice::from_raw_parts_mut(ptr, len) })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::alloc::ComputeAllocator;
+
+	#[test]
+	fn test_growable_alloc() {
+		let mut allocator = CpuComputeAllocator::<u128>::new_growable(64, 200);
+		let bump = allocator.into_bump_allocator();
+
+		let first = bump.alloc(48).unwrap();
+		first.fill(1);
+		// Larger than the remaining capacity and the chunk size.
+		let second = bump.alloc(100).unwrap();
+		second.fill(2);
+		let third = bump.alloc(16).unwrap();
+		third.fill(3);
+
+		assert_eq!(second.len(), 100);
+		assert!(first.iter().all(|&x| x == 1));
+		assert!(second.iter().all(|&x| x == 2));
+		assert!(third.iter().all(|&x| x == 3));
+
+		// The additional chunks are limited to 200 elements in total.
+		assert!(bump.alloc(128).is_err());
+	}
+}
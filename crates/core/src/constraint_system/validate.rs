@@ -105,7 +105,7 @@ where
 	let poly = witness.get_multilin_poly(oracle.id())?;
 
 	match oracle.variant {
-		MultilinearPolyVariant::Structured(_) if poly.n_vars() > n_vars => {
+		MultilinearPolyVariant::Structured { .. } if poly.n_vars() > n_vars => {
 			bail!(Error::VirtualOracleNvarsMismatch {
 				oracle: oracle_label.into(),
 				condition: '<',
@@ -138,13 +138,21 @@ where
 				check_eval(oracle_label, i, expected, got)?;
 			}
 		}
-		MultilinearPolyVariant::Structured(inner) => {
+		MultilinearPolyVariant::Structured { expr, max_n_vars } => {
+			if n_vars > *max_n_vars {
+				bail!(Error::VirtualOracleNvarsMismatch {
+					oracle: oracle_label.into(),
+					condition: '<',
+					oracle_num_vars: *max_n_vars,
+					witness_num_vars: n_vars,
+				});
+			}
 			for i in 0..1 << n_vars {
 				let got = poly.evaluate_on_hypercube(i)?;
-				// NOTE: that the arith circuit can have more query parameters than `n_vars`, that's
-				//       the reason we use the arith circuit's n_vars here.
-				let eval_point = decompose_index_to_hypercube_point(inner.n_vars(), i);
-				let expected = inner.evaluate(&eval_point)?;
+				// The variables from `n_vars` on are zero, and the arith circuit may use any of
+				// the `max_n_vars` variables.
+				let eval_point = decompose_index_to_hypercube_point(n_vars, i);
+				let expected = expr.evaluate_partial(&eval_point);
 				check_eval(oracle_label, i, expected, got)?;
 			}
 		}
@@ -16,6 +16,10 @@ pub enum Error {
 	IncorrectNumberOfVariables { expected: usize },
 	#[error("attempted to project more variables {values_len} than inner polynomial has {n_vars}")]
 	InvalidProjection { values_len: usize, n_vars: usize },
+	#[error(
+		"the expression of a structured oracle has {n_vars} variables, more than the maximum {max_n_vars}"
+	)]
+	StructuredTooManyVariables { n_vars: usize, max_n_vars: usize },
 	#[error("invalid polynomial index in committed batch")]
 	InvalidPolynomialIndex,
 	#[error("polynomial error")]
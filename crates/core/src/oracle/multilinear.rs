@@ -44,7 +44,23 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 		Ok(self.mut_ref.add_to_set(oracle))
 	}
 
-	pub fn structured(self, n_vars: usize, expr: ArithCircuit<F>) -> Result<OracleId, Error> {
+	/// Adds a structured oracle over `n_vars` variables with the multilinear extension `expr` over
+	/// at most `max_n_vars` variables.
+	pub fn structured(
+		self,
+		n_vars: usize,
+		expr: ArithCircuit<F>,
+		max_n_vars: usize,
+	) -> Result<OracleId, Error> {
+		if expr.n_vars() > max_n_vars {
+			bail!(Error::StructuredTooManyVariables {
+				n_vars: expr.n_vars(),
+				max_n_vars,
+			});
+		}
+		if n_vars > max_n_vars {
+			bail!(Error::StructuredTooManyVariables { n_vars, max_n_vars });
+		}
 		if expr.binary_tower_level() > F::TOWER_LEVEL {
 			bail!(Error::TowerLevelTooHigh {
 				tower_level: expr.binary_tower_level(),
@@ -56,7 +72,7 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 			n_vars,
 			tower_level: expr.binary_tower_level(),
 			name: self.name,
-			variant: MultilinearPolyVariant::Structured(expr),
+			variant: MultilinearPolyVariant::Structured { expr, max_n_vars },
 		};
 
 		Ok(self.mut_ref.add_to_set(oracle))
@@ -591,7 +607,14 @@ pub enum MultilinearPolyVariant<F: TowerField> {
 	/// expressed as an arithmetic circuit, of polynomial size in the number of variables.
 	///
 	/// [Lasso]: <https://eprint.iacr.org/2023/1216>
-	Structured(ArithCircuit<F>),
+	Structured {
+		expr: ArithCircuit<F>,
+		/// The maximum number of variables of the multilinear extension.
+		///
+		/// The expression may use fewer variables, and an evaluation point with more coordinates
+		/// is rejected.
+		max_n_vars: usize,
+	},
 	Repeating {
 		id: OracleId,
 		log_count: usize,
@@ -622,7 +645,10 @@ impl DeserializeBytes for MultilinearPolyVariant<BinaryField128b> {
 		Ok(match u8::deserialize(&mut buf, mode)? {
 			0 => Self::Committed,
 			1 => Self::Transparent(DeserializeBytes::deserialize(buf, mode)?),
-			2 => Self::Structured(DeserializeBytes::deserialize(buf, mode)?),
+			2 => Self::Structured {
+				expr: DeserializeBytes::deserialize(&mut buf, mode)?,
+				max_n_vars: DeserializeBytes::deserialize(buf, mode)?,
+			},
 			3 => Self::Repeating {
 				id: DeserializeBytes::deserialize(&mut buf, mode)?,
 				log_count: DeserializeBytes::deserialize(buf, mode)?,
@@ -950,7 +976,7 @@ impl<F: TowerField> MultilinearPolyOracle<F> {
 	const fn type_str(&self) -> &str {
 		match self.variant {
 			MultilinearPolyVariant::Transparent(_) => "Transparent",
-			MultilinearPolyVariant::Structured(_) => "Structured",
+			MultilinearPolyVariant::Structured { .. } => "Structured",
 			MultilinearPolyVariant::Committed => "Committed",
 			MultilinearPolyVariant::Repeating { .. } => "Repeating",
 			MultilinearPolyVariant::Projected(_) => "Projected",
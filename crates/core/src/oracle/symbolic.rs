@@ -53,7 +53,14 @@ pub enum SymbolicMultilinearPolyVariant<F: TowerField> {
 	/// expressed as an arithmetic circuit, of polynomial size in the number of variables.
 	///
 	/// [Lasso]: <https://eprint.iacr.org/2023/1216>
-	Structured(ArithCircuit<F>),
+	Structured {
+		expr: ArithCircuit<F>,
+		/// The maximum number of variables of the multilinear extension.
+		///
+		/// The expression may use fewer variables, and an evaluation point with more coordinates
+		/// is rejected.
+		max_n_vars: usize,
+	},
 	Repeating {
 		id: OracleId,
 	},
@@ -105,7 +112,10 @@ impl DeserializeBytes for SymbolicMultilinearPolyVariant<BinaryField128b> {
 		Ok(match u8::deserialize(&mut buf, mode)? {
 			0 => Self::Committed,
 			1 => Self::Transparent(DeserializeBytes::deserialize(buf, mode)?),
-			2 => Self::Structured(DeserializeBytes::deserialize(buf, mode)?),
+			2 => Self::Structured {
+				expr: DeserializeBytes::deserialize(&mut buf, mode)?,
+				max_n_vars: DeserializeBytes::deserialize(buf, mode)?,
+			},
 			3 => Self::Repeating {
 				id: DeserializeBytes::deserialize(&mut buf, mode)?,
 			},
@@ -257,7 +267,10 @@ fn instantiate_oracle_variant<F: TowerField>(
 		Symbolic::Transparent(transparent_poly_oracle) => {
 			Sized::Transparent(transparent_poly_oracle.clone())
 		}
-		Symbolic::Structured(arith_circuit) => Sized::Structured(arith_circuit.clone()),
+		Symbolic::Structured { expr, max_n_vars } => Sized::Structured {
+			expr: expr.clone(),
+			max_n_vars: *max_n_vars,
+		},
 		Symbolic::Repeating { id } => {
 			let log_count = n_vars - mos.n_vars(*id);
 			Sized::Repeating { id: *id, log_count }
@@ -345,7 +358,18 @@ impl<'a, F: TowerField> Builder<'a, F> {
 		Ok(self.mut_ref.add_to_set(oracle))
 	}
 
-	pub fn structured(self, expr: ArithCircuit<F>) -> Result<OracleId, Error> {
+	/// Adds a structured oracle with the multilinear extension `expr` over at most `max_n_vars`
+	/// variables.
+	///
+	/// The expression need not use all of the `max_n_vars` variables. The multilinear extension
+	/// does not depend on the variables that the expression does not use.
+	pub fn structured(self, expr: ArithCircuit<F>, max_n_vars: usize) -> Result<OracleId, Error> {
+		if expr.n_vars() > max_n_vars {
+			bail!(Error::StructuredTooManyVariables {
+				n_vars: expr.n_vars(),
+				max_n_vars,
+			});
+		}
 		if expr.binary_tower_level() > F::TOWER_LEVEL {
 			bail!(Error::TowerLevelTooHigh {
 				tower_level: expr.binary_tower_level(),
@@ -358,7 +382,7 @@ impl<'a, F: TowerField> Builder<'a, F> {
 			log_values_per_row: self.log_values_per_row,
 			tower_level: expr.binary_tower_level(),
 			name: self.name,
-			variant: SymbolicMultilinearPolyVariant::Structured(expr),
+			variant: SymbolicMultilinearPolyVariant::Structured { expr, max_n_vars },
 		};
 
 		Ok(self.mut_ref.add_to_set(oracle))
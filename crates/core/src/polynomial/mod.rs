@@ -8,3 +8,4 @@ pub mod test_utils;
 
 pub use error::*;
 pub use multivariate::*;
+pub use test_utils::hypercube_points;
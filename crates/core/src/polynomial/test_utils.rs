@@ -5,12 +5,8 @@ use binius_field::{BinaryField1b, Field, PackedField, packed::set_packed_slice};
 use crate::polynomial::MultivariatePoly;
 
 pub fn hypercube_evals_from_oracle<F: Field>(oracle: &dyn MultivariatePoly<F>) -> Vec<F> {
-	(0..(1 << oracle.n_vars()))
-		.map(|i| {
-			oracle
-				.evaluate(&decompose_index_to_hypercube_point(oracle.n_vars(), i))
-				.unwrap()
-		})
+	hypercube_points(oracle.n_vars())
+		.map(|point| oracle.evaluate(&point).unwrap())
 		.collect()
 }
 
@@ -23,6 +19,14 @@ pub fn decompose_index_to_hypercube_point<F: Field>(n_vars: usize, index: usize)
 		.collect()
 }
 
+/// Returns an iterator over all `2^n_vars` points of the boolean hypercube, in order.
+///
+/// Point `i` is [`decompose_index_to_hypercube_point`] of `i`, so the first coordinate is the least
+/// significant bit of the index.
+pub fn hypercube_points<F: Field>(n_vars: usize) -> impl Iterator<Item = Vec<F>> {
+	(0..1 << n_vars).map(move |i| decompose_index_to_hypercube_point(n_vars, i))
+}
+
 pub fn packed_slice<P>(assignments: &[(std::ops::Range<usize>, u8)]) -> Vec<P>
 where
 	P: PackedField<Scalar = BinaryField1b>,
@@ -57,3 +61,21 @@ where
 	}
 	result
 }
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField32b;
+
+	use super::*;
+
+	#[test]
+	fn test_hypercube_points() {
+		for n_vars in 0..5 {
+			let points = hypercube_points::<BinaryField32b>(n_vars).collect::<Vec<_>>();
+			assert_eq!(points.len(), 1 << n_vars);
+			for (i, point) in points.into_iter().enumerate() {
+				assert_eq!(point, decompose_index_to_hypercube_point(n_vars, i));
+			}
+		}
+	}
+}
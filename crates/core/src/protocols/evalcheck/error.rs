@@ -47,6 +47,14 @@ pub enum VerificationError {
 	MissingLinearCombinationEval,
 	#[error("The referenced duplicate claim is different from expected")]
 	DuplicateClaimMismatch,
+	#[error(
+		"structured oracle {oracle} is evaluated at a point with {n_vars} variables, more than the maximum {max_n_vars}"
+	)]
+	StructuredTooManyVariables {
+		oracle: String,
+		n_vars: usize,
+		max_n_vars: usize,
+	},
 }
 
 impl VerificationError {
@@ -511,7 +511,8 @@ where
 		let multilinear = &self.oracles[id];
 
 		match multilinear.variant {
-			MultilinearPolyVariant::Transparent { .. } | MultilinearPolyVariant::Structured(_) => {}
+			MultilinearPolyVariant::Transparent { .. }
+			| MultilinearPolyVariant::Structured { .. } => {}
 			MultilinearPolyVariant::Committed => {
 				self.committed_eval_claims.push(EvalcheckMultilinearClaim {
 					id: multilinear.id,
@@ -21,11 +21,11 @@ use rand::{SeedableRng, rngs::StdRng};
 
 use crate::{
 	fiat_shamir::HasherChallenger,
-	oracle::{MultilinearOracleSet, ShiftVariant},
+	oracle::{MultilinearOracleSet, ShiftVariant, SymbolicMultilinearOracleSet},
 	polynomial::MultivariatePoly,
 	protocols::evalcheck::{
-		EvalcheckHint, EvalcheckMultilinearClaim, EvalcheckProver, EvalcheckVerifier,
-		deserialize_evalcheck_proof, serialize_evalcheck_proof,
+		Error, EvalcheckHint, EvalcheckMultilinearClaim, EvalcheckProver, EvalcheckVerifier,
+		VerificationError, deserialize_evalcheck_proof, serialize_evalcheck_proof,
 	},
 	transcript::ProverTranscript,
 	transparent::select_row::SelectRow,
@@ -664,6 +664,53 @@ fn test_evalcheck_serialization() {
 	transcript.finalize().unwrap()
 }
 
+#[test]
+fn test_evalcheck_structured_beyond_max_n_vars() {
+	// The incrementing column over at most 4 variables.
+	let max_n_vars = 4;
+	let expr = (0..max_n_vars)
+		.map(|i| {
+			ArithExpr::Var(i)
+				* ArithExpr::Const(<FExtension as ExtensionField<BinaryField1b>>::basis(i))
+		})
+		.sum::<ArithExpr<FExtension>>();
+	let mut symbolic = SymbolicMultilinearOracleSet::<FExtension>::new();
+	let id = symbolic
+		.add_oracle(0, 0, "incrementing")
+		.structured(ArithCircuit::from(&expr), max_n_vars)
+		.unwrap();
+
+	let verify = |log_size: usize| {
+		let mut oracles = symbolic.instantiate(&[1 << log_size]).unwrap();
+		let mut rng = StdRng::seed_from_u64(0);
+		let eval_point = repeat_with(|| <FExtension as Field>::random(&mut rng))
+			.take(log_size)
+			.collect::<Vec<_>>();
+		// The evaluation of a table that wraps around the column beyond its maximum size.
+		let eval = ArithCircuit::from(&expr).evaluate_partial(&eval_point);
+		let claim = EvalcheckMultilinearClaim {
+			id,
+			eval_point: eval_point.into(),
+			eval,
+		};
+
+		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		serialize_evalcheck_proof(&mut transcript.message(), &EvalcheckHint::NewClaim);
+		let mut transcript = transcript.into_verifier();
+		EvalcheckVerifier::<FExtension>::new(&mut oracles).verify(vec![claim], &mut transcript)
+	};
+
+	verify(max_n_vars).unwrap();
+	assert!(matches!(
+		verify(max_n_vars + 1),
+		Err(Error::Verification(VerificationError::StructuredTooManyVariables {
+			n_vars: 5,
+			max_n_vars: 4,
+			..
+		}))
+	));
+}
+
 #[test]
 pub fn test_zero_padded_zero_vars() {
 	let mut oracles = MultilinearOracleSet::<BinaryField128b>::new();
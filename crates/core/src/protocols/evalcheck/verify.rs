@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{iter, mem};
+use std::mem;
 
 use binius_field::{Field, TowerField, util::inner_product_unchecked};
 use getset::{Getters, MutGetters};
@@ -161,17 +161,25 @@ impl<'a, F: TowerField> EvalcheckVerifier<'a, F> {
 					return Err(VerificationError::IncorrectEvaluation(multilinear_label).into());
 				}
 			}
-			MultilinearPolyVariant::Structured(ref inner) => {
-				// Here we need to extend the eval_point to the input domain of the arith circuit
-				// by assigning zeroes to the variables.
-				let eval_point: &[F] = &eval_point;
-				let n_pad_zeros = inner.n_vars() - eval_point.len();
-				let query = eval_point
-					.iter()
-					.copied()
-					.chain(iter::repeat_n(F::ZERO, n_pad_zeros))
-					.collect::<Vec<_>>();
-				let actual_eval = inner.evaluate(&query)?;
+			MultilinearPolyVariant::Structured {
+				ref expr,
+				max_n_vars,
+			} => {
+				// A table larger than the maximum size of the column would wrap around the
+				// multilinear extension instead of extending it.
+				if eval_point.len() > max_n_vars {
+					return Err(VerificationError::StructuredTooManyVariables {
+						oracle: multilinear_label,
+						n_vars: eval_point.len(),
+						max_n_vars,
+					}
+					.into());
+				}
+				// The arith circuit may have more variables than the eval_point, when the table is
+				// smaller than the maximum size of the column, and the remaining variables are
+				// assigned zeroes. It may also have fewer variables, when it does not depend on
+				// the high ones.
+				let actual_eval = expr.evaluate_partial(&eval_point);
 				if actual_eval != eval {
 					return Err(VerificationError::IncorrectEvaluation(multilinear_label).into());
 				}
@@ -55,4 +55,5 @@ pub use packed_extension::*;
 pub use packed_extension_ops::*;
 pub use packed_polyval::*;
 pub use polyval::*;
-pub use transpose::{Error as TransposeError, square_transpose};
+pub use transpose::{Error as TransposeError, b1_transpose, square_transpose};
+pub use util::inner_product;
@@ -1,8 +1,9 @@
 // Copyright 2023-2025 Irreducible Inc.
 
 use binius_utils::checked_arithmetics::log2_strict_usize;
+use bytemuck::{Pod, must_cast_slice_mut};
 
-use super::packed::PackedField;
+use super::{BinaryField1b, packed::PackedField};
 
 /// Error thrown when a transpose operation fails.
 #[derive(Clone, thiserror::Error, Debug)]
@@ -68,10 +69,112 @@ pub fn square_transpose<P: PackedField>(log_n: usize, elems: &mut [P]) -> Result
 	Ok(())
 }
 
+/// Transposes a bit matrix from row-major bytes into packed columns of 1-bit field elements.
+///
+/// The input is a matrix with `n_rows` rows and `n_cols` columns in row-major order, with the bits
+/// of each byte in little-endian order, i.e. bit `c` of row `r` is bit `c % 8` of byte
+/// `(r * n_cols + c) / 8`. The output is the matrix in column-major order, so that element
+/// `c * n_rows + r` of the packed slice is bit `c` of row `r`, and each column is a contiguous run
+/// of `n_rows` elements.
+///
+/// The matrix is transposed in blocks of 8x8 bits, each with the SWAR algorithm of Hacker's
+/// Delight, Section 7-3, on a 64-bit word.
+///
+/// # Arguments
+///
+/// * `input`: The row-major matrix, of length at least `n_rows * n_cols / 8`.
+/// * `n_rows`, `n_cols`: The dimensions of the matrix, both multiples of 8.
+/// * `out`: The column-major matrix, of at least `n_rows * n_cols` elements.
+pub fn b1_transpose<P>(
+	input: &[u8],
+	n_rows: usize,
+	n_cols: usize,
+	out: &mut [P],
+) -> Result<(), Error>
+where
+	P: PackedField<Scalar = BinaryField1b> + Pod,
+{
+	if n_rows % 8 != 0 || n_cols % 8 != 0 {
+		return Err(Error::InvalidBufferSize {
+			param: "n_rows, n_cols",
+			msg: "the dimensions must be multiples of 8".to_string(),
+		});
+	}
+	let row_bytes = n_cols / 8;
+	let col_bytes = n_rows / 8;
+	if input.len() < n_rows * row_bytes {
+		return Err(Error::InvalidBufferSize {
+			param: "input",
+			msg: format!("must have length at least {}", n_rows * row_bytes),
+		});
+	}
+	if out.len() * P::WIDTH < n_rows * n_cols {
+		return Err(Error::InvalidBufferSize {
+			param: "out",
+			msg: format!("must have at least {} elements", n_rows * n_cols),
+		});
+	}
+
+	let out_bytes = must_cast_slice_mut::<P, u8>(out);
+	for row_block in 0..col_bytes {
+		for col_block in 0..row_bytes {
+			// Byte k of the word is row `8 * row_block + k` of the block.
+			let block = (0..8).fold(0u64, |block, k| {
+				block | (input[(8 * row_block + k) * row_bytes + col_block] as u64) << (8 * k)
+			});
+			// Byte k of the result is column `8 * col_block + k` of the block.
+			let block = transpose_8x8_bits(block);
+			for k in 0..8 {
+				out_bytes[(8 * col_block + k) * col_bytes + row_block] = (block >> (8 * k)) as u8;
+			}
+		}
+	}
+	Ok(())
+}
+
+/// Transposes an 8x8 bit matrix with bit `j` of byte `i` at position `(i, j)`.
+fn transpose_8x8_bits(mut x: u64) -> u64 {
+	let t = (x ^ (x >> 7)) & 0x00AA00AA00AA00AA;
+	x ^= t ^ (t << 7);
+	let t = (x ^ (x >> 14)) & 0x0000CCCC0000CCCC;
+	x ^= t ^ (t << 14);
+	let t = (x ^ (x >> 28)) & 0x00000000F0F0F0F0;
+	x ^= t ^ (t << 28);
+	x
+}
+
 #[cfg(test)]
 mod tests {
+	use rand::{Rng, SeedableRng, rngs::StdRng};
+
 	use super::*;
-	use crate::{PackedBinaryField64x2b, PackedBinaryField128x1b};
+	use crate::{PackedBinaryField64x2b, PackedBinaryField128x1b, packed::get_packed_slice};
+
+	#[test]
+	fn test_b1_transpose() {
+		let n_rows = 24;
+		let n_cols = 16;
+		let mut rng = StdRng::seed_from_u64(0);
+		let input = (0..n_rows * n_cols / 8)
+			.map(|_| rng.random::<u8>())
+			.collect::<Vec<_>>();
+
+		let mut out = vec![PackedBinaryField128x1b::default(); 3];
+		b1_transpose(&input, n_rows, n_cols, &mut out).unwrap();
+
+		// Naive bit-by-bit transpose.
+		for r in 0..n_rows {
+			for c in 0..n_cols {
+				let byte = (r * n_cols + c) / 8;
+				let expected =
+					BinaryField1b::from((input[byte] >> ((r * n_cols + c) % 8)) & 1 == 1);
+				assert_eq!(get_packed_slice(&out, c * n_rows + r), expected);
+			}
+		}
+
+		assert!(b1_transpose(&input, n_rows, n_cols + 1, &mut out).is_err());
+		assert!(b1_transpose(&input, n_rows, n_cols, &mut out[..2]).is_err());
+	}
 
 	#[test]
 	fn test_square_transpose_128x1b() {
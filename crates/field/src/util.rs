@@ -19,6 +19,24 @@ where
 	iter::zip(a, b).map(|(a_i, b_i)| a_i * b_i).sum()
 }
 
+/// Computes the inner product of two vectors of packed elements.
+///
+/// The products are accumulated lane-wise in packed form, and the lanes are summed once at the
+/// end. A vector whose number of scalars is not a multiple of `P::WIDTH` must be padded with
+/// zeros in the last packed element.
+///
+/// ## Panics
+///
+/// * If the slices have different lengths.
+pub fn inner_product<P: PackedField>(a: &[P], b: &[P]) -> P::Scalar {
+	assert_eq!(a.len(), b.len(), "inner product operands must have the same length");
+	iter::zip(a, b)
+		.map(|(&a_i, &b_i)| a_i * b_i)
+		.sum::<P>()
+		.iter()
+		.sum()
+}
+
 /// Calculate inner product for potentially big slices of xs and ys.
 /// The number of elements in xs has to be less or equal to the number of elements in ys.
 pub fn inner_product_par<FX, PX, PY>(xs: &[PX], ys: &[PY]) -> FX
@@ -87,12 +105,39 @@ pub fn powers<F: Field>(val: F) -> impl Iterator<Item = F> {
 
 #[cfg(test)]
 mod tests {
+	use std::iter::repeat_with;
+
+	use rand::{SeedableRng, rngs::StdRng};
+
 	use super::*;
 	use crate::PackedBinaryField4x32b;
 
 	type P = PackedBinaryField4x32b;
 	type F = <P as PackedField>::Scalar;
 
+	#[test]
+	fn test_inner_product_matches_scalar_reference() {
+		let mut rng = StdRng::seed_from_u64(0);
+		// Include lengths that are not a multiple of the pack width.
+		for n_scalars in [0, 1, 3, 4, 5, 17, 64] {
+			let a = repeat_with(|| <F as Field>::random(&mut rng))
+				.take(n_scalars)
+				.collect::<Vec<_>>();
+			let b = repeat_with(|| <F as Field>::random(&mut rng))
+				.take(n_scalars)
+				.collect::<Vec<_>>();
+			let expected = inner_product_unchecked::<F, F>(a.iter().copied(), b.iter().copied());
+
+			let pack = |scalars: &[F]| {
+				scalars
+					.chunks(P::WIDTH)
+					.map(|chunk| P::from_scalars(chunk.iter().copied()))
+					.collect::<Vec<_>>()
+			};
+			assert_eq!(inner_product(&pack(&a), &pack(&b)), expected);
+		}
+	}
+
 	#[test]
 	fn test_inner_product_par_equal_length() {
 		// xs and ys have the same number of packed elements
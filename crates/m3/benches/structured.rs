@@ -0,0 +1,33 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::iter::repeat_with;
+
+use binius_field::{BinaryField128b, Field};
+use binius_m3::builder::{StructuredDynSize, structured::Lowering};
+use criterion::{Criterion, criterion_group, criterion_main};
+
+/// Compares the tensor evaluation of a structured column's multilinear extension with the
+/// evaluation of its arithmetic circuit.
+fn bench_evaluate_incrementing(c: &mut Criterion) {
+	let mut group = c.benchmark_group("structured_evaluate_incrementing");
+	let mut rng = rand::rng();
+	for max_size_log in [16, 32, 64] {
+		let variant = StructuredDynSize::Incrementing { max_size_log };
+		let point = repeat_with(|| BinaryField128b::random(&mut rng))
+			.take(max_size_log)
+			.collect::<Vec<_>>();
+
+		group.bench_function(format!("tensor/n_vars={max_size_log}"), |bench| {
+			bench.iter(|| variant.evaluate_mle_tensor(&point).unwrap());
+		});
+
+		let circuit = variant.circuit::<BinaryField128b>(Lowering::Flat).unwrap();
+		group.bench_function(format!("circuit/n_vars={max_size_log}"), |bench| {
+			bench.iter(|| circuit.evaluate(&point).unwrap());
+		});
+	}
+	group.finish()
+}
+
+criterion_group!(structured, bench_evaluate_incrementing);
+criterion_main!(structured);
@@ -6,7 +6,7 @@ use super::ColumnId;
 use crate::builder::{B1, Col};
 
 /// A flushing rule within a table.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Flush {
 	pub columns: Vec<ColumnId>,
 	pub channel_id: ChannelId,
@@ -42,7 +42,7 @@ impl Default for FlushOpts {
 }
 
 /// A channel.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Channel {
 	pub name: String,
 }
@@ -1,12 +1,12 @@
 // Copyright 2025 Irreducible Inc.
 
-use std::{marker::PhantomData, sync::Arc};
+use std::{collections::BTreeMap, marker::PhantomData, sync::Arc};
 
 use binius_core::{oracle::ShiftVariant, polynomial::MultivariatePoly};
 use binius_field::{ExtensionField, TowerField};
 use binius_math::ArithCircuit;
 
-use super::{structured::StructuredDynSize, table::TableId, types::B128};
+use super::{structured::Structured, table::TableId, types::B128};
 
 /// An index of a column within a table.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -84,7 +84,7 @@ where
 }
 
 /// Complete description of a column within a table.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ColumnInfo<F: TowerField = B128> {
 	pub id: ColumnId,
 	pub col: ColumnDef<F>,
@@ -92,6 +92,14 @@ pub struct ColumnInfo<F: TowerField = B128> {
 	pub shape: ColumnShape,
 	/// Whether the column is constrained to be non-zero.
 	pub is_nonzero: bool,
+	/// Free-form key/value annotations for external tooling, such as a semantic tag of the column.
+	///
+	/// The metadata does not affect the constraint system.
+	pub metadata: BTreeMap<String, String>,
+	/// The phase of the witness fill in which the column is written.
+	///
+	/// See [`TableBuilder::add_committed_phase`](super::TableBuilder::add_committed_phase).
+	pub phase: usize,
 }
 
 /// The shape of each cell in a column.
@@ -121,7 +129,7 @@ pub struct ColumnId {
 }
 
 /// A definition of a column in a table.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum ColumnDef<F: TowerField = B128> {
 	Committed {
 		tower_level: usize,
@@ -161,9 +169,9 @@ pub enum ColumnDef<F: TowerField = B128> {
 		poly: Arc<dyn MultivariatePoly<F>>,
 		data: Vec<F>,
 	},
-	StructuredDynSize(StructuredDynSize),
+	StructuredDynSize(Arc<dyn Structured<F>>),
 	StructuredFixedSize {
-		expr: ArithCircuit<F>,
+		expr: Arc<ArithCircuit<F>>,
 	},
 	StaticExp {
 		bit_cols: Vec<ColumnId>,
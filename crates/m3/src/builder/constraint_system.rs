@@ -1,6 +1,12 @@
 // Copyright 2025 Irreducible Inc.
 
-use std::{cell, collections::BTreeMap, ops::Index};
+use std::{
+	cell,
+	collections::BTreeMap,
+	fmt::Write,
+	ops::{Deref, Index},
+	sync::Arc,
+};
 
 use binius_compute::alloc::HostBumpAllocator;
 pub use binius_core::constraint_system::channel::{
@@ -16,31 +22,136 @@ use binius_core::{
 		Constraint, ConstraintPredicate, ConstraintSet, OracleId, SymbolicMultilinearOracleSet,
 	},
 };
-use binius_field::{PackedField, TowerField};
+use binius_field::{ExtensionField, PackedExtension, PackedField, TowerField};
 use binius_math::{ArithCircuit, LinearNormalForm};
-use binius_utils::checked_arithmetics::log2_strict_usize;
+use binius_utils::checked_arithmetics::{log2_ceil_usize, log2_strict_usize};
 
 use super::{
-	ColumnId, Table, TableBuilder, TableId, ZeroConstraint,
+	Affine, ColumnId, CostEstimate, StructuredDynSize, Table, TableBuilder, TableId,
+	ZeroConstraint,
 	channel::{Channel, Flush},
 	column::{ColumnDef, ColumnInfo},
 	error::Error,
-	table::TablePartition,
-	types::B128,
+	table::{TablePartition, log_capacity},
+	types::{B1, B8, B16, B32, B64, B128},
 	witness::WitnessIndex,
 };
 use crate::builder::expr::ArithExprNamedVars;
 
+/// The number of rows whose values are listed by [`ConstraintSystem::structured_report`].
+pub const STRUCTURED_REPORT_SAMPLE_ROWS: usize = 5;
+
 /// An M3 constraint system, independent of the table sizes.
 #[derive(Debug, Default)]
 pub struct ConstraintSystem<F: TowerField = B128> {
 	pub tables: Vec<Table<F>>,
 	pub channels: Vec<Channel>,
+	/// Debug option to compile the structured columns as committed columns.
+	///
+	/// The verifier then no longer evaluates the multilinear extensions of the structured columns,
+	/// and the witness must hold their values, which
+	/// [`WitnessIndex::fill_structured_cols`] computes from the column specifications. This makes
+	/// proving less efficient, but a wrong structured column can be inspected and constrained row
+	/// by row.
+	pub structured_as_committed: bool,
+	/// The maximum number of steps of the arithmetic circuit of a structured column, or `None`
+	/// for no limit.
+	///
+	/// With a limit, [`ConstraintSystem::compile`] fails with
+	/// [`binius_math::Error::CircuitTooLarge`] on a structured column with a larger circuit,
+	/// instead of running out of memory on a pathological user-defined
+	/// [`Structured`](super::Structured) column. A column whose
+	/// [`Structured::circuit_cost`](super::Structured::circuit_cost) exceeds the limit is rejected
+	/// before its expression is built.
+	pub max_structured_circuit_steps: Option<usize>,
 
 	// This is assigned as part of `ConstraintSystem::compile`.
 	oracle_lookup: cell::RefCell<Option<OracleLookup>>,
 }
 
+impl<F: TowerField> SuggestedPattern<F> {
+	/// Returns the closed form of the values of a column, if there is one.
+	fn find(values: &[F]) -> Option<Self> {
+		let intercept = values[0];
+		if values.iter().all(|&value| value == intercept) {
+			return Some(Self::Constant(intercept));
+		}
+
+		// The values must be an affine function of the bits of the row index: the value of a row
+		// is the value of the row with its lowest set bit cleared plus the weight of that bit.
+		let n_vars = log2_ceil_usize(values.len());
+		if n_vars > F::N_BITS {
+			return None;
+		}
+		let weights = (0..n_vars)
+			.map(|i| values[1 << i] - intercept)
+			.collect::<Vec<_>>();
+		let is_affine = (1..values.len()).all(|row| {
+			values[row] == values[row & (row - 1)] + weights[row.trailing_zeros() as usize]
+		});
+		if !is_affine {
+			return None;
+		}
+
+		let basis = |i| <F as ExtensionField<B1>>::basis(i);
+		let is_incrementing = (0..n_vars).all(|i| weights[i] == basis(i));
+		if is_incrementing && intercept == F::ZERO {
+			return Some(Self::Structured(StructuredDynSize::Incrementing {
+				max_size_log: n_vars,
+			}));
+		}
+		if is_incrementing && intercept == (0..n_vars).map(basis).sum::<F>() {
+			return Some(Self::Structured(StructuredDynSize::Complement {
+				max_size_log: n_vars,
+			}));
+		}
+		if intercept == F::ZERO && (0..n_vars).all(|i| weights[i] == basis(n_vars - 1 - i)) {
+			return Some(Self::Structured(StructuredDynSize::IncrementingBigEndian {
+				max_size_log: n_vars,
+			}));
+		}
+		let slope = weights[0];
+		if (0..n_vars).all(|i| weights[i] == slope * basis(i)) {
+			return Some(Self::Affine(Affine {
+				slope,
+				intercept,
+				max_size_log: n_vars,
+			}));
+		}
+		None
+	}
+}
+
+/// A saved state of a [`ConstraintSystem`], created with [`ConstraintSystem::checkpoint`].
+#[derive(Debug, Clone)]
+pub struct Checkpoint<F: TowerField = B128> {
+	tables: Vec<Table<F>>,
+	channels: Vec<Channel>,
+}
+
+/// A committed column whose filled values follow a closed form, found by
+/// [`ConstraintSystem::suggest_structured`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion<F: TowerField = B128> {
+	pub table_id: TableId,
+	pub id: ColumnId,
+	pub name: String,
+	/// The column that the committed column could be replaced with.
+	pub pattern: SuggestedPattern<F>,
+}
+
+/// The closed form of the values of a committed column. See [`Suggestion`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SuggestedPattern<F: TowerField = B128> {
+	/// Every row has the same value, as a column added with [`TableBuilder::add_constant`].
+	Constant(F),
+	/// The values are those of a built-in structured column, over as many variables as the table
+	/// has.
+	Structured(StructuredDynSize),
+	/// The values are an affine map of the row index, as an [`Affine`] structured column.
+	Affine(Affine<F>),
+}
+
 impl<F: TowerField> std::fmt::Display for ConstraintSystem<F> {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		writeln!(f, "ConstraintSystem {{")?;
@@ -144,6 +255,29 @@ impl<F: TowerField> ConstraintSystem<F> {
 		TableBuilder::new(self.tables.last_mut().expect("table was just pushed"))
 	}
 
+	/// Saves the tables and channels of the constraint system, to be restored with
+	/// [`Self::restore`].
+	///
+	/// This allows trying out a gadget and rolling back the tables, columns, constraints, and
+	/// channels added afterwards, e.g. in a programmatic search over circuits.
+	pub fn checkpoint(&self) -> Checkpoint<F> {
+		Checkpoint {
+			tables: self.tables.clone(),
+			channels: self.channels.clone(),
+		}
+	}
+
+	/// Restores the tables and channels saved by [`Self::checkpoint`].
+	///
+	/// Everything added to the constraint system after the checkpoint is removed, and the
+	/// constraint system must be compiled again before it is used to build a witness.
+	pub fn restore(&mut self, checkpoint: Checkpoint<F>) {
+		let Checkpoint { tables, channels } = checkpoint;
+		self.tables = tables;
+		self.channels = channels;
+		*self.oracle_lookup.borrow_mut() = None;
+	}
+
 	pub fn add_channel(&mut self, name: impl ToString) -> ChannelId {
 		let id = self.channels.len();
 		self.channels.push(Channel {
@@ -152,6 +286,214 @@ impl<F: TowerField> ConstraintSystem<F> {
 		id
 	}
 
+	/// Substitutes the constant structured columns into the zero constraints of all the tables.
+	///
+	/// See [`Table::simplify_structured_constraints`]. Returns the number of replaced
+	/// constraints.
+	pub fn simplify_structured_constraints(&mut self) -> Result<usize, Error> {
+		self.tables
+			.iter_mut()
+			.map(Table::simplify_structured_constraints)
+			.sum()
+	}
+
+	/// Returns the number of zero constraints of each degree, across all the tables.
+	///
+	/// The degree is that of the constraint expression, see [`ArithCircuit::degree`]. This shows
+	/// how the constraints are distributed, e.g. a few expensive high-degree constraints among
+	/// many cheap ones.
+	pub fn degree_histogram(&self) -> BTreeMap<usize, usize> {
+		let mut histogram = BTreeMap::new();
+		for table in &self.tables {
+			for partition in table.partitions.values() {
+				for constraint in &partition.zero_constraints {
+					*histogram.entry(constraint.expr.degree()).or_default() += 1;
+				}
+			}
+		}
+		histogram
+	}
+
+	/// Returns a human-readable report of the closed forms of all the structured columns.
+	///
+	/// Every structured column is listed under its table with its specification, the number of
+	/// variables and degree of its multilinear extension, and its values on the first
+	/// [`STRUCTURED_REPORT_SAMPLE_ROWS`] rows, which are the evaluations of the extension on the
+	/// hypercube. This documents a circuit for auditing without building a witness. A column whose
+	/// expression fails to build is listed with the error instead.
+	pub fn structured_report(&self) -> String {
+		let mut report = String::new();
+		for table in &self.tables {
+			let columns = table
+				.columns
+				.iter()
+				.filter_map(|info| {
+					let (spec, expr) = match &info.col {
+						ColumnDef::StructuredDynSize(structured) => (
+							format!("{structured:?}"),
+							structured
+								.expr()
+								.map(|expr| Arc::new(ArithCircuit::from(&expr)))
+								.map_err(|err| err.for_column(&info.name)),
+						),
+						ColumnDef::StructuredFixedSize { expr } => {
+							("fixed size".to_string(), Ok(Arc::clone(expr)))
+						}
+						_ => return None,
+					};
+					Some((&info.name, spec, expr))
+				})
+				.collect::<Vec<_>>();
+			if columns.is_empty() {
+				continue;
+			}
+
+			writeln!(report, "TABLE {}", table.name).expect("writing to a String cannot fail");
+			for (name, spec, expr) in columns {
+				let line = match expr {
+					Ok(expr) => {
+						let n_vars = expr.n_vars();
+						let values = (0..STRUCTURED_REPORT_SAMPLE_ROWS.min(1 << n_vars))
+							.map(|row| {
+								let point = (0..n_vars)
+									.map(|i| if (row >> i) & 1 == 1 { F::ONE } else { F::ZERO })
+									.collect::<Vec<_>>();
+								expr.evaluate(&point)
+									.map_or_else(|err| format!("error: {err}"), |v| v.to_string())
+							})
+							.collect::<Vec<_>>()
+							.join(", ");
+						format!("n_vars {n_vars}, degree {}, values [{values}]", expr.degree())
+					}
+					Err(err) => format!("error: {err}"),
+				};
+				writeln!(report, "    STRUCTURED {name}: {spec}, {line}")
+					.expect("writing to a String cannot fail");
+			}
+		}
+		report
+	}
+
+	/// Returns the groups of structurally identical structured columns, across all the tables.
+	///
+	/// Two structured columns are identical if they have the same shape and number of variables,
+	/// and their multilinear extensions optimize to equal arithmetic circuits, e.g. the same
+	/// incrementing column added to two tables. The columns of a group could be shared by a
+	/// single table instead. Only the groups of two or more columns are returned, in the order of
+	/// their first columns, and a column whose expression fails to build is skipped.
+	pub fn find_duplicate_structured(&self) -> Vec<Vec<(TableId, ColumnId)>> {
+		let mut groups = Vec::<(_, Vec<_>)>::new();
+		for info in self.tables.iter().flat_map(|table| &table.columns) {
+			let (n_vars, expr) = match &info.col {
+				ColumnDef::StructuredDynSize(structured) => match structured.expr() {
+					Ok(expr) => (structured.max_size_log(), ArithCircuit::from(&expr).optimize()),
+					Err(_) => continue,
+				},
+				ColumnDef::StructuredFixedSize { expr } => {
+					(expr.n_vars(), ArithCircuit::clone(expr).optimize())
+				}
+				_ => continue,
+			};
+			let key = (info.shape.tower_height, info.shape.log_values_per_row, n_vars, expr);
+			let member = (info.id.table_id, info.id);
+			match groups.iter_mut().find(|(group_key, _)| *group_key == key) {
+				Some((_, members)) => members.push(member),
+				None => groups.push((key, vec![member])),
+			}
+		}
+		groups
+			.into_iter()
+			.map(|(_, members)| members)
+			.filter(|members| members.len() > 1)
+			.collect()
+	}
+
+	/// Returns the committed columns of a filled witness that could be structured or constant
+	/// columns instead.
+	///
+	/// This is an opt-in analysis of the values of the filled tables, meant as a hint to save the
+	/// cost of committing columns that the verifier could evaluate itself. The values of a column
+	/// over the events of its table are matched against a constant, then against the closed forms
+	/// over the bits of the row index, with one variable per bit of the largest row index: the
+	/// incrementing, big-endian incrementing and complement columns, and then an affine map of
+	/// the row index. Only the single-valued columns of the tables with at least two events are
+	/// analyzed, and a match only holds for the given witness, not for every witness.
+	pub fn suggest_structured<P>(
+		&self,
+		witness: &mut WitnessIndex<P>,
+	) -> Result<Vec<Suggestion<F>>, Error>
+	where
+		P: PackedField<Scalar = F>
+			+ PackedExtension<B1>
+			+ PackedExtension<B8>
+			+ PackedExtension<B16>
+			+ PackedExtension<B32>
+			+ PackedExtension<B64>
+			+ PackedExtension<B128>,
+	{
+		let mut suggestions = Vec::new();
+		for table in &self.tables {
+			let Some(table_witness) = witness.get_table(table.id) else {
+				continue;
+			};
+			let size = table_witness.logical_size();
+			if size < 2 {
+				continue;
+			}
+			let segment = table_witness.full_segment();
+			for info in &table.columns {
+				if !matches!(info.col, ColumnDef::Committed { .. })
+					|| info.shape.log_values_per_row != 0
+				{
+					continue;
+				}
+				let col = segment.get_dyn(info.id)?;
+				let values = (0..size).map(|i| col.get(i)).collect::<Vec<_>>();
+				if let Some(pattern) = SuggestedPattern::find(&values) {
+					suggestions.push(Suggestion {
+						table_id: table.id,
+						id: info.id,
+						name: info.name.clone(),
+						pattern,
+					});
+				}
+			}
+		}
+		Ok(suggestions)
+	}
+
+	/// Returns a rough estimate of the work to prove the constraint system with the given table
+	/// sizes, indexed by table ID.
+	///
+	/// The estimate is computed from the [`TableStat`](super::TableStat) of every table, scaled by
+	/// the table capacity, so it can be used to compare circuit designs without proving. The
+	/// tables of size zero are not proven and are skipped. See [`CostEstimate`] for the modeled
+	/// counts.
+	///
+	/// ## Panics
+	///
+	/// * If the number of table sizes is not the number of tables.
+	pub fn estimate_proving_cost(&self, table_sizes: &[usize]) -> CostEstimate {
+		assert_eq!(table_sizes.len(), self.tables.len(), "expected a size for every table");
+		let mut estimate = CostEstimate::default();
+		for (table, &size) in self.tables.iter().zip(table_sizes) {
+			if size == 0 {
+				continue;
+			}
+			let log_capacity = log_capacity(size);
+			let stat = table.stat();
+			estimate.committed_bits += (stat.bits_per_row_committed() as u64) << log_capacity;
+			estimate.constraint_evals += (stat.assert_zero_cost_approx() as u64) << log_capacity;
+			estimate.sumcheck_rounds += table
+				.partitions
+				.values()
+				.filter(|partition| !partition.zero_constraints.is_empty())
+				.map(|partition| (log_capacity + partition.values_per_row.ilog2() as usize) as u64)
+				.sum::<u64>();
+		}
+		estimate
+	}
+
 	/// Creates and allocates the witness index.
 	///
 	/// **Deprecated**: This is a thin wrapper over [`WitnessIndex::new`] now, which is preferred.
@@ -163,6 +505,19 @@ impl<F: TowerField> ConstraintSystem<F> {
 		WitnessIndex::new(self, allocator)
 	}
 
+	/// Compiles the constraint system and freezes it against further modification.
+	///
+	/// Compiling checks that the constraint system is well-formed, so a frozen system is known to
+	/// be valid. See [`FrozenConstraintSystem`].
+	///
+	/// ## Throws
+	///
+	/// * The errors of [`Self::compile`].
+	pub fn freeze(self) -> Result<FrozenConstraintSystem<F>, Error> {
+		let compiled = self.compile()?;
+		Ok(FrozenConstraintSystem { cs: self, compiled })
+	}
+
 	/// Returns the oracle lookup for this constraint system.
 	///
 	/// Note that this function returns the struct as of the last call to
@@ -190,6 +545,7 @@ impl<F: TowerField> ConstraintSystem<F> {
 		let mut oracle_lookup = OracleLookup::new();
 
 		for table in &self.tables {
+			table.check_constraint_names()?;
 			table_size_specs.push(table.size_spec());
 
 			// Add multilinear oracles for all table columns.
@@ -197,6 +553,8 @@ impl<F: TowerField> ConstraintSystem<F> {
 				&mut oracle_lookup,
 				&mut oracles,
 				table,
+				self.structured_as_committed,
+				self.max_structured_circuit_steps,
 				&mut non_zero_oracle_ids,
 			)?;
 
@@ -261,6 +619,12 @@ impl<F: TowerField> ConstraintSystem<F> {
 					selectors,
 				} in flushes
 				{
+					if table.blinding_rows() > 0 && selectors.is_empty() {
+						return Err(Error::UnselectedFlushWithBlindingRows {
+							table_id: table.id(),
+						});
+					}
+
 					let flush_oracles = flush_columns
 						.iter()
 						.map(|&column_id| OracleOrConst::Oracle(oracle_lookup[column_id]))
@@ -307,6 +671,46 @@ impl<F: TowerField> ConstraintSystem<F> {
 	}
 }
 
+/// A compiled [`ConstraintSystem`] that can no longer be modified, created with
+/// [`ConstraintSystem::freeze`].
+///
+/// The frozen system dereferences to the [`ConstraintSystem`], so the query methods, such as
+/// [`ConstraintSystem::degree_histogram`], and [`WitnessIndex::new`] take it in place of the
+/// constraint system. The methods that add tables and channels take the constraint system by
+/// mutable reference, which the frozen system does not give out:
+///
+/// ```compile_fail
+/// use binius_m3::builder::ConstraintSystem;
+///
+/// let mut frozen = ConstraintSystem::<binius_m3::builder::B128>::new().freeze().unwrap();
+/// frozen.add_table("too late");
+/// ```
+#[derive(Debug)]
+pub struct FrozenConstraintSystem<F: TowerField = B128> {
+	cs: ConstraintSystem<F>,
+	compiled: CompiledConstraintSystem<F>,
+}
+
+impl<F: TowerField> FrozenConstraintSystem<F> {
+	/// Returns the compiled constraint system, as returned by [`ConstraintSystem::compile`].
+	pub fn compiled(&self) -> &CompiledConstraintSystem<F> {
+		&self.compiled
+	}
+
+	/// Unfreezes the constraint system, so that it can be modified again.
+	pub fn into_inner(self) -> ConstraintSystem<F> {
+		self.cs
+	}
+}
+
+impl<F: TowerField> Deref for FrozenConstraintSystem<F> {
+	type Target = ConstraintSystem<F>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.cs
+	}
+}
+
 #[derive(Debug, Copy, Clone)]
 pub(crate) enum OracleMapping {
 	Regular(OracleId),
@@ -403,10 +807,19 @@ fn add_oracles_for_columns<F: TowerField>(
 	oracle_lookup: &mut OracleLookup,
 	oracle_set: &mut SymbolicMultilinearOracleSet<F>,
 	table: &Table<F>,
+	structured_as_committed: bool,
+	max_structured_circuit_steps: Option<usize>,
 	non_zero_oracle_ids: &mut Vec<OracleId>,
 ) -> Result<(), Error> {
 	for column_info in table.columns.iter() {
-		add_oracle_for_column(oracle_set, oracle_lookup, column_info, table.id())?;
+		add_oracle_for_column(
+			oracle_set,
+			oracle_lookup,
+			column_info,
+			table.id(),
+			structured_as_committed,
+			max_structured_circuit_steps,
+		)?;
 		if column_info.is_nonzero {
 			non_zero_oracle_ids.push(oracle_lookup[column_info.id]);
 		}
@@ -422,11 +835,15 @@ fn add_oracles_for_columns<F: TowerField>(
 /// * `oracle_lookup` - mapping of column indices in the table to oracle IDs in the oracle set
 /// * `column_info` - information about the column to be added
 /// * `n_vars` - number of variables of the multilinear oracle
+/// * `structured_as_committed` - whether structured columns are added as committed oracles
+/// * `max_structured_circuit_steps` - the maximum size of the circuit of a structured column
 fn add_oracle_for_column<F: TowerField>(
 	oracles: &mut SymbolicMultilinearOracleSet<F>,
 	oracle_lookup: &mut OracleLookup,
 	column_info: &ColumnInfo<F>,
 	table_id: TableId,
+	structured_as_committed: bool,
+	max_structured_circuit_steps: Option<usize>,
 ) -> Result<(), Error> {
 	let ColumnInfo {
 		id: column_id,
@@ -547,17 +964,33 @@ fn add_oracle_for_column<F: TowerField>(
 				.repeating(oracle_id_original)?;
 			oracle_lookup.register_transparent(*column_id, oracle_id_original, oracle_id_repeating);
 		}
+		ColumnDef::StructuredDynSize(_) if structured_as_committed => {
+			let oracle_id = oracles
+				.add_oracle(table_id, shape.log_values_per_row, name)
+				.committed(shape.tower_height);
+			oracle_lookup.register_regular(*column_id, oracle_id);
+		}
 		ColumnDef::StructuredDynSize(structured) => {
-			let expr = structured.expr()?;
+			let max_steps = max_structured_circuit_steps.unwrap_or(usize::MAX);
+			// Every multiplication is a step of the circuit, so a column whose cost exceeds the
+			// limit is rejected without building its expression.
+			if structured
+				.circuit_cost()
+				.is_some_and(|cost| cost.n_muls > max_steps)
+			{
+				return Err(binius_math::Error::CircuitTooLarge { max_steps }.into());
+			}
+			let expr = structured.expr().map_err(|err| err.for_column(name))?;
+			let circuit = ArithCircuit::from_expr_with_limit(&expr, max_steps)?;
 			let oracle_id = oracles
 				.add_oracle(table_id, shape.log_values_per_row, name)
-				.structured(ArithCircuit::from(&expr))?;
+				.structured(circuit, structured.max_size_log())?;
 			oracle_lookup.register_regular(*column_id, oracle_id);
 		}
 		ColumnDef::StructuredFixedSize { expr } => {
 			let oracle_id = oracles
 				.add_oracle(table_id, shape.log_values_per_row, name)
-				.transparent(expr.clone())?;
+				.transparent(Arc::clone(expr))?;
 			oracle_lookup.register_regular(*column_id, oracle_id);
 		}
 		ColumnDef::StaticExp {
@@ -646,3 +1079,308 @@ fn translate_constraint_set<F: TowerField>(
 		constraints: compiled_constraints,
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use binius_compute::cpu::alloc::CpuComputeAllocator;
+	use binius_field::{arch::OptimalUnderlier128b, as_packed_field::PackedType};
+
+	use super::*;
+	use crate::builder::{
+		B16, B32, BitPermutation, StructuredDynSize,
+		test_utils::{ClosureFiller, validate_system_witness},
+	};
+
+	#[test]
+	fn test_checkpoint_restore() {
+		let mut cs = ConstraintSystem::<B128>::new();
+		let channel = cs.add_channel("channel");
+		let mut table = cs.add_table("kept");
+		let col = table.add_committed::<B32, 1>("col");
+		table.push(channel, [col]);
+		drop(table);
+
+		let checkpoint = cs.checkpoint();
+		let before = cs.to_string();
+
+		let mut table = cs.add_table("experimental");
+		let other = table.add_committed::<B32, 1>("other");
+		table.assert_zero("other = 0", other.into());
+		drop(table);
+		cs.add_channel("experimental");
+		let mut table = TableBuilder::new(&mut cs.tables[0]);
+		table.add_committed::<B32, 1>("extra");
+		assert_eq!(cs.tables.len(), 2);
+
+		cs.restore(checkpoint);
+		assert_eq!(cs.tables.len(), 1);
+		assert!(cs.tables.iter().all(|table| table.name != "experimental"));
+		assert_eq!(cs.tables[0].columns.len(), 1);
+		assert_eq!(cs.channels.len(), 1);
+		assert_eq!(cs.to_string(), before);
+		cs.compile().unwrap();
+	}
+
+	#[test]
+	fn test_freeze() {
+		let mut cs = ConstraintSystem::<B128>::new();
+		let mut table = cs.add_table("squares");
+		let table_id = table.id();
+		let x = table.add_committed::<B32, 1>("x");
+		let y = table.add_committed::<B32, 1>("y");
+		table.assert_zero("y = x^2", y - x * x);
+		drop(table);
+		let display = cs.to_string();
+
+		let frozen = cs.freeze().unwrap();
+		assert_eq!(frozen.tables.len(), 1);
+		assert_eq!(frozen.to_string(), display);
+		assert_eq!(frozen.degree_histogram(), BTreeMap::from([(2, 1)]));
+		assert_eq!(frozen.compiled().table_size_specs.len(), 1);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&frozen, &allocator);
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |events: &[u32], index| {
+					let mut x_col = index.get_scalars_mut(x)?;
+					let mut y_col = index.get_scalars_mut(y)?;
+					for (i, &event) in events.iter().enumerate() {
+						x_col[i] = B32::new(event);
+						y_col[i] = x_col[i].square();
+					}
+					Ok(())
+				}),
+				&(0..16).collect::<Vec<_>>(),
+			)
+			.unwrap();
+		validate_system_witness::<OptimalUnderlier128b>(&frozen, witness, vec![]);
+
+		let mut cs = frozen.into_inner();
+		cs.add_table("more");
+		assert_eq!(cs.tables.len(), 2);
+	}
+
+	#[test]
+	fn test_structured_report() {
+		let mut cs = ConstraintSystem::<B128>::new();
+		let mut table = cs.add_table("incrementing");
+		table.require_power_of_two_size();
+		table.add_committed::<B32, 1>("unlisted");
+		table.add_structured::<B32>("counter", StructuredDynSize::Incrementing { max_size_log: 8 });
+		drop(table);
+		cs.add_table("empty");
+
+		let report = cs.structured_report();
+		let values = (0..5)
+			.map(|i| B128::new(i).to_string())
+			.collect::<Vec<_>>()
+			.join(", ");
+		assert_eq!(
+			report,
+			format!(
+				"TABLE incrementing\n    STRUCTURED counter: Incrementing {{ max_size_log: 8 }}, \
+				 n_vars 8, degree 1, values [{values}]\n"
+			)
+		);
+	}
+
+	#[test]
+	fn test_find_duplicate_structured() {
+		let mut cs = ConstraintSystem::<B128>::new();
+		let mut ids = Vec::new();
+		for name in ["first", "second"] {
+			let mut table = cs.add_table(name);
+			table.require_power_of_two_size();
+			let incrementing = table.add_structured::<B32>(
+				"incrementing",
+				StructuredDynSize::Incrementing { max_size_log: 8 },
+			);
+			let parity = table
+				.add_structured::<B32>("parity", StructuredDynSize::Parity { max_size_log: 8 });
+			ids.push((table.id(), incrementing.id(), parity.id()));
+		}
+		let mut table = cs.add_table("third");
+		table.require_power_of_two_size();
+		// The identity permutation of the bits is the incrementing column.
+		let identity = table.add_structured::<B32>(
+			"identity",
+			StructuredDynSize::BitPermute {
+				perm: BitPermutation::new(&(0..8).collect::<Vec<_>>()).unwrap(),
+				max_size_log: 8,
+			},
+		);
+		// A column in another field or over more variables is not the same column.
+		table.add_structured::<B16>(
+			"incrementing_b16",
+			StructuredDynSize::Incrementing { max_size_log: 8 },
+		);
+		table.add_structured::<B32>(
+			"incrementing_9",
+			StructuredDynSize::Incrementing { max_size_log: 9 },
+		);
+		let third_id = table.id();
+		drop(table);
+
+		let [
+			(first, first_incrementing, first_parity),
+			(second, second_incrementing, second_parity),
+		] = ids[..]
+		else {
+			unreachable!()
+		};
+		assert_eq!(
+			cs.find_duplicate_structured(),
+			vec![
+				vec![
+					(first, first_incrementing),
+					(second, second_incrementing),
+					(third_id, identity.id())
+				],
+				vec![(first, first_parity), (second, second_parity)],
+			]
+		);
+		assert!(
+			ConstraintSystem::<B128>::new()
+				.find_duplicate_structured()
+				.is_empty()
+		);
+	}
+
+	#[test]
+	fn test_degree_histogram() {
+		let mut cs = ConstraintSystem::<B128>::new();
+		let mut table = cs.add_table("first");
+		let a = table.add_committed::<B32, 1>("a");
+		let b = table.add_committed::<B32, 1>("b");
+		let c = table.add_committed::<B32, 4>("c");
+		table.assert_zero("linear", a + b);
+		table.assert_zero("quadratic", a * b - a);
+		table.assert_zero("cubic", c * c * c);
+		drop(table);
+		let mut table = cs.add_table("second");
+		let d = table.add_committed::<B32, 1>("d");
+		table.assert_zero("linear", d.into());
+		table.assert_zero("quadratic", d * d + d);
+		drop(table);
+
+		assert_eq!(cs.degree_histogram(), BTreeMap::from([(1, 2), (2, 2), (3, 1)]));
+		assert!(
+			ConstraintSystem::<B128>::new()
+				.degree_histogram()
+				.is_empty()
+		);
+	}
+
+	#[test]
+	fn test_estimate_proving_cost() {
+		let mut cs = ConstraintSystem::<B128>::new();
+		let mut table = cs.add_table("first");
+		let a = table.add_committed::<B32, 1>("a");
+		let b = table.add_committed::<B32, 1>("b");
+		let c = table.add_committed::<B32, 4>("c");
+		table.assert_zero("linear", a + b);
+		table.assert_zero("quadratic", a * b - a);
+		table.assert_zero("cubic", c * c * c);
+		drop(table);
+		let mut table = cs.add_table("second");
+		let d = table.add_committed::<B32, 1>("d");
+		table.assert_zero("linear", d.into());
+		table.assert_zero("quadratic", d * d + d);
+		drop(table);
+
+		let estimate = cs.estimate_proving_cost(&[1 << 10, 1 << 8]);
+		let doubled = cs.estimate_proving_cost(&[1 << 11, 1 << 9]);
+		assert_eq!(doubled.constraint_evals, 2 * estimate.constraint_evals);
+		assert_eq!(doubled.committed_bits, 2 * estimate.committed_bits);
+		// One more round for each of the three partitions with constraints.
+		assert_eq!(doubled.sumcheck_rounds, estimate.sumcheck_rounds + 3);
+		// The first table: a and b are in a partition of 10 variables, c in one of 12 variables.
+		// The second table: d is in a partition of 8 variables.
+		assert_eq!(estimate.sumcheck_rounds, 10 + 12 + 8);
+		assert_eq!(estimate.committed_bits, ((32 * 2 + 32 * 4) << 10) + (32 << 8));
+		// An empty table is not proven.
+		assert_eq!(
+			cs.estimate_proving_cost(&[0, 1 << 8]).constraint_evals,
+			cs.tables[1].stat().assert_zero_cost_approx() as u64 * (1 << 8)
+		);
+	}
+
+	#[test]
+	fn test_suggest_structured() {
+		let mut cs = ConstraintSystem::<B128>::new();
+		let mut table = cs.add_table("committed");
+		let table_id = table.id();
+		let incrementing = table.add_committed::<B32, 1>("incrementing");
+		let constant = table.add_committed::<B32, 1>("constant");
+		let affine = table.add_committed::<B32, 1>("affine");
+		let big_endian = table.add_committed::<B32, 1>("big_endian");
+		let squares = table.add_committed::<B32, 1>("squares");
+		// Only committed columns are analyzed.
+		table.add_computed("computed", incrementing + B32::new(1));
+		drop(table);
+
+		let slope = B32::new(0x1234);
+		let intercept = B32::new(7);
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |events: &[u32], index| {
+					let mut incrementing = index.get_scalars_mut(incrementing)?;
+					let mut constant = index.get_scalars_mut(constant)?;
+					let mut affine = index.get_scalars_mut(affine)?;
+					let mut big_endian = index.get_scalars_mut(big_endian)?;
+					let mut squares = index.get_scalars_mut(squares)?;
+					for (i, &event) in events.iter().enumerate() {
+						incrementing[i] = B32::new(event);
+						constant[i] = B32::new(5);
+						affine[i] = slope * B32::new(event) + intercept;
+						big_endian[i] = B32::new(event.reverse_bits() >> (32 - 4));
+						squares[i] = B32::new(event * event);
+					}
+					Ok(())
+				}),
+				&(0..16).collect::<Vec<_>>(),
+			)
+			.unwrap();
+
+		let suggestions = cs.suggest_structured(&mut witness).unwrap();
+		let patterns = suggestions
+			.iter()
+			.map(|suggestion| (suggestion.name.as_str(), suggestion.pattern.clone()))
+			.collect::<Vec<_>>();
+		assert_eq!(
+			patterns,
+			[
+				(
+					"incrementing",
+					SuggestedPattern::Structured(StructuredDynSize::Incrementing {
+						max_size_log: 4
+					})
+				),
+				("constant", SuggestedPattern::Constant(B128::new(5))),
+				(
+					"affine",
+					SuggestedPattern::Affine(Affine {
+						slope: B128::from(slope),
+						intercept: B128::from(intercept),
+						max_size_log: 4,
+					})
+				),
+				(
+					"big_endian",
+					SuggestedPattern::Structured(StructuredDynSize::IncrementingBigEndian {
+						max_size_log: 4
+					})
+				),
+			]
+		);
+		assert_eq!(suggestions[0].id, incrementing.id());
+		assert_eq!(suggestions[0].table_id, table_id);
+	}
+}
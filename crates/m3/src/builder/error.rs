@@ -1,7 +1,11 @@
 // Copyright 2025 Irreducible Inc.
 
-use std::cell::{BorrowError, BorrowMutError};
+use std::{
+	cell::{BorrowError, BorrowMutError},
+	ops::Range,
+};
 
+use binius_compute::alloc::Error as AllocError;
 use binius_core::{oracle::Error as OracleError, polynomial::Error as PolynomialError};
 use binius_math::Error as MathError;
 
@@ -20,10 +24,26 @@ pub enum Error {
 	},
 	#[error("cannot construct witness index for empty table {table_id}")]
 	EmptyTable { table_id: TableId },
+	#[error(
+		"failed to allocate the witness of table {table_id}; try raising the allocator capacity: {source}"
+	)]
+	Alloc {
+		table_id: TableId,
+		#[source]
+		source: AllocError,
+	},
 	#[error("failed to write element to a column with a lower tower height")]
 	FieldElementTooBig,
+	#[error("column {0:?} is not a structured column")]
+	NotStructuredColumn(ColumnId),
 	#[error("structured column error: {0}")]
 	Structured(#[from] StructuredError),
+	#[error("table {table_id} has more than one zero constraint named {name:?}")]
+	DuplicateConstraintName { table_id: TableId, name: String },
+	#[error("the witness has {expected} tables, the constraint system has {actual}")]
+	IncorrectNumberOfTables { expected: usize, actual: usize },
+	#[error("table {table_id} of the witness does not match the constraint system: {reason}")]
+	IncompatibleTable { table_id: TableId, reason: String },
 	#[error("table {table_id} index has already been initialized")]
 	TableIndexAlreadyInitialized { table_id: TableId },
 	#[error(
@@ -33,6 +53,10 @@ pub enum Error {
 		column_table_id: TableId,
 		witness_table_id: TableId,
 	},
+	#[error(
+		"table {table_id} has blinding rows and a flush without selectors, which would flush the blinding rows"
+	)]
+	UnselectedFlushWithBlindingRows { table_id: TableId },
 	#[error("table {table_id} is required to have a power-of-two size, instead got {size}")]
 	TableSizePowerOfTwoRequired { table_id: TableId, size: usize },
 	#[error("table {table_id} is required to have a fixed power-of-two size, instead got {size}")]
@@ -50,8 +74,46 @@ pub enum Error {
 		"the table index was initialized for {expected} events; attempted to fill with {actual}"
 	)]
 	IncorrectNumberOfTableEvents { expected: usize, actual: usize },
+	#[error(
+		"segment {segment:?} must start where the previous one ends, have a power of two length between {min_len} and the table capacity, and start at a multiple of its length"
+	)]
+	InvalidSegment {
+		segment: Range<usize>,
+		min_len: usize,
+	},
+	#[error(
+		"the segments cover {covered} rows; expected them to cover the table capacity {capacity}"
+	)]
+	IncompleteSegments { covered: usize, capacity: usize },
+	#[error(
+		"committed column {column:?} of table {table_id} was not written on the rows from {row} on"
+	)]
+	UnwrittenColumn {
+		table_id: TableId,
+		column: String,
+		row: usize,
+	},
+	#[error("column {column:?} has {expected} values; attempted to write {actual}")]
+	IncorrectColumnLength {
+		column: ColumnId,
+		expected: usize,
+		actual: usize,
+	},
+	#[error(
+		"phase {phase} of table {table_id} is filled out of order, the next of its {n_phases} phases is {expected}"
+	)]
+	PhaseOutOfOrder {
+		table_id: TableId,
+		phase: usize,
+		expected: usize,
+		n_phases: usize,
+	},
 	#[error("table fill error: {0}")]
 	TableFill(anyhow::Error),
+	#[error("table chunk callback error: {0}")]
+	ChunkCallback(anyhow::Error),
+	#[error("table {table_id} has blinding rows, which a chunked fill does not fill")]
+	ChunkedFillWithBlindingRows { table_id: TableId },
 	#[error("math error: {0}")]
 	Math(#[from] MathError),
 	#[error("oracle error: {0}")]
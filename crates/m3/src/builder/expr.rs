@@ -7,7 +7,7 @@ use getset::{CopyGetters, Getters};
 use super::{column::Col, table::TableId};
 
 /// A constraint that the evaluation of an expression over a table is zero at every row.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ZeroConstraint<F: Field> {
 	pub name: String,
 	pub expr: ArithCircuit<F>,
@@ -53,7 +53,7 @@ impl<F: TowerField, const V: usize> std::ops::Add<Self> for Col<F, V> {
 	type Output = Expr<F, V>;
 
 	fn add(self, rhs: Self) -> Self::Output {
-		assert_eq!(self.table_id, rhs.table_id);
+		assert_eq!(self.table_id, rhs.table_id, "cannot combine the columns of different tables");
 
 		let lhs_expr = ArithExpr::Var(self.partition_index.0);
 		let rhs_expr = ArithExpr::Var(rhs.partition_index.0);
@@ -69,7 +69,7 @@ impl<F: TowerField, const V: usize> std::ops::Add<Col<F, V>> for Expr<F, V> {
 	type Output = Expr<F, V>;
 
 	fn add(self, rhs: Col<F, V>) -> Self::Output {
-		assert_eq!(self.table_id, rhs.table_id);
+		assert_eq!(self.table_id, rhs.table_id, "cannot combine the columns of different tables");
 
 		let rhs_expr = ArithExpr::Var(rhs.partition_index.0);
 		Expr {
@@ -83,7 +83,7 @@ impl<F: TowerField, const V: usize> std::ops::Add<Expr<F, V>> for Expr<F, V> {
 	type Output = Expr<F, V>;
 
 	fn add(self, rhs: Expr<F, V>) -> Self::Output {
-		assert_eq!(self.table_id, rhs.table_id);
+		assert_eq!(self.table_id, rhs.table_id, "cannot combine the columns of different tables");
 		Expr {
 			table_id: self.table_id,
 			expr: self.expr + rhs.expr,
@@ -122,7 +122,7 @@ impl<F: TowerField, const V: usize> std::ops::Sub<Self> for Col<F, V> {
 	type Output = Expr<F, V>;
 
 	fn sub(self, rhs: Self) -> Self::Output {
-		assert_eq!(self.table_id, rhs.table_id);
+		assert_eq!(self.table_id, rhs.table_id, "cannot combine the columns of different tables");
 		let lhs_expr = ArithExpr::Var(self.partition_index.0);
 		let rhs_expr = ArithExpr::Var(rhs.partition_index.0);
 
@@ -145,7 +145,7 @@ impl<F: TowerField, const V: usize> std::ops::Sub<Expr<F, V>> for Expr<F, V> {
 	type Output = Expr<F, V>;
 
 	fn sub(self, rhs: Expr<F, V>) -> Self::Output {
-		assert_eq!(self.table_id, rhs.table_id);
+		assert_eq!(self.table_id, rhs.table_id, "cannot combine the columns of different tables");
 		Expr {
 			table_id: self.table_id,
 			expr: self.expr - rhs.expr,
@@ -200,7 +200,7 @@ impl<F: TowerField, const V: usize> std::ops::Mul<Expr<F, V>> for Expr<F, V> {
 	type Output = Expr<F, V>;
 
 	fn mul(self, rhs: Expr<F, V>) -> Self::Output {
-		assert_eq!(self.table_id, rhs.table_id);
+		assert_eq!(self.table_id, rhs.table_id, "cannot combine the columns of different tables");
 		Expr {
 			table_id: self.table_id,
 			expr: self.expr * rhs.expr,
@@ -13,8 +13,10 @@ pub mod table;
 #[cfg(feature = "test_utils")]
 pub mod test_utils;
 pub mod types;
+pub mod verifier;
 pub mod witness;
 
+pub use binius_macros::TableSchema;
 pub use channel::*;
 pub use column::*;
 pub use constraint_system::*;
@@ -22,7 +24,11 @@ pub use error::*;
 pub use expr::*;
 pub use indexed_lookup::*;
 pub use stat::*;
-pub use structured::StructuredDynSize;
+pub use structured::{
+	Affine, BitPermutation, Embed, Scaled, Structured, StructuredCost, StructuredDynSize,
+	StructuredMleBatch, StructuredTable, SubElementBasis, Sum, VarAllocator,
+};
 pub use table::*;
 pub use types::*;
+pub use verifier::*;
 pub use witness::*;
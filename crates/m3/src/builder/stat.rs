@@ -178,6 +178,24 @@ impl TableStat {
 	}
 }
 
+/// A modeled count of the work to prove a constraint system with given table sizes.
+///
+/// This is created with
+/// [`ConstraintSystem::estimate_proving_cost`](super::ConstraintSystem::estimate_proving_cost).
+/// The model is deliberately coarse and only meant to compare circuit designs: the counts grow
+/// with the proving time, but they are not calibrated to it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CostEstimate {
+	/// The number of committed bits over all rows of all tables.
+	pub committed_bits: u64,
+	/// The number of zerocheck sumcheck rounds, one per variable of every table partition with
+	/// zero constraints.
+	pub sumcheck_rounds: u64,
+	/// The cost of evaluating the zero constraints on all rows, i.e. the
+	/// [`TableStat::assert_zero_cost_approx`] of every table times the number of rows.
+	pub constraint_evals: u64,
+}
+
 impl fmt::Display for TableStat {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		writeln!(f, "table '{}':", self.name)?;
@@ -1,9 +1,11 @@
 // Copyright 2025 Irreducible Inc.
 
-use binius_field::{ExtensionField, TowerField};
-use binius_math::ArithExpr;
+use std::{fmt::Debug, iter, marker::PhantomData, mem, ops::Range};
 
-use crate::builder::B1;
+use binius_field::{ExtensionField, Field, TowerField, inner_product};
+use binius_math::{ArithCircuit, ArithExpr};
+
+use crate::builder::{B1, B128};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -13,8 +15,68 @@ pub enum Error {
 	#[error("table size must be less than or equal to max_log_size")]
 	TableSizeTooLarge,
 
-	#[error("math error: {0}")]
-	Math(#[from] binius_math::Error),
+	#[error("cannot fold a structured column with no variables")]
+	NoVariablesToFold,
+
+	#[error(
+		"the multilinear extension of a table with 2^{log_size} values is too large to interpolate"
+	)]
+	TableTooLargeToInterpolate { log_size: usize },
+
+	#[error("the scaling factor is not in the field of the column")]
+	FactorNotInField,
+
+	#[error("row {row} is beyond the size of a table with 2^{n_vars} rows")]
+	RowBeyondTableSize { row: usize, n_vars: usize },
+
+	#[error("row {row} is an event row of a table with {logical_size} events")]
+	NotPaddingRow { row: usize, logical_size: usize },
+
+	#[error("{perm:?} is not a permutation of the {max_size_log} bits of the row index")]
+	InvalidBitPermutation {
+		perm: Vec<usize>,
+		max_size_log: usize,
+	},
+
+	#[error("math error in {context}: {source}")]
+	Math {
+		/// The structured operation that failed, and the column it failed on if known.
+		context: String,
+		#[source]
+		source: binius_math::Error,
+	},
+}
+
+impl Error {
+	/// Returns an [`Error::Math`] with the given context, which describes the failed operation.
+	pub fn math(context: impl ToString, source: binius_math::Error) -> Self {
+		Self::Math {
+			context: context.to_string(),
+			source,
+		}
+	}
+
+	/// Adds the name of the column on which the operation failed to the context of an
+	/// [`Error::Math`].
+	///
+	/// The other errors are returned unchanged.
+	pub fn for_column(self, name: &str) -> Self {
+		match self {
+			Self::Math { context, source } => Self::Math {
+				context: format!("{context} of column {name:?}"),
+				source,
+			},
+			err => err,
+		}
+	}
+}
+
+/// Converts a math error without the context of the operation, such as one raised with `?` in
+/// [`Structured::expr`] of a custom column. [`Error::math`] adds the context.
+impl From<binius_math::Error> for Error {
+	fn from(source: binius_math::Error) -> Self {
+		Self::math("the expression", source)
+	}
 }
 
 /// Specifications of structured columns that generated from a dynamic table size.
@@ -30,23 +92,652 @@ pub enum StructuredDynSize {
 		/// The base-2 logarithm of the maximum size of the column.
 		max_size_log: usize,
 	},
+	/// A column whose values are incrementing binary field elements with the bits of the index
+	/// assigned to the field basis in big-endian order.
+	///
+	/// See [`Endianness::Big`].
+	IncrementingBigEndian {
+		/// The base-2 logarithm of the maximum size of the column.
+		max_size_log: usize,
+	},
+	/// A column whose value is the parity of the row index, i.e. `0` on even rows and `1` on odd
+	/// rows.
+	///
+	/// The multilinear extension is just the first variable. This is the same column as
+	/// [`Self::LowBits`] with `bits` equal to one.
+	Parity {
+		/// The base-2 logarithm of the maximum size of the column.
+		max_size_log: usize,
+	},
+	/// A column whose values are the low `bits` bits of the row index, i.e. `i & (2^bits - 1)`,
+	/// as binary field elements in lexicographic order.
+	///
+	/// This is the incrementing column masked to its first `bits` variables, which repeats every
+	/// `2^bits` rows. The multilinear extension is the incrementing expression with the weights of
+	/// the variables from `bits` on set to zero. With `bits` at least `max_size_log` the column is
+	/// the same as [`Self::Incrementing`].
+	LowBits {
+		/// The number of low bits of the row index.
+		bits: usize,
+		/// The base-2 logarithm of the maximum size of the column.
+		max_size_log: usize,
+	},
+	/// A column whose values are the bitwise complement of the row index over `max_size_log`
+	/// bits, i.e. `(2^max_size_log - 1) - i`.
+	///
+	/// The multilinear extension is $\sum_i (1 - X_i) \beta_i$. The number of complemented bits is
+	/// `max_size_log` and not the number of variables of the table: in a smaller table the high
+	/// variables are zero, so their complemented bits are one.
+	Complement {
+		/// The base-2 logarithm of the maximum size of the column.
+		max_size_log: usize,
+	},
+	/// A column whose values are the Z-order (Morton) codes of the row indices of a 2D grid.
+	///
+	/// The low `x_bits` bits of the row index are the x coordinate and the next `y_bits` bits the
+	/// y coordinate. The code interleaves the bits of the coordinates, starting with x, and the
+	/// remaining bits of the longer coordinate follow, see [`morton_bit_position`]. This is a
+	/// permutation of the bits of the index, so the multilinear extension is the incrementing
+	/// expression with the basis elements permuted. The maximum size of the column is
+	/// `2^(x_bits + y_bits)`.
+	Morton {
+		/// The number of bits of the x coordinate.
+		x_bits: usize,
+		/// The number of bits of the y coordinate.
+		y_bits: usize,
+	},
+	/// A column whose values are the row indices with their bits permuted.
+	///
+	/// Bit `i` of the row index is moved to bit `perm[i]` of the value, so the multilinear
+	/// extension is $\sum_i X_i \beta_{perm[i]}$. `perm` must permute the `max_size_log` bits of
+	/// the row index. This generalizes the columns that reorder the bits of the index: the
+	/// reversal permutation gives [`Self::IncrementingBigEndian`], the identity
+	/// [`Self::Incrementing`], and [`morton_bit_position`] gives [`Self::Morton`].
+	BitPermute {
+		/// The position in the value of each bit of the row index.
+		perm: BitPermutation,
+		/// The base-2 logarithm of the maximum size of the column.
+		max_size_log: usize,
+	},
+}
+
+/// The order in which the bits of a row index are assigned to the $\mathbb{F}_2$-basis of a field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Endianness {
+	/// The $i$-th bit of the index is weighted by the $i$-th basis element.
+	#[default]
+	Little,
+	/// The $i$-th bit of an $n$-bit index is weighted by the $(n - 1 - i)$-th basis element.
+	Big,
+}
+
+/// How the multilinear extension of a structured column is lowered to an arithmetic circuit.
+///
+/// The lowerings evaluate to the same values and only differ in the shape of the circuit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Lowering {
+	/// The sum of the weighted variables, as in [`StructuredDynSize::expr`].
+	///
+	/// There is one multiplication per variable, and the products are added in a chain, so the
+	/// depth of the circuit is linear in the number of variables.
+	#[default]
+	Flat,
+	/// A nested form over the generators of the tower.
+	///
+	/// The basis element $\beta_{2^k + j}$ is $\beta_{2^k} \beta_j$ for $j < 2^k$, so the sum of
+	/// the weighted variables over a range of $2^{k+1}$ basis elements is the sum over the first
+	/// half plus $\beta_{2^k}$ times the sum over the second half. Nesting this rule, as for
+	/// Horner's method, gives a circuit whose depth is logarithmic in the number of variables.
+	Horner,
+}
+
+/// How [`StructuredDynSize::row_value`] treats a row index beyond the size of the table.
+///
+/// A table with `2^n_vars` rows holds the multilinear extension of the column with the variables
+/// from `n_vars` on set to zero, and the fills write the values of the rows of the table only, so
+/// a row past the end of the table has no value of its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BeyondTableSize {
+	/// The row index wraps around the table, i.e. row `i` has the value of row `i % 2^n_vars`.
+	///
+	/// This drops the high bits of the index, which are the variables that the table sets to
+	/// zero, so it is the value of the extension at the truncated point of the hypercube.
+	#[default]
+	Wrap,
+	/// The row index saturates at the last row of the table.
+	Saturate,
+	/// The row index is rejected with [`Error::RowBeyondTableSize`].
+	Error,
+}
+
+/// A permutation of the bits of the row index, for [`StructuredDynSize::BitPermute`].
+///
+/// The positions are stored inline, so that [`StructuredDynSize`] is `Copy`, which limits the
+/// permutation to [`Self::MAX_LEN`] bits. This is more than the bits of any row index.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct BitPermutation {
+	positions: [u8; Self::MAX_LEN],
+	len: usize,
+}
+
+impl BitPermutation {
+	/// The maximum number of bits that a permutation permutes.
+	pub const MAX_LEN: usize = 64;
+
+	/// Creates the permutation that moves bit `i` to bit `perm[i]`.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::InvalidBitPermutation`] if `perm` is not a permutation of `0..perm.len()` or if
+	///   it has more than [`Self::MAX_LEN`] entries.
+	pub fn new(perm: &[usize]) -> Result<Self, Error> {
+		let mut seen = [false; Self::MAX_LEN];
+		let is_permutation = perm.len() <= Self::MAX_LEN
+			&& perm
+				.iter()
+				.all(|&position| position < perm.len() && !mem::replace(&mut seen[position], true));
+		if !is_permutation {
+			return Err(Error::InvalidBitPermutation {
+				perm: perm.to_vec(),
+				max_size_log: perm.len(),
+			});
+		}
+		let mut positions = [0; Self::MAX_LEN];
+		for (dst, &position) in positions.iter_mut().zip(perm) {
+			*dst = position as u8;
+		}
+		Ok(Self {
+			positions,
+			len: perm.len(),
+		})
+	}
+
+	/// Returns the number of permuted bits.
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Returns whether the permutation permutes no bits.
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Returns the position to which bit `i` is moved.
+	///
+	/// ## Panics
+	///
+	/// * If `i` is not less than [`Self::len`].
+	pub fn position(&self, i: usize) -> usize {
+		assert!(i < self.len, "bit {i} is not permuted");
+		self.positions[i] as usize
+	}
+
+	/// Returns the positions to which the bits are moved, in order.
+	pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+		self.positions[..self.len]
+			.iter()
+			.map(|&position| position as usize)
+	}
+}
+
+impl Debug for BitPermutation {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_list().entries(self.iter()).finish()
+	}
 }
 
 impl StructuredDynSize {
 	/// Returns an arithmetic expression that represents the multilinear extension of the
 	/// structured column.
+	///
+	/// The extensions of the built-in columns are affine, so the expression is the sum of the
+	/// weighted variables plus the constant term, if it is not zero.
 	pub fn expr<F: TowerField>(self) -> Result<ArithExpr<F>, Error> {
+		self.check_field_bits(F::N_BITS)?;
+		self.check_params()?;
+		let mut expr = basis_weighted_expr(self.max_size_log(), |i| self.var_weight::<F>(i));
+		let constant = self.constant_term::<F>();
+		if constant != F::ZERO {
+			expr += ArithExpr::Const(constant);
+		}
+		Ok(expr)
+	}
+
+	/// Returns the column of the lane index of each row within the packed elements of type
+	/// `PSub`, i.e. `i % PSub::WIDTH`.
+	///
+	/// This is [`Self::LowBits`] with `PSub::LOG_WIDTH` bits. `PSub` is the packed type that stores
+	/// the column, i.e. `PackedSubfield<P, FSub>` for a column of `FSub` in a witness packed as
+	/// `P`, so the column follows the packing if it changes. The column is filled with
+	/// [`fill_lane_id`](crate::gadgets::structured::fill_lane_id).
+	pub fn lane_id<PSub: binius_field::PackedField>(max_size_log: usize) -> Self {
+		StructuredDynSize::LowBits {
+			bits: PSub::LOG_WIDTH,
+			max_size_log,
+		}
+	}
+
+	/// Returns the column of the position of each row within the elements of `F` that pack
+	/// consecutive rows of `FSub`, i.e. `i % D` where `D` is the degree of `F` over `FSub`.
+	///
+	/// This is [`Self::LowBits`] with `log2(D)` bits, e.g. the index `i % 4` of a B32 value in a
+	/// B128 element. The basis element at which the value of each row embeds is the
+	/// [`SubElementBasis`] column. The column is filled with
+	/// [`fill_sub_element_index`](crate::gadgets::structured::fill_sub_element_index).
+	pub fn sub_element_index<F, FSub>(max_size_log: usize) -> Self
+	where
+		F: ExtensionField<FSub>,
+		FSub: Field,
+	{
+		StructuredDynSize::LowBits {
+			bits: F::LOG_DEGREE,
+			max_size_log,
+		}
+	}
+
+	/// Returns the multilinear extension of the structured column as an arithmetic circuit with
+	/// the given lowering.
+	///
+	/// The circuit evaluates to the same values as [`Self::expr`] with any lowering.
+	pub fn circuit<F: TowerField>(&self, lowering: Lowering) -> Result<ArithCircuit<F>, Error> {
+		match lowering {
+			Lowering::Flat => Ok(ArithCircuit::from(&StructuredDynSize::expr::<F>(*self)?)),
+			Lowering::Horner => {
+				let n_vars = self.n_weighted_vars();
+				if n_vars > F::N_BITS {
+					return Err(Error::MaxLogSizeTooLarge);
+				}
+				self.check_params()?;
+				// The variable weighted by the basis element of each index.
+				let var = |i: usize| match *self {
+					StructuredDynSize::IncrementingBigEndian { max_size_log } => {
+						max_size_log - 1 - i
+					}
+					StructuredDynSize::Morton { x_bits, y_bits } => {
+						morton_bit_var(x_bits, y_bits, i)
+					}
+					StructuredDynSize::BitPermute { perm, .. } => (0..perm.len())
+						.find(|&j| perm.position(j) == i)
+						.expect("perm is a permutation"),
+					_ => i,
+				};
+				let mut expr = nested_basis_expr::<F>(n_vars, var);
+				let constant = self.constant_term::<F>();
+				if constant != F::ZERO {
+					expr += ArithExpr::Const(constant);
+				}
+				Ok(ArithCircuit::from(&expr))
+			}
+		}
+	}
+
+	/// Returns a canonical encoding of the specification of the column, for observing in a
+	/// transcript.
+	///
+	/// The encoding starts with a tag byte of the variant, followed by its parameters in
+	/// declaration order as little-endian `u64`s. The bit permutation is prefixed with its length,
+	/// so distinct specifications never share an encoding.
+	pub fn transcript_bytes(&self) -> Vec<u8> {
+		let (tag, params): (u8, Vec<usize>) = match self {
+			StructuredDynSize::Incrementing { max_size_log } => (0, vec![*max_size_log]),
+			StructuredDynSize::IncrementingBigEndian { max_size_log } => (1, vec![*max_size_log]),
+			StructuredDynSize::Parity { max_size_log } => (2, vec![*max_size_log]),
+			StructuredDynSize::LowBits { bits, max_size_log } => (3, vec![*bits, *max_size_log]),
+			StructuredDynSize::Complement { max_size_log } => (4, vec![*max_size_log]),
+			StructuredDynSize::Morton { x_bits, y_bits } => (5, vec![*x_bits, *y_bits]),
+			StructuredDynSize::BitPermute { perm, max_size_log } => (
+				6,
+				iter::once(perm.len())
+					.chain(perm.iter())
+					.chain(iter::once(*max_size_log))
+					.collect(),
+			),
+		};
+		iter::once(tag)
+			.chain(
+				params
+					.into_iter()
+					.flat_map(|param| (param as u64).to_le_bytes()),
+			)
+			.collect()
+	}
+
+	/// Returns the base-2 logarithm of the maximum size of the column.
+	pub fn max_size_log(&self) -> usize {
+		match self {
+			StructuredDynSize::Incrementing { max_size_log }
+			| StructuredDynSize::IncrementingBigEndian { max_size_log }
+			| StructuredDynSize::Parity { max_size_log }
+			| StructuredDynSize::LowBits { max_size_log, .. }
+			| StructuredDynSize::Complement { max_size_log }
+			| StructuredDynSize::BitPermute { max_size_log, .. } => *max_size_log,
+			StructuredDynSize::Morton { x_bits, y_bits } => x_bits + y_bits,
+		}
+	}
+
+	/// Returns the multilinear extension of the structured column with the first variable fixed to
+	/// `challenge`.
+	///
+	/// This is the closed form of one round of folding in a sumcheck prover, so that the folded
+	/// column does not need to be materialized. For example, the incrementing column folds to a
+	/// smaller incrementing column with the basis shifted by one, plus a constant offset.
+	pub fn fold_first_var<F: TowerField>(
+		&self,
+		challenge: F,
+	) -> Result<FoldedStructured<F>, Error> {
+		FoldedStructured {
+			expr: ArithCircuit::from(&StructuredDynSize::expr::<F>(*self)?),
+			n_vars: self.max_size_log(),
+		}
+		.fold_first_var(challenge)
+	}
+
+	/// Returns the multilinear extension of the column with its first variables fixed to
+	/// `challenges`.
+	///
+	/// This is the closed form of the first `challenges.len()` rounds of folding in a sumcheck
+	/// prover. Unlike [`Self::fold_first_var`], which folds an arithmetic circuit, the extensions
+	/// of the built-in columns are affine, so the reduced column is the weights of the remaining
+	/// variables and a constant that absorbs the fixed ones. For example, the incrementing column
+	/// reduces to an incrementing column over the remaining basis elements plus the offset
+	/// $\sum_{i < k} r_i \beta_i$.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::MaxLogSizeTooLarge`] if there are more challenges than [`Self::max_size_log`] or
+	///   if the values of the column do not fit in `F`.
+	pub fn fix_prefix<F: TowerField>(
+		&self,
+		challenges: &[F],
+	) -> Result<ReducedStructured<F>, Error> {
+		if challenges.len() > self.max_size_log() || self.n_weighted_vars() > F::N_BITS {
+			return Err(Error::MaxLogSizeTooLarge);
+		}
+		self.check_params()?;
+		let weights = (0..self.max_size_log())
+			.map(|i| self.var_weight(i))
+			.collect::<Vec<_>>();
+		let (fixed, remaining) = weights.split_at(challenges.len());
+		Ok(ReducedStructured {
+			offset: self.constant_term::<F>() + inner_product(challenges, fixed),
+			weights: remaining.to_vec(),
+		})
+	}
+
+	/// Evaluates the multilinear extension of the column at `point`.
+	///
+	/// The built-in columns have affine extensions, so the evaluation is the inner product of the
+	/// point with the weights of the variables plus a constant, without building an arithmetic
+	/// circuit. For example, an incrementing column evaluates to $\sum_i r_i \beta_i$ at the point
+	/// $r$. See [`Self::evaluate_mle_tensor`]. The point may have fewer coordinates than
+	/// [`Self::max_size_log`], in which case the remaining variables are set to zero.
+	pub fn evaluate<F: TowerField>(&self, point: &[F]) -> Result<F, Error> {
+		self.evaluate_mle_tensor(point)
+	}
+
+	/// Evaluates the multilinear extension of the column at `point` by contracting the point with
+	/// the tensor of its coefficients.
+	///
+	/// The extension is $\sum_S c_S \prod_{i \in S} X_i$, so its evaluation is the contraction of
+	/// the coefficient tensor with the tensor product of the $(1, r_i)$. The coefficients of the
+	/// built-in columns vanish beyond the singleton monomials, see [`Self::mle_coefficients`], so
+	/// the contraction is $c_\emptyset + \sum_i r_i c_{\{i\}}$, linear in the number of
+	/// variables. The `structured` benchmark compares this with evaluating [`Self::circuit`]. The
+	/// point may have fewer coordinates than [`Self::max_size_log`], in which case the remaining
+	/// variables are set to zero.
+	pub fn evaluate_mle_tensor<F: TowerField>(&self, point: &[F]) -> Result<F, Error> {
+		self.check_nvars(point.len())?;
+		self.check_field_bits(F::N_BITS)?;
+		self.check_params()?;
+		let weights = (0..point.len())
+			.map(|i| self.var_weight(i))
+			.collect::<Vec<_>>();
+		Ok(self.constant_term::<F>() + inner_product(point, &weights))
+	}
+
+	/// Returns the coefficients of the multilinear extension of the column over its first
+	/// `n_vars` variables, with the remaining variables set to zero.
+	///
+	/// The coefficient at index `i` is the one of the monomial that is the product of the
+	/// variables in the bits of `i`, so there are `2^n_vars` coefficients. The built-in columns
+	/// have affine extensions, so only the constant coefficient at index `0` and the coefficients
+	/// of the singleton monomials at the indices `2^j` may be non-zero. For example, the
+	/// coefficients of an incrementing column are the basis elements at the singleton monomials.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::MaxLogSizeTooLarge`] if `n_vars` is greater than [`Self::max_size_log`] or if the
+	///   values of the column do not fit in `F`.
+	pub fn mle_coefficients<F: TowerField>(&self, n_vars: usize) -> Result<Vec<F>, Error> {
+		self.check_nvars(n_vars)?;
+		self.check_field_bits(F::N_BITS)?;
+		self.check_params()?;
+		let mut coeffs = vec![F::ZERO; 1 << n_vars];
+		coeffs[0] = self.constant_term();
+		for i in 0..n_vars {
+			coeffs[1 << i] = self.var_weight(i);
+		}
+		Ok(coeffs)
+	}
+
+	/// Returns the value of the column at the padding row `row` of a table with `logical_size`
+	/// events padded to `padded_size` rows.
+	///
+	/// This is the value of the multilinear extension at the bits of the row index, which is the
+	/// value the verifier assumes for the row. For example, an incrementing column continues its
+	/// sequence into the padding rows.
+	///
+	/// The witness of a sequential fill does not necessarily hold this value: the fill clones the
+	/// last event to complete the last segment with events, and copies that segment into the
+	/// segments after it. The copies repeat the values of that segment instead of continuing the
+	/// sequence, so a column filled by a table filler only matches this value on the rows of the
+	/// last event segment. [`WitnessIndex::fill_structured_cols`] writes this value to every
+	/// row.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::NotPaddingRow`] if `row` is an event row.
+	/// * [`Error::RowBeyondTableSize`] if `row` is not less than `padded_size`.
+	/// * [`Error::TableSizeTooLarge`] if `padded_size` is not a power of two of at most
+	///   `2^max_size_log` rows.
+	/// * [`Error::MaxLogSizeTooLarge`] if the values of the column do not fit in `F`.
+	/// * [`Error::InvalidBitPermutation`] if the permutation of the column is invalid.
+	///
+	/// [`WitnessIndex::fill_structured_cols`]: super::WitnessIndex::fill_structured_cols
+	pub fn padding_value<F: TowerField>(
+		&self,
+		logical_size: usize,
+		padded_size: usize,
+		row: usize,
+	) -> Result<F, Error> {
+		if !padded_size.is_power_of_two() || padded_size.ilog2() as usize > self.max_size_log() {
+			return Err(Error::TableSizeTooLarge);
+		}
+		let n_vars = padded_size.ilog2() as usize;
+		if row < logical_size {
+			return Err(Error::NotPaddingRow { row, logical_size });
+		}
+		if row >= padded_size {
+			return Err(Error::RowBeyondTableSize { row, n_vars });
+		}
+		self.check_field_bits(F::N_BITS)?;
+		self.check_params()?;
+		Ok(self.hypercube_value(n_vars, row))
+	}
+
+	/// Returns the value of the column at row `row` of a table with `2^n_vars` rows.
+	///
+	/// The rows of the table have the values that the fill gadgets write. A row past the end of
+	/// the table is handled by `beyond`, see [`BeyondTableSize`]. With the default
+	/// [`BeyondTableSize::Wrap`], this is the value of the extension at the bits of the row index
+	/// over the `n_vars` variables of the table. Evaluating the full expression of the column at
+	/// the bits of such a row instead gives the value of the row in a table of the maximum size.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::MaxLogSizeTooLarge`] if `n_vars` is greater than [`Self::max_size_log`] or if the
+	///   values of the column do not fit in `F`.
+	/// * [`Error::RowBeyondTableSize`] if `row` is beyond the table and `beyond` is
+	///   [`BeyondTableSize::Error`].
+	pub fn row_value<F: TowerField>(
+		&self,
+		n_vars: usize,
+		row: usize,
+		beyond: BeyondTableSize,
+	) -> Result<F, Error> {
+		self.check_nvars(n_vars)?;
+		self.check_field_bits(F::N_BITS)?;
+		self.check_params()?;
+		let size = 1usize.checked_shl(n_vars as u32).unwrap_or(usize::MAX);
+		let row = if row < size {
+			row
+		} else {
+			match beyond {
+				BeyondTableSize::Wrap => row & (size - 1),
+				BeyondTableSize::Saturate => size - 1,
+				BeyondTableSize::Error => return Err(Error::RowBeyondTableSize { row, n_vars }),
+			}
+		};
+		Ok(self.hypercube_value(n_vars, row))
+	}
+
+	/// Returns the values of the column on the `2^n_vars` rows of a table, packed as `P`.
+	///
+	/// The values are computed from the closed form of the column, without building its
+	/// expression, see [`Self::evaluate_all_cube`]. A table with fewer rows than the packing width
+	/// is padded with zeros in its single packed element.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::MaxLogSizeTooLarge`] if `n_vars` is greater than [`Self::max_size_log`] or if the
+	///   values of the column do not fit in `F`.
+	pub fn materialize<F, P>(&self, n_vars: usize) -> Result<Vec<P>, Error>
+	where
+		F: TowerField,
+		P: binius_field::PackedField<Scalar = F>,
+	{
+		self.evaluate_all_cube(n_vars)
+	}
+
+	/// Returns the evaluations of the multilinear extension of the column over `n_vars` variables
+	/// at every point of the hypercube, packed as `P`.
+	///
+	/// The lanes of a packed element are the points of the lowest `P::LOG_WIDTH` variables, so the
+	/// first packed element is computed lane by lane and every other one takes a single packed
+	/// addition: the element of index `k` is the element of index `k` with its lowest set bit
+	/// cleared plus the broadcast weight of that bit.
+	/// A hypercube with fewer points than the packing width is padded with zeros.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::MaxLogSizeTooLarge`] if `n_vars` is greater than [`Self::max_size_log`] or if the
+	///   values of the column do not fit in the scalar field of `P`.
+	pub fn evaluate_all_cube<P>(&self, n_vars: usize) -> Result<Vec<P>, Error>
+	where
+		P: binius_field::PackedField<Scalar: TowerField>,
+	{
+		self.check_nvars(n_vars)?;
+		self.check_field_bits(<P::Scalar as binius_field::BinaryField>::N_BITS)?;
+		self.check_params()?;
+		let log_width = P::LOG_WIDTH.min(n_vars);
+		let weights = (log_width..n_vars)
+			.map(|i| P::broadcast(self.var_weight(i)))
+			.collect::<Vec<_>>();
+		let mut values = Vec::with_capacity(1 << (n_vars - log_width));
+		values.push(P::from_fn(|lane| {
+			if lane < 1 << log_width {
+				self.hypercube_value(log_width, lane)
+			} else {
+				P::Scalar::ZERO
+			}
+		}));
+		for k in 1usize..1 << (n_vars - log_width) {
+			values.push(values[k & (k - 1)] + weights[k.trailing_zeros() as usize]);
+		}
+		Ok(values)
+	}
+
+	/// Returns the value of the extension over the first `n_vars` variables at the bits of `row`.
+	fn hypercube_value<F: TowerField>(&self, n_vars: usize, row: usize) -> F {
+		(0..n_vars.min(usize::BITS as usize))
+			.filter(|&i| (row >> i) & 1 == 1)
+			.map(|i| self.var_weight::<F>(i))
+			.sum::<F>()
+			+ self.constant_term::<F>()
+	}
+
+	/// Returns the weight of variable `i` in the affine multilinear extension of the column.
+	///
+	/// This is the single source of the weights, from which the expression, the evaluations and
+	/// the values of the column are derived.
+	fn var_weight<F: TowerField>(&self, i: usize) -> F {
+		match self {
+			StructuredDynSize::Incrementing { .. } | StructuredDynSize::Complement { .. } => {
+				<F as ExtensionField<B1>>::basis(i)
+			}
+			StructuredDynSize::IncrementingBigEndian { max_size_log } => {
+				<F as ExtensionField<B1>>::basis(max_size_log - 1 - i)
+			}
+			StructuredDynSize::Parity { .. } => low_bits_weight(1, i),
+			StructuredDynSize::LowBits { bits, .. } => low_bits_weight(*bits, i),
+			StructuredDynSize::Morton { x_bits, y_bits } => {
+				<F as ExtensionField<B1>>::basis(morton_bit_position(*x_bits, *y_bits, i))
+			}
+			StructuredDynSize::BitPermute { perm, .. } => {
+				<F as ExtensionField<B1>>::basis(perm.position(i))
+			}
+		}
+	}
+
+	/// Returns the constant term of the affine multilinear extension of the column.
+	fn constant_term<F: TowerField>(&self) -> F {
 		match self {
-			StructuredDynSize::Incrementing { max_size_log } => {
-				incrementing_expr::<F>(max_size_log)
+			StructuredDynSize::Complement { max_size_log } => {
+				(0..*max_size_log).map(|i| self.var_weight::<F>(i)).sum()
 			}
+			_ => F::ZERO,
 		}
 	}
 
-	/// Returns the maximum size of the column.
-	fn max_size_log(&self) -> usize {
+	/// Returns the cost of evaluating the multilinear extension of the column over `n_vars`
+	/// variables.
+	///
+	/// This is meant to help choosing between a structured column and a committed one. For example,
+	/// an incrementing column has a degree 1 expression with one multiplication per variable.
+	pub fn cost_estimate(&self, n_vars: usize) -> StructuredCost {
+		match self {
+			StructuredDynSize::Incrementing { .. }
+			| StructuredDynSize::IncrementingBigEndian { .. }
+			| StructuredDynSize::Complement { .. }
+			| StructuredDynSize::Morton { .. }
+			| StructuredDynSize::BitPermute { .. } => StructuredCost {
+				degree: usize::from(n_vars > 0),
+				n_muls: n_vars,
+				has_closed_form: true,
+			},
+			StructuredDynSize::Parity { .. } => StructuredCost {
+				degree: usize::from(n_vars > 0),
+				n_muls: usize::from(n_vars > 0),
+				has_closed_form: true,
+			},
+			StructuredDynSize::LowBits { bits, .. } => StructuredCost {
+				degree: usize::from(n_vars > 0 && *bits > 0),
+				n_muls: n_vars.min(*bits),
+				has_closed_form: true,
+			},
+		}
+	}
+
+	/// Returns the number of variables with a non-zero weight in the multilinear extension.
+	fn n_weighted_vars(&self) -> usize {
 		match self {
-			StructuredDynSize::Incrementing { max_size_log } => *max_size_log,
+			StructuredDynSize::Incrementing { max_size_log }
+			| StructuredDynSize::IncrementingBigEndian { max_size_log }
+			| StructuredDynSize::Complement { max_size_log }
+			| StructuredDynSize::BitPermute { max_size_log, .. } => *max_size_log,
+			StructuredDynSize::Parity { max_size_log } => (*max_size_log).min(1),
+			StructuredDynSize::LowBits { bits, max_size_log } => (*max_size_log).min(*bits),
+			StructuredDynSize::Morton { x_bits, y_bits } => x_bits + y_bits,
 		}
 	}
 
@@ -59,55 +750,2737 @@ impl StructuredDynSize {
 			Ok(())
 		}
 	}
+
+	/// Checks that the permutation of a [`Self::BitPermute`] column permutes the `max_size_log`
+	/// bits of the row index.
+	fn check_params(&self) -> Result<(), Error> {
+		match self {
+			StructuredDynSize::BitPermute { perm, max_size_log } if perm.len() != *max_size_log => {
+				Err(Error::InvalidBitPermutation {
+					perm: perm.iter().collect(),
+					max_size_log: *max_size_log,
+				})
+			}
+			_ => Ok(()),
+		}
+	}
+
+	/// Checks whether the values of the column fit in a field with `n_bits` bits.
+	///
+	/// The values of the built-in columns have one bit per variable with a non-zero weight, e.g.
+	/// `max_size_log` bits for an incrementing column.
+	pub fn check_field_bits(&self, n_bits: usize) -> Result<(), Error> {
+		if self.n_weighted_vars() > n_bits {
+			Err(Error::MaxLogSizeTooLarge)
+		} else {
+			Ok(())
+		}
+	}
 }
 
-/// Returns the arithmetic expression for an incrementing column.
+/// Evaluates the multilinear extensions of several structured columns at the same point.
 ///
-/// The multilinear expression is
+/// [`StructuredDynSize::evaluate`] computes the weights of the variables, which are basis
+/// elements, and their products with the coordinates of the point for every column. The batch
+/// computes the running sums $\sum_{i < k} r_i \beta_i$ once, after which the columns with the
+/// basis in order are evaluated with a single lookup. The other columns are evaluated with
+/// [`StructuredDynSize::evaluate`].
+#[derive(Debug, Clone)]
+pub struct StructuredMleBatch<'a, F: TowerField> {
+	point: &'a [F],
+	/// `point_prefix[k]` is $\sum_{i < k} r_i \beta_i$.
+	point_prefix: Vec<F>,
+	/// `basis_prefix[k]` is $\sum_{i < k} \beta_i$.
+	basis_prefix: Vec<F>,
+}
+
+impl<'a, F: TowerField> StructuredMleBatch<'a, F> {
+	/// Precomputes the evaluation of structured columns at `point`.
+	pub fn new(point: &'a [F]) -> Self {
+		let basis = (0..F::N_BITS)
+			.map(<F as ExtensionField<B1>>::basis)
+			.collect::<Vec<_>>();
+		let point_prefix = iter::once(F::ZERO)
+			.chain(point.iter().zip(&basis).scan(F::ZERO, |sum, (&r, &beta)| {
+				*sum += r * beta;
+				Some(*sum)
+			}))
+			.collect();
+		let basis_prefix = iter::once(F::ZERO)
+			.chain(basis.iter().scan(F::ZERO, |sum, &beta| {
+				*sum += beta;
+				Some(*sum)
+			}))
+			.collect();
+		Self {
+			point,
+			point_prefix,
+			basis_prefix,
+		}
+	}
+
+	/// Evaluates the multilinear extension of one column at the point of the batch.
+	///
+	/// This returns the same value as [`StructuredDynSize::evaluate`].
+	pub fn evaluate_one(&self, variant: &StructuredDynSize) -> Result<F, Error> {
+		let n_vars = self.point.len();
+		if n_vars > variant.max_size_log() || variant.n_weighted_vars() > F::N_BITS {
+			return Err(Error::MaxLogSizeTooLarge);
+		}
+		variant.check_params()?;
+		let value = match variant {
+			StructuredDynSize::Incrementing { .. }
+			| StructuredDynSize::Parity { .. }
+			| StructuredDynSize::LowBits { .. } => self.point_prefix[variant.n_weighted_vars().min(n_vars)],
+			StructuredDynSize::Complement { max_size_log } => {
+				self.point_prefix[n_vars] + self.basis_prefix[*max_size_log]
+			}
+			// The columns that permute the basis have no running sum to look up.
+			StructuredDynSize::IncrementingBigEndian { .. }
+			| StructuredDynSize::Morton { .. }
+			| StructuredDynSize::BitPermute { .. } => variant.evaluate(self.point)?,
+		};
+		Ok(value)
+	}
+
+	/// Evaluates the multilinear extensions of `variants` at `point`.
+	///
+	/// The point may have fewer coordinates than the columns have variables, in which case the
+	/// remaining variables are set to zero, as for [`StructuredDynSize::evaluate`].
+	pub fn evaluate(variants: &[StructuredDynSize], point: &'a [F]) -> Result<Vec<F>, Error> {
+		let batch = Self::new(point);
+		variants
+			.iter()
+			.map(|variant| batch.evaluate_one(variant))
+			.collect()
+	}
+}
+
+/// A specification of a structured column over the field `F`.
 ///
-/// $$
-/// \sum_{v \in B_n} X_i \beta_i,
-/// $$
+/// This is the extension point for structured columns: implement it to define a custom structured
+/// column outside of this crate and add it to a table with
+/// [`TableBuilder::add_structured`](crate::builder::TableBuilder::add_structured). The built-in
+/// columns are the variants of [`StructuredDynSize`].
 ///
-/// where $\beta_i$ is the $i$-th basis element of the field $F$ as an $\mathbb{F}_2$ vector space.
-pub fn incrementing_expr<F: TowerField>(max_log_size: usize) -> Result<ArithExpr<F>, Error> {
-	if max_log_size > F::N_BITS {
-		return Err(Error::MaxLogSizeTooLarge);
+/// The multilinear extension returned by [`Self::expr`] is over [`Self::max_size_log`] variables,
+/// of which the expression may use any subset; it does not depend on the variables it does not
+/// use. When the table is smaller than the maximum size, the extension over the table's hypercube
+/// is the expression with the remaining high variables set to zero.
+pub trait Structured<F: TowerField = B128>: Debug + Send + Sync {
+	/// Returns an arithmetic expression that represents the multilinear extension of the
+	/// structured column.
+	fn expr(&self) -> Result<ArithExpr<F>, Error>;
+
+	/// Returns the base-2 logarithm of the maximum size of the column.
+	fn max_size_log(&self) -> usize;
+
+	/// Checks whether the given table size specified as n_vars can fit into this structured column
+	/// specifier.
+	fn check_nvars(&self, n_vars: usize) -> Result<(), Error> {
+		if n_vars > self.max_size_log() {
+			Err(Error::MaxLogSizeTooLarge)
+		} else {
+			Ok(())
+		}
+	}
+
+	/// Checks whether the values of the column fit in a field with `n_bits` bits.
+	///
+	/// This is called when the column is added to a table, with the bit width of the column field.
+	/// The default implementation accepts any width.
+	fn check_field_bits(&self, n_bits: usize) -> Result<(), Error> {
+		let _ = n_bits;
+		Ok(())
+	}
+
+	/// Returns the built-in variant that this column is, if it is one.
+	///
+	/// A built-in column is described to the verifier by its variant, see
+	/// [`StructuredColumnDescriptor`](crate::builder::StructuredColumnDescriptor). The default
+	/// implementation returns `None`.
+	fn as_dyn_size(&self) -> Option<StructuredDynSize> {
+		None
+	}
+
+	/// Returns the cost of the circuit of [`Self::expr`] over [`Self::max_size_log`] variables, if
+	/// it is known without building the expression.
+	///
+	/// The circuit has at least [`StructuredCost::n_muls`] steps, so
+	/// [`ConstraintSystem::compile`](crate::builder::ConstraintSystem::compile) rejects a column
+	/// whose cost exceeds
+	/// [`ConstraintSystem::max_structured_circuit_steps`](crate::builder::ConstraintSystem::max_structured_circuit_steps)
+	/// before building its expression. The default implementation returns `None`, in which case
+	/// the limit is only checked on the built expression.
+	fn circuit_cost(&self) -> Option<StructuredCost> {
+		None
+	}
+}
+
+impl<F: TowerField> Structured<F> for StructuredDynSize {
+	fn expr(&self) -> Result<ArithExpr<F>, Error> {
+		StructuredDynSize::expr(*self)
+	}
+
+	fn max_size_log(&self) -> usize {
+		StructuredDynSize::max_size_log(self)
+	}
+
+	fn check_nvars(&self, n_vars: usize) -> Result<(), Error> {
+		StructuredDynSize::check_nvars(self, n_vars)
+	}
+
+	fn check_field_bits(&self, n_bits: usize) -> Result<(), Error> {
+		StructuredDynSize::check_field_bits(self, n_bits)
+	}
+
+	fn as_dyn_size(&self) -> Option<StructuredDynSize> {
+		Some(*self)
+	}
+
+	fn circuit_cost(&self) -> Option<StructuredCost> {
+		Some(self.cost_estimate(self.max_size_log()))
+	}
+}
+
+/// A structured column whose values are the entries of a lookup table, such as an S-box.
+///
+/// Row `i` of the column holds `values[i]`, and the rows past the end of the values are zero. The
+/// multilinear extension is interpolated from the values, so it can be evaluated at any point,
+/// not only on the hypercube. The interpolated expression has up to one monomial per value, which
+/// limits the size of the table to [`Self::MAX_INTERPOLATION_LOG_SIZE`].
+///
+/// The column is filled with [`fill_table_values`](crate::gadgets::structured::fill_table_values).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StructuredTable<F: TowerField = B128> {
+	values: Vec<F>,
+	max_size_log: usize,
+}
+
+impl<F: TowerField> StructuredTable<F> {
+	/// The base-2 logarithm of the largest table whose multilinear extension is interpolated.
+	pub const MAX_INTERPOLATION_LOG_SIZE: usize = 12;
+
+	/// Creates a table column with `2^max_size_log` rows.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::TableSizeTooLarge`] if there are more than `2^max_size_log` values.
+	pub fn new(values: Vec<F>, max_size_log: usize) -> Result<Self, Error> {
+		if max_size_log >= usize::BITS as usize || values.len() > 1 << max_size_log {
+			return Err(Error::TableSizeTooLarge);
+		}
+		Ok(Self {
+			values,
+			max_size_log,
+		})
+	}
+
+	/// Returns the values of the table.
+	pub fn values(&self) -> &[F] {
+		&self.values
+	}
+}
+
+impl<F: TowerField> Structured<F> for StructuredTable<F> {
+	fn expr(&self) -> Result<ArithExpr<F>, Error> {
+		if self.max_size_log > Self::MAX_INTERPOLATION_LOG_SIZE {
+			return Err(Error::TableTooLargeToInterpolate {
+				log_size: self.max_size_log,
+			});
+		}
+
+		// The coefficient of the monomial with the variables in the bits of `i` is the sum of the
+		// values at the indices whose bits are a subset of those of `i`.
+		let mut coeffs = self.values.clone();
+		coeffs.resize(1 << self.max_size_log, F::ZERO);
+		for var in 0..self.max_size_log {
+			for i in 0..coeffs.len() {
+				if (i >> var) & 1 == 1 {
+					let sub_coeff = coeffs[i ^ (1 << var)];
+					coeffs[i] += sub_coeff;
+				}
+			}
+		}
+
+		ArithExpr::from_multilinear_coeffs(&coeffs, self.max_size_log).map_err(|source| {
+			Error::math(
+				format!(
+					"the interpolation of a table with 2^{} values over a {}-bit field",
+					self.max_size_log,
+					F::N_BITS
+				),
+				source,
+			)
+		})
+	}
+
+	fn max_size_log(&self) -> usize {
+		self.max_size_log
+	}
+}
+
+/// A structured column whose values are those of another structured column times a constant.
+///
+/// The multilinear extension is the one of the inner column times `factor`, so scaling keeps the
+/// closed form of the inner column. This is a separate type and not a variant of
+/// [`StructuredDynSize`], which is `Copy` and not generic over the field of the factor.
+///
+/// The column is filled with [`fill_scaled`](crate::gadgets::structured::fill_scaled).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scaled<S, F: TowerField = B128> {
+	pub inner: S,
+	pub factor: F,
+}
+
+impl<F: TowerField, S: Structured<F>> Structured<F> for Scaled<S, F> {
+	fn expr(&self) -> Result<ArithExpr<F>, Error> {
+		Ok(self.inner.expr()? * ArithExpr::Const(self.factor))
+	}
+
+	fn max_size_log(&self) -> usize {
+		self.inner.max_size_log()
+	}
+
+	fn check_nvars(&self, n_vars: usize) -> Result<(), Error> {
+		self.inner.check_nvars(n_vars)
+	}
+
+	/// The values fit in the column field if those of the inner column do and the factor is in
+	/// the field, which is closed under multiplication.
+	fn check_field_bits(&self, n_bits: usize) -> Result<(), Error> {
+		self.inner.check_field_bits(n_bits)?;
+		if 1 << self.factor.min_tower_level() > n_bits {
+			return Err(Error::FactorNotInField);
+		}
+		Ok(())
+	}
+}
+
+/// A structured column whose values are a fixed affine map of the row index, `slope * i +
+/// intercept`.
+///
+/// The row index `i` is the field element with the bits of `i`, as in
+/// [`StructuredDynSize::Incrementing`], so the multilinear extension is the incrementing
+/// expression times `slope` plus `intercept`. This is the general linear form of the incrementing
+/// column: [`Scaled`] incrementing columns are the affine columns with a zero intercept. Like
+/// [`Scaled`], this is a separate type to keep [`StructuredDynSize`] `Copy`.
+///
+/// The column is filled with [`fill_affine`](crate::gadgets::structured::fill_affine).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Affine<F: TowerField = B128> {
+	pub slope: F,
+	pub intercept: F,
+	/// The base-2 logarithm of the maximum size of the column.
+	pub max_size_log: usize,
+}
+
+impl<F: TowerField> Structured<F> for Affine<F> {
+	fn expr(&self) -> Result<ArithExpr<F>, Error> {
+		Ok(ArithExpr::Const(self.intercept)
+			+ ArithExpr::Const(self.slope) * incrementing_expr::<F>(self.max_size_log)?)
+	}
+
+	fn max_size_log(&self) -> usize {
+		self.max_size_log
+	}
+
+	fn check_nvars(&self, n_vars: usize) -> Result<(), Error> {
+		StructuredDynSize::Incrementing {
+			max_size_log: self.max_size_log,
+		}
+		.check_nvars(n_vars)
+	}
+
+	/// The values fit in the column field if the row indices do and the slope and intercept are
+	/// in the field.
+	fn check_field_bits(&self, n_bits: usize) -> Result<(), Error> {
+		if self.max_size_log > n_bits {
+			return Err(Error::MaxLogSizeTooLarge);
+		}
+		if 1 << self
+			.slope
+			.min_tower_level()
+			.max(self.intercept.min_tower_level())
+			> n_bits
+		{
+			return Err(Error::FactorNotInField);
+		}
+		Ok(())
+	}
+}
+
+/// A structured column whose values are the sums of the values of two structured columns.
+///
+/// Both columns share the row index variables, so the multilinear extension is the sum of their
+/// extensions. The column fits a table if both columns do. Like [`Scaled`], this is a separate
+/// type to keep [`StructuredDynSize`] `Copy`.
+///
+/// The column is filled with [`fill_sum`](crate::gadgets::structured::fill_sum).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sum<A, B> {
+	pub lhs: A,
+	pub rhs: B,
+}
+
+impl<F: TowerField, A: Structured<F>, B: Structured<F>> Structured<F> for Sum<A, B> {
+	fn expr(&self) -> Result<ArithExpr<F>, Error> {
+		Ok(self.lhs.expr()? + self.rhs.expr()?)
+	}
+
+	fn max_size_log(&self) -> usize {
+		self.lhs.max_size_log().max(self.rhs.max_size_log())
+	}
+
+	fn check_nvars(&self, n_vars: usize) -> Result<(), Error> {
+		self.lhs.check_nvars(n_vars)?;
+		self.rhs.check_nvars(n_vars)
+	}
+
+	fn check_field_bits(&self, n_bits: usize) -> Result<(), Error> {
+		self.lhs.check_field_bits(n_bits)?;
+		self.rhs.check_field_bits(n_bits)
+	}
+}
+
+/// A structured column whose values are those of a built-in column embedded in a wider field at
+/// a bit offset.
+///
+/// The values of `inner` are taken as elements of a field with `from_bits` bits, and bit `k` of a
+/// value is moved to bit `k + to_field_offset`, so the multilinear extension is the one of `inner`
+/// with every basis element $\beta_k$ replaced by $\beta_{k + to\_field\_offset}$. For example, an
+/// incrementing B32 column embedded at offset 32 in a B128 column has the row indices in bits
+/// `32..64`, like the `basis(i + 4)` weights of the bitwise AND tests. The values of `inner` must
+/// fit in `from_bits` bits. Like [`Scaled`], this is a separate type to keep
+/// [`StructuredDynSize`] `Copy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Embed {
+	/// The column whose values are embedded.
+	pub inner: StructuredDynSize,
+	/// The number of bits of the values of the inner column.
+	pub from_bits: usize,
+	/// The bit of the values of this column at which the values of the inner column start.
+	pub to_field_offset: usize,
+}
+
+impl Embed {
+	/// Evaluates the multilinear extension of the column at `point`.
+	///
+	/// This is the evaluation of the inner column with its weights embedded, see
+	/// [`StructuredDynSize::evaluate`]. The point may have fewer coordinates than
+	/// [`Structured::max_size_log`], in which case the remaining variables are set to zero.
+	pub fn evaluate<F: TowerField>(&self, point: &[F]) -> Result<F, Error> {
+		self.inner.check_nvars(point.len())?;
+		Structured::<F>::check_field_bits(self, F::N_BITS)?;
+		self.inner.check_params()?;
+		let weights = (0..point.len())
+			.map(|i| self.embed(self.inner.var_weight(i)))
+			.collect::<Vec<_>>();
+		Ok(self.embed(self.inner.constant_term::<F>()) + inner_product(point, &weights))
+	}
+
+	/// Returns `value` with bit `k` moved to bit `k + to_field_offset`, for the bits
+	/// `k < from_bits`.
+	fn embed<F: TowerField>(&self, value: F) -> F {
+		(0..self.from_bits)
+			.filter(|&k| <F as ExtensionField<B1>>::get_base(&value, k) == B1::ONE)
+			.map(|k| <F as ExtensionField<B1>>::basis(k + self.to_field_offset))
+			.sum()
+	}
+}
+
+impl<F: TowerField> Structured<F> for Embed {
+	fn expr(&self) -> Result<ArithExpr<F>, Error> {
+		Structured::<F>::check_field_bits(self, F::N_BITS)?;
+		self.inner.check_params()?;
+		let mut expr = basis_weighted_expr(self.inner.max_size_log(), |i| {
+			self.embed(self.inner.var_weight::<F>(i))
+		});
+		let constant = self.embed(self.inner.constant_term::<F>());
+		if constant != F::ZERO {
+			expr += ArithExpr::Const(constant);
+		}
+		Ok(expr)
+	}
+
+	fn max_size_log(&self) -> usize {
+		self.inner.max_size_log()
+	}
+
+	fn check_nvars(&self, n_vars: usize) -> Result<(), Error> {
+		self.inner.check_nvars(n_vars)
+	}
+
+	/// The values fit in the column field if the inner values fit in `from_bits` bits and the
+	/// embedded bits in the field.
+	fn check_field_bits(&self, n_bits: usize) -> Result<(), Error> {
+		self.inner.check_field_bits(self.from_bits)?;
+		if self.from_bits + self.to_field_offset > n_bits {
+			return Err(Error::MaxLogSizeTooLarge);
+		}
+		Ok(())
+	}
+
+	fn circuit_cost(&self) -> Option<StructuredCost> {
+		Some(self.inner.cost_estimate(self.inner.max_size_log()))
+	}
+}
+
+/// A structured column whose values are the basis elements of `F` over `FSub` at which the values
+/// of consecutive rows embed when they are packed into `F`.
+///
+/// Row `i` holds `<F as ExtensionField<FSub>>::basis(i % D)`, where `D` is the degree of `F` over
+/// `FSub`, so the packed element of rows `D * k..D * (k + 1)` is the sum of the values of the rows
+/// times this column. The position `i % D` itself is the
+/// [`StructuredDynSize::sub_element_index`] column.
+///
+/// In a tower, the basis element at position `j` is the product of the basis elements at the
+/// powers of two in the bits of `j`, so the multilinear extension is
+/// $\prod_k (1 + X_k (\beta_{2^k} + 1))$ over the first `log2(D)` variables.
+///
+/// The column is filled with
+/// [`fill_sub_element_basis`](crate::gadgets::structured::fill_sub_element_basis).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubElementBasis<FSub> {
+	max_size_log: usize,
+	_marker: PhantomData<FSub>,
+}
+
+impl<FSub> SubElementBasis<FSub> {
+	/// Creates a sub-element basis column with at most `2^max_size_log` rows.
+	pub fn new(max_size_log: usize) -> Self {
+		Self {
+			max_size_log,
+			_marker: PhantomData,
+		}
+	}
+}
+
+impl<F, FSub> Structured<F> for SubElementBasis<FSub>
+where
+	F: TowerField + ExtensionField<FSub>,
+	FSub: Field,
+{
+	fn expr(&self) -> Result<ArithExpr<F>, Error> {
+		let n_position_vars = <F as ExtensionField<FSub>>::LOG_DEGREE.min(self.max_size_log);
+		// The value does not depend on the variables of the higher row index bits, which the
+		// expression leaves out.
+		Ok((0..n_position_vars)
+			.map(|k| {
+				let generator = <F as ExtensionField<FSub>>::basis(1 << k);
+				ArithExpr::Const(F::ONE) + ArithExpr::Var(k) * ArithExpr::Const(generator + F::ONE)
+			})
+			.product::<ArithExpr<F>>())
+	}
+
+	fn max_size_log(&self) -> usize {
+		self.max_size_log
+	}
+}
+
+/// The cost of evaluating the multilinear extension of a structured column.
+///
+/// This is returned by [`StructuredDynSize::cost_estimate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StructuredCost {
+	/// The degree of the arithmetic circuit.
+	pub degree: usize,
+	/// The number of multiplications in the arithmetic circuit.
+	pub n_muls: usize,
+	/// Whether the multilinear extension has a closed form that the verifier can evaluate
+	/// succinctly.
+	pub has_closed_form: bool,
+}
+
+impl StructuredCost {
+	/// Returns the cost of evaluating the given closed form expression.
+	pub fn from_circuit<F: Field>(expr: &ArithCircuit<F>) -> Self {
+		Self {
+			degree: expr.degree(),
+			n_muls: expr.eval_cost().n_muls,
+			has_closed_form: true,
+		}
+	}
+}
+
+/// The multilinear extension of a structured column with a prefix of its variables fixed.
+///
+/// This is returned by [`StructuredDynSize::fold_first_var`].
+#[derive(Debug, Clone)]
+pub struct FoldedStructured<F: Field> {
+	expr: ArithCircuit<F>,
+	n_vars: usize,
+}
+
+impl<F: Field> FoldedStructured<F> {
+	/// Returns the number of remaining variables.
+	pub fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	/// Returns the arithmetic circuit of the folded multilinear over the remaining variables.
+	pub fn expr(&self) -> &ArithCircuit<F> {
+		&self.expr
+	}
+
+	/// Fixes the first remaining variable to `challenge`.
+	pub fn fold_first_var(&self, challenge: F) -> Result<Self, Error> {
+		if self.n_vars == 0 {
+			return Err(Error::NoVariablesToFold);
+		}
+		// Variable 0 is substituted away, so its entry in the remapping is never used.
+		let var_remapping = (0..self.n_vars)
+			.map(|i| i.saturating_sub(1))
+			.collect::<Vec<_>>();
+		let expr = self
+			.expr
+			.clone()
+			.const_subst(0, challenge)
+			.remap_vars(&var_remapping)
+			.map_err(|source| {
+				Error::math(
+					format!("the folding of a structured column with {} variables", self.n_vars),
+					source,
+				)
+			})?
+			.optimize();
+		Ok(Self {
+			expr,
+			n_vars: self.n_vars - 1,
+		})
+	}
+
+	/// Evaluates the folded multilinear at a point over the remaining variables.
+	pub fn evaluate(&self, query: &[F]) -> Result<F, Error> {
+		let context = || {
+			format!("the evaluation of a folded structured column with {} variables", self.n_vars)
+		};
+		if query.len() != self.n_vars {
+			return Err(Error::math(
+				context(),
+				binius_math::Error::IncorrectQuerySize {
+					expected: self.n_vars,
+					actual: query.len(),
+				},
+			));
+		}
+		self.expr
+			.evaluate(query)
+			.map_err(|source| Error::math(context(), source))
+	}
+}
+
+/// The affine multilinear extension of a built-in structured column with a prefix of its
+/// variables fixed.
+///
+/// The extension is $c + \sum_i X_i w_i$ over the remaining variables. This is returned by
+/// [`StructuredDynSize::fix_prefix`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReducedStructured<F: Field> {
+	offset: F,
+	weights: Vec<F>,
+}
+
+impl<F: Field> ReducedStructured<F> {
+	/// Returns the number of remaining variables.
+	pub fn n_vars(&self) -> usize {
+		self.weights.len()
+	}
+
+	/// Returns the constant term $c$ of the extension.
+	pub fn offset(&self) -> F {
+		self.offset
+	}
+
+	/// Returns the weights $w_i$ of the remaining variables.
+	pub fn weights(&self) -> &[F] {
+		&self.weights
+	}
+
+	/// Fixes the first remaining variables to `challenges`.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::NoVariablesToFold`] if there are more challenges than remaining variables.
+	pub fn fix_prefix(&self, challenges: &[F]) -> Result<Self, Error> {
+		if challenges.len() > self.n_vars() {
+			return Err(Error::NoVariablesToFold);
+		}
+		let (fixed, remaining) = self.weights.split_at(challenges.len());
+		Ok(Self {
+			offset: self.offset + inner_product(challenges, fixed),
+			weights: remaining.to_vec(),
+		})
+	}
+
+	/// Evaluates the reduced multilinear at a point over the remaining variables.
+	pub fn evaluate(&self, query: &[F]) -> Result<F, Error> {
+		if query.len() != self.n_vars() {
+			return Err(Error::math(
+				format!(
+					"the evaluation of a reduced structured column with {} variables",
+					self.n_vars()
+				),
+				binius_math::Error::IncorrectQuerySize {
+					expected: self.n_vars(),
+					actual: query.len(),
+				},
+			));
+		}
+		Ok(self.offset + inner_product(query, &self.weights))
+	}
+}
+
+/// Returns the arithmetic expression for an incrementing column.
+///
+/// The multilinear expression is
+///
+/// $$
+/// \sum_{v \in B_n} X_i \beta_i,
+/// $$
+///
+/// where $\beta_i$ is the $i$-th basis element of the field $F$ as an $\mathbb{F}_2$ vector space.
+pub fn incrementing_expr<F: TowerField>(max_log_size: usize) -> Result<ArithExpr<F>, Error> {
+	incrementing_expr_with_endianness(max_log_size, Endianness::Little)
+}
+
+/// Returns the arithmetic expression for an incrementing column over the basis of `F` as a vector
+/// space over the subfield `FSub`.
+///
+/// The multilinear expression is
+///
+/// $$
+/// \sum_{v \in B_n} X_i \gamma_i,
+/// $$
+///
+/// where $\gamma_i$ is the $i$-th basis element of `F` over `FSub`. The value at row $i$ is the
+/// element whose coordinates over `FSub` are the bits of $i$. With `FSub` equal to [`B1`] this is
+/// [`incrementing_expr`]. The fields are still binary towers: the basis of `F` over `FSub` is the
+/// one of [`ExtensionField::basis`].
+///
+/// ## Throws
+///
+/// * [`Error::MaxLogSizeTooLarge`] if `max_log_size` is greater than the degree of `F` over `FSub`.
+pub fn incrementing_expr_over_basis<F, FSub>(max_log_size: usize) -> Result<ArithExpr<F>, Error>
+where
+	F: TowerField + ExtensionField<FSub>,
+	FSub: TowerField,
+{
+	if max_log_size > <F as ExtensionField<FSub>>::DEGREE {
+		return Err(Error::MaxLogSizeTooLarge);
+	}
+	Ok(basis_weighted_expr(max_log_size, <F as ExtensionField<FSub>>::basis))
+}
+
+/// Returns the arithmetic expression for an incrementing column with the given bit order.
+///
+/// With [`Endianness::Little`] this is the same as [`incrementing_expr`]. With [`Endianness::Big`]
+/// the multilinear expression is
+///
+/// $$
+/// \sum_{v \in B_n} X_i \beta_{n - 1 - i},
+/// $$
+///
+/// where $n$ is `max_log_size`.
+pub fn incrementing_expr_with_endianness<F: TowerField>(
+	max_log_size: usize,
+	endianness: Endianness,
+) -> Result<ArithExpr<F>, Error> {
+	let max_size_log = max_log_size;
+	match endianness {
+		Endianness::Little => StructuredDynSize::Incrementing { max_size_log },
+		Endianness::Big => StructuredDynSize::IncrementingBigEndian { max_size_log },
+	}
+	.expr()
+}
+
+/// Returns the arithmetic expression for a parity column.
+///
+/// The multilinear expression is $X_0 \beta_0$, where $\beta_0$ is the first basis element of the
+/// field $F$ as an $\mathbb{F}_2$ vector space. A column with `max_log_size` zero has a single
+/// row, which is even, so its expression is zero.
+pub fn parity_expr<F: TowerField>(max_log_size: usize) -> ArithExpr<F> {
+	StructuredDynSize::Parity {
+		max_size_log: max_log_size,
+	}
+	.expr()
+	.expect("the value of a parity column fits in every field")
+}
+
+/// Returns the arithmetic expression for a column of the low `bits` bits of the row index.
+///
+/// The multilinear expression is $\sum_{i < \min(bits, n)} X_i \beta_i$, where $n$ is
+/// `max_log_size`. With `bits` equal to one this is [`parity_expr`], and with `bits` at least
+/// `max_log_size` this is [`incrementing_expr`].
+///
+/// ## Throws
+///
+/// * [`Error::MaxLogSizeTooLarge`] if more variables than `F::N_BITS` have a non-zero weight.
+pub fn low_bits_expr<F: TowerField>(
+	bits: usize,
+	max_log_size: usize,
+) -> Result<ArithExpr<F>, Error> {
+	StructuredDynSize::LowBits {
+		bits,
+		max_size_log: max_log_size,
+	}
+	.expr()
+}
+
+/// Returns the arithmetic expression for the bitwise complement of the row index.
+///
+/// The multilinear expression is $\sum_{i < n} (1 - X_i) \beta_i$, where $n$ is `max_log_size`,
+/// which in characteristic 2 is the incrementing expression plus the constant
+/// $\sum_{i < n} \beta_i$.
+///
+/// ## Throws
+///
+/// * [`Error::MaxLogSizeTooLarge`] if `max_log_size` is greater than `F::N_BITS`.
+pub fn complement_expr<F: TowerField>(max_log_size: usize) -> Result<ArithExpr<F>, Error> {
+	StructuredDynSize::Complement {
+		max_size_log: max_log_size,
+	}
+	.expr()
+}
+
+/// Returns the arithmetic expression for the Morton code of the row index.
+///
+/// The multilinear expression is $\sum_{i < n} X_i \beta_{p(i)}$, where $n$ is
+/// `x_bits + y_bits` and $p$ is [`morton_bit_position`].
+///
+/// ## Throws
+///
+/// * [`Error::MaxLogSizeTooLarge`] if `x_bits + y_bits` is greater than `F::N_BITS`.
+pub fn morton_expr<F: TowerField>(x_bits: usize, y_bits: usize) -> Result<ArithExpr<F>, Error> {
+	StructuredDynSize::Morton { x_bits, y_bits }.expr()
+}
+
+/// Returns the position in the Morton code of bit `i` of the row index.
+///
+/// Bit `i` is bit `i` of the x coordinate if `i < x_bits`, and bit `i - x_bits` of the y
+/// coordinate otherwise. Bit `k` of x is at position `2k` and bit `k` of y at position `2k + 1`
+/// for `k < min(x_bits, y_bits)`, and the remaining bits of the longer coordinate follow in
+/// order. For example, with two bits of x and one of y, the code of `(x, y)` has the bits
+/// `x_0, y_0, x_1`.
+pub fn morton_bit_position(x_bits: usize, y_bits: usize, i: usize) -> usize {
+	let n_pairs = x_bits.min(y_bits);
+	let (k, is_y) = if i < x_bits { (i, 0) } else { (i - x_bits, 1) };
+	if k < n_pairs {
+		2 * k + is_y
+	} else {
+		n_pairs + k
+	}
+}
+
+/// Returns the arithmetic expression for the row index with its bits permuted.
+///
+/// The multilinear expression is $\sum_{i < n} X_i \beta_{perm[i]}$, where $n$ is
+/// `max_log_size`.
+///
+/// ## Throws
+///
+/// * [`Error::MaxLogSizeTooLarge`] if `max_log_size` is greater than `F::N_BITS`.
+/// * [`Error::InvalidBitPermutation`] if `perm` is not a permutation of `0..max_log_size`.
+pub fn bit_permute_expr<F: TowerField>(
+	perm: &[usize],
+	max_log_size: usize,
+) -> Result<ArithExpr<F>, Error> {
+	StructuredDynSize::BitPermute {
+		perm: BitPermutation::new(perm)?,
+		max_size_log: max_log_size,
+	}
+	.expr()
+}
+
+/// Returns the bit of the row index at position `j` of the Morton code.
+///
+/// This is the inverse of [`morton_bit_position`].
+fn morton_bit_var(x_bits: usize, y_bits: usize, j: usize) -> usize {
+	let n_pairs = x_bits.min(y_bits);
+	if j < 2 * n_pairs {
+		if j % 2 == 0 { j / 2 } else { x_bits + j / 2 }
+	} else if x_bits > y_bits {
+		j - n_pairs
+	} else {
+		x_bits + j - n_pairs
+	}
+}
+
+/// The weight of variable `i` in the expression of the low `bits` bits of the row index.
+fn low_bits_weight<F: TowerField>(bits: usize, i: usize) -> F {
+	if i < bits {
+		<F as ExtensionField<B1>>::basis(i)
+	} else {
+		F::ZERO
+	}
+}
+
+/// Returns the linear expression $\sum_{i < n} X_i w_i$, where the weights $w_i$ are given by
+/// `weight_fn`.
+///
+/// Terms with a zero weight are omitted, so the expression has one multiplication per non-zero
+/// weight. With no non-zero weights the expression is zero.
+fn basis_weighted_expr<F: Field>(n_vars: usize, weight_fn: impl Fn(usize) -> F) -> ArithExpr<F> {
+	(0..n_vars)
+		.filter_map(|i| {
+			let weight = weight_fn(i);
+			(weight != F::ZERO).then(|| ArithExpr::Var(i) * ArithExpr::Const(weight))
+		})
+		.sum()
+}
+
+/// Returns the linear expression $\sum_{i < n} X_{var(i)} \beta_i$ in the nested form of
+/// [`Lowering::Horner`].
+fn nested_basis_expr<F: TowerField>(n_vars: usize, var: impl Fn(usize) -> usize) -> ArithExpr<F> {
+	// The sum of X_{var(offset + j)} β_j over j < 2^log_len with offset + j < n_vars.
+	fn nest<F: TowerField>(
+		offset: usize,
+		log_len: usize,
+		n_vars: usize,
+		var: &impl Fn(usize) -> usize,
+	) -> Option<ArithExpr<F>> {
+		if offset >= n_vars {
+			return None;
+		}
+		if log_len == 0 {
+			return Some(ArithExpr::Var(var(offset)));
+		}
+		let half = 1 << (log_len - 1);
+		let low = nest(offset, log_len - 1, n_vars, var)?;
+		match nest(offset + half, log_len - 1, n_vars, var) {
+			Some(high) => {
+				let generator = <F as ExtensionField<B1>>::basis(half);
+				Some(low + high * ArithExpr::Const(generator))
+			}
+			None => Some(low),
+		}
+	}
+
+	let log_len = n_vars.next_power_of_two().trailing_zeros() as usize;
+	nest(0, log_len, n_vars, &var).unwrap_or(ArithExpr::Const(F::ZERO))
+}
+
+/// Hands out disjoint ranges of variables to the sub-expressions of a composed expression.
+///
+/// The expression of a fixed or structured column is often the combination of sub-gadget
+/// expressions, each written over its own variables starting at index zero. Instead of shifting
+/// the variable indices of each part by hand, every part is embedded with [`Self::embed`], which
+/// remaps it to variables that no other part uses.
+#[derive(Debug, Default, Clone)]
+pub struct VarAllocator {
+	n_vars: usize,
+}
+
+impl VarAllocator {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns the number of variables allocated so far, which is the number of variables of the
+	/// composed expression.
+	pub fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	/// Allocates `n_vars` fresh variables and returns their indices.
+	pub fn alloc(&mut self, n_vars: usize) -> Range<usize> {
+		let start = self.n_vars;
+		self.n_vars += n_vars;
+		start..self.n_vars
+	}
+
+	/// Remaps an expression over the variables `0..n_vars` to `n_vars` fresh variables.
+	///
+	/// Variable `i` of `expr` becomes variable `start + i` of the returned expression, where
+	/// `start` is the number of variables allocated before the call.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::Math`] if `expr` has a variable with index `n_vars` or larger. No variables are
+	///   allocated in that case.
+	pub fn embed<F: Field>(
+		&mut self,
+		n_vars: usize,
+		expr: &ArithExpr<F>,
+	) -> Result<ArithExpr<F>, Error> {
+		let indices = (self.n_vars..self.n_vars + n_vars).collect::<Vec<_>>();
+		let remapped = ArithCircuit::from(expr)
+			.remap_vars(&indices)
+			.map_err(|source| {
+				Error::math(
+					format!(
+						"the embedding of an expression over {n_vars} variables at variable {}",
+						self.n_vars
+					),
+					source,
+				)
+			})?;
+		self.alloc(n_vars);
+		Ok(remapped.into())
+	}
+}
+
+/// Declares a structured column on a table, optionally constrained to equal a reference column.
+///
+/// The first form expands to a [`TableBuilder::add_structured`] call and returns the column:
+///
+/// ```ignore
+/// let col = structured_column!(table, "incrementing", B32, Incrementing { max_size_log: 32 });
+/// ```
+///
+/// The second form also asserts that the structured column equals `reference` on every row, with
+/// a zero constraint named `"<name> = reference"`:
+///
+/// ```ignore
+/// let col = structured_column!(
+///     table, "incrementing", B32, Incrementing { max_size_log: 32 }, reference = expected_col
+/// );
+/// ```
+///
+/// `table` may be either a [`TableBuilder`] or a mutable reference to one, and it is evaluated
+/// exactly once.
+///
+/// [`TableBuilder`]: crate::builder::TableBuilder
+/// [`TableBuilder::add_structured`]: crate::builder::TableBuilder::add_structured
+#[macro_export]
+macro_rules! structured_column {
+	($table:expr, $name:expr, $field:ty, $variant:ident { $($spec:tt)* } $(,)?) => {{
+		let table: &mut $crate::builder::TableBuilder<_> = &mut $table;
+		table.add_structured::<$field>(
+			$name,
+			$crate::builder::StructuredDynSize::$variant { $($spec)* },
+		)
+	}};
+	(
+		$table:expr,
+		$name:expr,
+		$field:ty,
+		$variant:ident { $($spec:tt)* },
+		reference = $reference:expr $(,)?
+	) => {{
+		let table: &mut $crate::builder::TableBuilder<_> = &mut $table;
+		let name = ::std::string::ToString::to_string(&$name);
+		let col = table.add_structured::<$field>(
+			&name,
+			$crate::builder::StructuredDynSize::$variant { $($spec)* },
+		);
+		table.assert_zero(::std::format!("{name} = reference"), $reference - col);
+		col
+	}};
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::{self, repeat_with};
+
+	use assert_matches::assert_matches;
+	use binius_compute::{alloc::HostBumpAllocator, cpu::alloc::CpuComputeAllocator};
+	use binius_core::polynomial::{
+		hypercube_points, test_utils::decompose_index_to_hypercube_point,
+	};
+	use binius_fast_compute::arith_circuit::ArithCircuitPoly;
+	use binius_field::{
+		BinaryField32b, BinaryField128bPolyval, PackedExtension, PackedFieldIndexable,
+		PackedSubfield,
+		arch::{OptimalUnderlier128b, OptimalUnderlier256b},
+		as_packed_field::{PackScalar, PackedType},
+		linear_transformation::PackedTransformationFactory,
+		packed::set_packed_slice,
+		underlier::UnderlierType,
+	};
+	use binius_math::{ArithCircuit, ArithCircuitStep, CompositionPoly, eq_ind_partial_eval};
+	use itertools::izip;
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::{
+		builder::{
+			B1, B8, B16, B32, B64, B128, Col, ColumnDef, ColumnId, ConstraintSystem,
+			OptimalPackedB128, TableBuilder, TableWitnessSegment, WitnessIndex,
+			test_utils::{
+				ClosureFiller, ConstantStructured, assert_expr_matches_materialize,
+				assert_structured_matches_fill, check_structured_variant, validate_system_witness,
+			},
+		},
+		gadgets::structured::{
+			IndexAtLeast, InverseTable, PrefixXorIndex, SaturatingIncrementing, fill_affine,
+			fill_bit_permute, fill_complement_b32, fill_incrementing_b32,
+			fill_incrementing_b32_big_endian, fill_index_at_least, fill_lane_id, fill_low_bits,
+			fill_morton, fill_msb_position, fill_parity, fill_popcount, fill_scaled,
+			fill_sub_element_basis, fill_sub_element_index, fill_sum, fill_table_values,
+		},
+	};
+
+	#[test]
+	fn test_incrementing_expr() {
+		let expr = incrementing_expr::<B32>(5).unwrap();
+		let evaluator = ArithCircuitPoly::new(expr.into());
+		for (i, bits) in hypercube_points::<B32>(5).enumerate() {
+			assert_eq!(evaluator.evaluate(&bits).unwrap(), B32::new(i as u32));
+		}
+	}
+
+	#[test]
+	fn test_incrementing_expr_over_basis() {
+		assert_eq!(
+			incrementing_expr_over_basis::<B32, B1>(5).unwrap(),
+			incrementing_expr::<B32>(5).unwrap()
+		);
+
+		let expr = incrementing_expr_over_basis::<B128, B32>(4).unwrap();
+		let evaluator = ArithCircuitPoly::new(expr.into());
+		for (i, point) in hypercube_points::<B128>(4).enumerate() {
+			let value = evaluator.evaluate(&point).unwrap();
+			for j in 0..4 {
+				let expected = if (i >> j) & 1 == 1 {
+					B32::ONE
+				} else {
+					B32::ZERO
+				};
+				assert_eq!(ExtensionField::<B32>::get_base(&value, j), expected);
+			}
+		}
+
+		assert_matches!(
+			incrementing_expr_over_basis::<B128, B32>(5),
+			Err(Error::MaxLogSizeTooLarge)
+		);
+	}
+
+	#[test]
+	fn test_incrementing_expr_at_points() {
+		let n_vars = 8;
+		let circuit = ArithCircuit::from(&incrementing_expr::<B128>(n_vars).unwrap());
+		let evaluator = ArithCircuitPoly::new(circuit.clone());
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let points = repeat_with(|| {
+			repeat_with(|| B128::random(&mut rng))
+				.take(n_vars)
+				.collect::<Vec<_>>()
+		})
+		.take(3)
+		.collect::<Vec<_>>();
+		let expected = points
+			.iter()
+			.map(|point| evaluator.evaluate(point).unwrap())
+			.collect::<Vec<_>>();
+		assert_eq!(circuit.evaluate_at_points(&points).unwrap(), expected);
+
+		assert!(circuit.evaluate_at_points(&[]).unwrap().is_empty());
+		assert_matches!(
+			circuit.evaluate_at_points(&[vec![B128::ONE; n_vars - 1]]),
+			Err(binius_math::Error::IncorrectQuerySize { .. })
+		);
+	}
+
+	#[test]
+	fn test_incrementing_expr_big_endian() {
+		let expr = incrementing_expr_with_endianness::<B32>(5, Endianness::Big).unwrap();
+		let evaluator = ArithCircuitPoly::new(expr.into());
+
+		let bits = decompose_index_to_hypercube_point::<B32>(5, 1);
+		assert_eq!(evaluator.evaluate(&bits).unwrap(), <B32 as ExtensionField<B1>>::basis(4));
+
+		for i in 0..1 << 5 {
+			let bits = decompose_index_to_hypercube_point::<B32>(5, i);
+			let expected = B32::new((i as u32).reverse_bits() >> (32 - 5));
+			assert_eq!(evaluator.evaluate(&bits).unwrap(), expected);
+		}
+
+		assert_ne!(
+			incrementing_expr_with_endianness::<B32>(5, Endianness::Big).unwrap(),
+			incrementing_expr_with_endianness::<B32>(5, Endianness::Little).unwrap()
+		);
+	}
+
+	#[test]
+	fn test_fill_incrementing_big_endian() {
+		let max_size_log = 8;
+
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("test");
+		table.require_power_of_two_size();
+		let test_table_id = table.id();
+		let expected_col = table.add_committed::<B32, 1>("reference");
+		let structured_col = table.add_structured::<B32>(
+			"incrementing_be",
+			StructuredDynSize::IncrementingBigEndian { max_size_log },
+		);
+		table.assert_zero("reference = structured", expected_col - structured_col);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		{
+			let table_witness = witness.init_table(test_table_id, 1 << 5).unwrap();
+			table_witness
+				.fill_sequential_with_segment_size(
+					&ClosureFiller::new(test_table_id, |events: &[u32], index| {
+						{
+							let mut expected_col = index.get_scalars_mut::<B32, 1>(expected_col)?;
+							for (&i, col_i) in iter::zip(events, &mut *expected_col) {
+								*col_i = B32::new(i.reverse_bits() >> (32 - max_size_log));
+							}
+						}
+
+						fill_incrementing_b32_big_endian(index, structured_col, max_size_log)?;
+						Ok(())
+					}),
+					&(0..1 << 5).collect::<Vec<_>>(),
+					4,
+				)
+				.unwrap();
+		}
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+	}
+
+	fn incrementing_values<'a, P>(allocator: &'a HostBumpAllocator<'a, P>) -> Vec<B32>
+	where
+		P: binius_field::PackedField<Scalar = B128> + PackedExtension<B32>,
+		PackedSubfield<P, B32>: PackedFieldIndexable,
+	{
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("incrementing");
+		table.require_power_of_two_size();
+		let table_id = table.id();
+		let incrementing = table.add_structured::<B32>(
+			"incrementing",
+			StructuredDynSize::Incrementing { max_size_log: 10 },
+		);
+		drop(table);
+
+		let mut witness = WitnessIndex::new(&cs, allocator);
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |_events: &[()], index| {
+					fill_incrementing_b32(index, incrementing)?;
+					Ok(())
+				}),
+				&[(); 1 << 10],
+			)
+			.unwrap();
+		witness
+			.get_table(table_id)
+			.unwrap()
+			.full_segment()
+			.get_scalars(incrementing)
+			.unwrap()
+			.to_vec()
+	}
+
+	#[test]
+	fn test_fill_incrementing_packing_width_independent() {
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let values_128b = incrementing_values::<PackedType<OptimalUnderlier128b, B128>>(
+			&allocator.into_bump_allocator(),
+		);
+		let mut allocator = CpuComputeAllocator::new(1 << 11);
+		let values_256b = incrementing_values::<PackedType<OptimalUnderlier256b, B128>>(
+			&allocator.into_bump_allocator(),
+		);
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let values_optimal =
+			incrementing_values::<OptimalPackedB128>(&allocator.into_bump_allocator());
+
+		assert_eq!(values_128b, (0..1 << 10).map(B32::new).collect::<Vec<_>>());
+		assert_eq!(values_128b, values_256b);
+		assert_eq!(values_128b, values_optimal);
+	}
+
+	#[test]
+	fn test_parity_expr() {
+		let parity = StructuredDynSize::Parity { max_size_log: 6 };
+		let expr = ArithCircuit::from(&parity.expr::<B128>().unwrap());
+		for i in 0..1 << 6 {
+			let bits = decompose_index_to_hypercube_point::<B128>(6, i);
+			let expected = if i % 2 == 1 { B128::ONE } else { B128::ZERO };
+			assert_eq!(expr.evaluate(&bits).unwrap(), expected);
+		}
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let point = repeat_with(|| B128::random(&mut rng))
+			.take(6)
+			.collect::<Vec<_>>();
+		assert_eq!(expr.evaluate(&point).unwrap(), point[0]);
+	}
+
+	#[test]
+	fn test_basis_weighted_variants() {
+		let n_vars = 6;
+		for i in 0..1 << n_vars {
+			let bits = decompose_index_to_hypercube_point::<B32>(n_vars, i);
+
+			// Bit-reversing the index over `n_vars` bits gives the big-endian value.
+			let big_endian = ArithCircuit::from(
+				&incrementing_expr_with_endianness::<B32>(n_vars, Endianness::Big).unwrap(),
+			);
+			let reversed = (i as u32).reverse_bits() >> (32 - n_vars);
+			assert_eq!(big_endian.evaluate(&bits).unwrap(), B32::new(reversed));
+
+			let parity = ArithCircuit::from(&parity_expr::<B32>(n_vars));
+			assert_eq!(parity.evaluate(&bits).unwrap(), B32::new((i & 1) as u32));
+		}
+
+		// Zero weights are skipped, so the parity expression has a single term.
+		let parity = ArithCircuit::from(&parity_expr::<B32>(n_vars));
+		assert_eq!(parity.eval_cost().n_muls, 1);
+		assert_eq!(parity_expr::<B32>(0), ArithExpr::zero());
+	}
+
+	#[test]
+	fn test_evaluate_matches_expr() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let max_size_log = 8;
+		for structured in [
+			StructuredDynSize::Incrementing { max_size_log },
+			StructuredDynSize::IncrementingBigEndian { max_size_log },
+			StructuredDynSize::Parity { max_size_log },
+			StructuredDynSize::LowBits {
+				bits: 3,
+				max_size_log,
+			},
+			StructuredDynSize::Complement { max_size_log },
+			StructuredDynSize::Morton {
+				x_bits: 5,
+				y_bits: 3,
+			},
+			StructuredDynSize::BitPermute {
+				perm: BitPermutation::new(&[3, 0, 7, 1, 6, 2, 5, 4]).unwrap(),
+				max_size_log,
+			},
+		] {
+			let expr = ArithCircuit::from(&structured.expr::<B128>().unwrap());
+			for n_vars in [0, 3, max_size_log] {
+				let point = repeat_with(|| B128::random(&mut rng))
+					.take(n_vars)
+					.collect::<Vec<_>>();
+				let padded = point
+					.iter()
+					.copied()
+					.chain(iter::repeat(B128::ZERO))
+					.take(max_size_log)
+					.collect::<Vec<_>>();
+				assert_eq!(structured.evaluate(&point).unwrap(), expr.evaluate(&padded).unwrap());
+			}
+		}
+
+		let too_long = vec![B128::ONE; max_size_log + 1];
+		assert_matches!(
+			StructuredDynSize::Incrementing { max_size_log }.evaluate(&too_long),
+			Err(Error::MaxLogSizeTooLarge)
+		);
+	}
+
+	#[test]
+	fn test_evaluate_mle_tensor_matches_circuit() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let max_size_log = 8;
+		for structured in [
+			StructuredDynSize::Incrementing { max_size_log },
+			// Bit `i` of the index moves to bit `3 i mod 8`, i.e. the bits are spread with a
+			// stride of three.
+			StructuredDynSize::BitPermute {
+				perm: BitPermutation::new(
+					&(0..max_size_log)
+						.map(|i| 3 * i % max_size_log)
+						.collect::<Vec<_>>(),
+				)
+				.unwrap(),
+				max_size_log,
+			},
+			// The bits of each coordinate are spread with a stride of two.
+			StructuredDynSize::Morton {
+				x_bits: 4,
+				y_bits: 4,
+			},
+		] {
+			let circuit = structured.circuit::<B128>(Lowering::Flat).unwrap();
+			for _ in 0..4 {
+				let point = repeat_with(|| B128::random(&mut rng))
+					.take(max_size_log)
+					.collect::<Vec<_>>();
+				assert_eq!(
+					structured.evaluate_mle_tensor(&point).unwrap(),
+					circuit.evaluate(&point).unwrap(),
+					"the tensor and circuit evaluations of {structured:?} disagree"
+				);
+			}
+		}
+	}
+
+	/// The length of the longest path from an input to the output of the circuit.
+	fn circuit_depth<F: Field>(circuit: &ArithCircuit<F>) -> usize {
+		let mut depths = Vec::<usize>::with_capacity(circuit.steps().len());
+		for step in circuit.steps() {
+			let depth = match *step {
+				ArithCircuitStep::Const(_) | ArithCircuitStep::Var(_) => 0,
+				ArithCircuitStep::Add(left, right) | ArithCircuitStep::Mul(left, right) => {
+					depths[left].max(depths[right]) + 1
+				}
+				ArithCircuitStep::Pow(base, _) => depths[base] + 1,
+			};
+			depths.push(depth);
+		}
+		*depths.last().unwrap()
+	}
+
+	#[test]
+	fn test_evaluate_partial_point() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let variant = StructuredDynSize::Incrementing { max_size_log: 20 };
+		let circuit = ArithCircuit::from(variant.expr::<B32>().unwrap());
+		assert_eq!(circuit.n_vars(), 20);
+
+		let point = repeat_with(|| B128::random(&mut rng))
+			.take(12)
+			.collect::<Vec<_>>();
+		let circuit = circuit.convert_field::<B128>();
+		let padded = point
+			.iter()
+			.copied()
+			.chain(iter::repeat_n(B128::ZERO, 8))
+			.collect::<Vec<_>>();
+		assert_eq!(circuit.evaluate_partial(&point), circuit.evaluate(&padded).unwrap());
+		// With the high variables zero, the incrementing column agrees with the incrementing
+		// column over the low variables only.
+		assert_eq!(
+			circuit.evaluate_partial(&point),
+			StructuredDynSize::Incrementing { max_size_log: 12 }
+				.evaluate(&point)
+				.unwrap()
+		);
+		assert_eq!(circuit.evaluate_partial(&[]), B128::ZERO);
+	}
+
+	#[test]
+	fn test_horner_lowering() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let variants = [
+			StructuredDynSize::Incrementing { max_size_log: 20 },
+			StructuredDynSize::IncrementingBigEndian { max_size_log: 20 },
+			StructuredDynSize::Parity { max_size_log: 20 },
+			StructuredDynSize::LowBits {
+				bits: 5,
+				max_size_log: 20,
+			},
+			StructuredDynSize::Complement { max_size_log: 20 },
+			StructuredDynSize::Morton {
+				x_bits: 7,
+				y_bits: 13,
+			},
+			StructuredDynSize::BitPermute {
+				perm: BitPermutation::new(&(0..20).map(|i| (i + 7) % 20).collect::<Vec<_>>())
+					.unwrap(),
+				max_size_log: 20,
+			},
+			StructuredDynSize::Incrementing { max_size_log: 0 },
+		];
+		for variant in variants {
+			let flat = variant.circuit::<B128>(Lowering::Flat).unwrap();
+			let horner = variant.circuit::<B128>(Lowering::Horner).unwrap();
+			for _ in 0..4 {
+				let point = repeat_with(|| B128::random(&mut rng))
+					.take(variant.max_size_log())
+					.collect::<Vec<_>>();
+				assert_eq!(
+					horner.evaluate(&point).unwrap(),
+					flat.evaluate(&point).unwrap(),
+					"{variant:?}"
+				);
+			}
+		}
+
+		let variant = StructuredDynSize::Incrementing { max_size_log: 32 };
+		let flat = variant.circuit::<B32>(Lowering::Flat).unwrap();
+		let horner = variant.circuit::<B32>(Lowering::Horner).unwrap();
+		assert_eq!(circuit_depth(&flat), 32);
+		assert_eq!(circuit_depth(&horner), 10);
+		assert_matches!(
+			StructuredDynSize::Incrementing { max_size_log: 33 }.circuit::<B32>(Lowering::Horner),
+			Err(Error::MaxLogSizeTooLarge)
+		);
+	}
+
+	#[test]
+	fn test_structured_mle_batch() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let max_size_log = 8;
+		let variants = [
+			StructuredDynSize::Incrementing { max_size_log },
+			StructuredDynSize::IncrementingBigEndian { max_size_log },
+			StructuredDynSize::Parity { max_size_log },
+			StructuredDynSize::LowBits {
+				bits: 3,
+				max_size_log,
+			},
+			StructuredDynSize::Complement { max_size_log },
+			StructuredDynSize::Morton {
+				x_bits: 3,
+				y_bits: 5,
+			},
+			StructuredDynSize::BitPermute {
+				perm: BitPermutation::new(&[3, 0, 7, 1, 6, 2, 5, 4]).unwrap(),
+				max_size_log,
+			},
+		];
+		for n_vars in [0, 3, max_size_log] {
+			let point = repeat_with(|| B128::random(&mut rng))
+				.take(n_vars)
+				.collect::<Vec<_>>();
+			let values = StructuredMleBatch::evaluate(&variants, &point).unwrap();
+			for (variant, value) in variants.iter().zip(values) {
+				assert_eq!(value, variant.evaluate(&point).unwrap(), "{variant:?}");
+			}
+		}
+
+		let point = [B32::ONE; 8];
+		assert_matches!(
+			StructuredMleBatch::evaluate(
+				&[
+					StructuredDynSize::Incrementing { max_size_log: 8 },
+					StructuredDynSize::Incrementing { max_size_log: 64 },
+				],
+				&point,
+			),
+			Err(Error::MaxLogSizeTooLarge)
+		);
+	}
+
+	fn check_lane_id<U>()
+	where
+		U: UnderlierType
+			+ PackScalar<B1>
+			+ PackScalar<B8>
+			+ PackScalar<B16>
+			+ PackScalar<B32>
+			+ PackScalar<B64>
+			+ PackScalar<B128>
+			+ PackScalar<BinaryField128bPolyval>,
+		PackedType<U, B128>: PackedFieldIndexable
+			+ PackedTransformationFactory<PackedType<U, BinaryField128bPolyval>>,
+		PackedType<U, BinaryField128bPolyval>: PackedTransformationFactory<PackedType<U, B128>>,
+		PackedSubfield<PackedType<U, B128>, B8>: PackedFieldIndexable,
+	{
+		let width = <PackedSubfield<PackedType<U, B128>, B8> as binius_field::PackedField>::WIDTH;
+
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("lane id");
+		table.require_power_of_two_size();
+		let table_id = table.id();
+		let lane_id = table.add_structured::<B8>(
+			"lane id",
+			StructuredDynSize::lane_id::<PackedSubfield<PackedType<U, B128>, B8>>(8),
+		);
+		let reference = table.add_committed::<B8, 1>("reference");
+		table.assert_zero("reference = lane id", reference - lane_id);
+		drop(table);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness = WitnessIndex::<PackedType<U, B128>>::new(&cs, &allocator);
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |_events: &[()], index| {
+					fill_lane_id(index, lane_id)?;
+					fill_lane_id(index, reference)?;
+					Ok(())
+				}),
+				&[(); 1 << 7],
+			)
+			.unwrap();
+		{
+			let table_witness = witness.get_table(table_id).unwrap();
+			let segment = table_witness.full_segment();
+			let values = segment.get_scalars(lane_id).unwrap();
+			for (i, &value) in values.iter().enumerate() {
+				assert_eq!(value, B8::new((i % width) as u8));
+			}
+		}
+		validate_system_witness::<U>(&cs, witness, vec![]);
+	}
+
+	#[test]
+	fn test_lane_id() {
+		assert_eq!(
+			<PackedSubfield<PackedType<OptimalUnderlier128b, B128>, B8> as binius_field::PackedField>::WIDTH,
+			16
+		);
+		check_lane_id::<OptimalUnderlier128b>();
+		assert_eq!(
+			<PackedSubfield<PackedType<OptimalUnderlier256b, B128>, B8> as binius_field::PackedField>::WIDTH,
+			32
+		);
+		check_lane_id::<OptimalUnderlier256b>();
+	}
+
+	#[test]
+	fn test_padding_value() {
+		let incrementing = StructuredDynSize::Incrementing { max_size_log: 8 };
+		for row in 24..32 {
+			assert_eq!(
+				incrementing.padding_value::<B32>(24, 32, row).unwrap(),
+				B32::new(row as u32)
+			);
+		}
+
+		let complement = StructuredDynSize::Complement { max_size_log: 8 };
+		assert_eq!(complement.padding_value::<B32>(24, 32, 30).unwrap(), B32::new(0xff - 30));
+	}
+
+	#[test]
+	fn test_padding_value_errors() {
+		let incrementing = StructuredDynSize::Incrementing { max_size_log: 8 };
+		assert_matches!(
+			incrementing.padding_value::<B32>(24, 32, 23),
+			Err(Error::NotPaddingRow {
+				row: 23,
+				logical_size: 24
+			})
+		);
+		assert_matches!(
+			incrementing.padding_value::<B32>(24, 32, 32),
+			Err(Error::RowBeyondTableSize { row: 32, n_vars: 5 })
+		);
+		assert_matches!(
+			incrementing.padding_value::<B32>(24, 30, 25),
+			Err(Error::TableSizeTooLarge)
+		);
+		assert_matches!(
+			incrementing.padding_value::<B32>(24, 512, 25),
+			Err(Error::TableSizeTooLarge)
+		);
+		assert_matches!(
+			StructuredDynSize::Incrementing { max_size_log: 9 }.padding_value::<B8>(24, 32, 25),
+			Err(Error::MaxLogSizeTooLarge)
+		);
+	}
+
+	#[test]
+	fn test_mle_coefficients() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let max_size_log = 6;
+		for structured in [
+			StructuredDynSize::Incrementing { max_size_log },
+			StructuredDynSize::IncrementingBigEndian { max_size_log },
+			StructuredDynSize::Parity { max_size_log },
+			StructuredDynSize::LowBits {
+				bits: 3,
+				max_size_log,
+			},
+			StructuredDynSize::Complement { max_size_log },
+		] {
+			let expr = ArithCircuit::from(&structured.expr::<B128>().unwrap());
+			for n_vars in [0, 2, max_size_log] {
+				let coeffs = structured.mle_coefficients::<B128>(n_vars).unwrap();
+				assert_eq!(coeffs.len(), 1 << n_vars);
+				for _ in 0..4 {
+					let point = repeat_with(|| B128::random(&mut rng))
+						.take(n_vars)
+						.collect::<Vec<_>>();
+					let reconstructed = coeffs
+						.iter()
+						.enumerate()
+						.map(|(i, &coeff)| {
+							(0..n_vars)
+								.filter(|var| (i >> var) & 1 == 1)
+								.map(|var| point[var])
+								.fold(coeff, |acc, x| acc * x)
+						})
+						.sum::<B128>();
+					let padded = point
+						.iter()
+						.copied()
+						.chain(iter::repeat(B128::ZERO))
+						.take(max_size_log)
+						.collect::<Vec<_>>();
+					assert_eq!(reconstructed, expr.evaluate(&padded).unwrap());
+				}
+			}
+		}
+
+		let incrementing = StructuredDynSize::Incrementing { max_size_log };
+		assert_eq!(
+			incrementing.mle_coefficients::<B32>(2).unwrap(),
+			vec![B32::ZERO, B32::new(1), B32::new(2), B32::ZERO]
+		);
+
+		// The coefficients round-trip through an expression.
+		let coeffs = incrementing.mle_coefficients::<B128>(max_size_log).unwrap();
+		let imported =
+			ArithCircuit::from(ArithExpr::from_multilinear_coeffs(&coeffs, max_size_log).unwrap());
+		for _ in 0..4 {
+			let point = repeat_with(|| B128::random(&mut rng))
+				.take(max_size_log)
+				.collect::<Vec<_>>();
+			assert_eq!(imported.evaluate(&point).unwrap(), incrementing.evaluate(&point).unwrap());
+		}
+		assert_matches!(
+			incrementing.mle_coefficients::<B128>(max_size_log + 1),
+			Err(Error::MaxLogSizeTooLarge)
+		);
+		assert_matches!(
+			StructuredDynSize::Incrementing { max_size_log: 16 }.mle_coefficients::<B8>(0),
+			Err(Error::MaxLogSizeTooLarge)
+		);
+	}
+
+	#[test]
+	fn test_cost_estimate() {
+		let n_vars = 10;
+		let incrementing = StructuredDynSize::Incrementing {
+			max_size_log: n_vars,
+		};
+		let cost = incrementing.cost_estimate(n_vars);
+		assert_eq!(
+			cost,
+			StructuredCost {
+				degree: 1,
+				n_muls: n_vars,
+				has_closed_form: true,
+			}
+		);
+		assert_eq!(
+			cost,
+			StructuredCost::from_circuit(&ArithCircuit::from(
+				&incrementing.expr::<B128>().unwrap()
+			))
+		);
+
+		let parity = StructuredDynSize::Parity {
+			max_size_log: n_vars,
+		};
+		assert_eq!(
+			parity.cost_estimate(n_vars),
+			StructuredCost::from_circuit(&ArithCircuit::from(&parity.expr::<B128>().unwrap()))
+		);
+
+		// An indicator of a single hypercube vertex, such as `[0, ..., 0, 1]`, is the product of
+		// all variables and has degree `n_vars`.
+		let indicator = (0..n_vars)
+			.map(ArithExpr::<B128>::Var)
+			.product::<ArithExpr<B128>>();
+		let indicator_cost = StructuredCost::from_circuit(&ArithCircuit::from(&indicator));
+		assert_eq!(indicator_cost.degree, n_vars);
+		assert_eq!(indicator_cost.n_muls, n_vars - 1);
+		assert!(indicator_cost.degree > cost.degree);
+	}
+
+	#[test]
+	fn test_fill_popcount() {
+		let log_size = 6;
+
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("test");
+		let test_table_id = table.id();
+		let popcount_col = table.add_committed::<B32, 1>("popcount");
+		drop(table);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		let table_witness = witness.init_table(test_table_id, 1 << log_size).unwrap();
+		table_witness
+			.fill_sequential_with_segment_size(
+				&ClosureFiller::new(test_table_id, |_events: &[()], index| {
+					fill_popcount(index, popcount_col)?;
+					Ok(())
+				}),
+				&[(); 1 << 6],
+				4,
+			)
+			.unwrap();
+
+		let segment = table_witness.full_segment();
+		let popcount = segment.get_scalars(popcount_col).unwrap();
+		for (i, &value) in popcount.iter().enumerate() {
+			assert_eq!(value, B32::new((i as u32).count_ones()));
+		}
+	}
+
+	#[test]
+	fn test_fill_msb_position() {
+		let log_size = 6;
+
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("test");
+		let test_table_id = table.id();
+		let msb_col = table.add_committed::<B32, 1>("msb");
+		drop(table);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		let table_witness = witness.init_table(test_table_id, 1 << log_size).unwrap();
+		table_witness
+			.fill_sequential_with_segment_size(
+				&ClosureFiller::new(test_table_id, |_events: &[()], index| {
+					fill_msb_position(index, msb_col)?;
+					Ok(())
+				}),
+				&[(); 1 << 6],
+				4,
+			)
+			.unwrap();
+
+		let segment = table_witness.full_segment();
+		let msb = segment.get_scalars(msb_col).unwrap();
+		assert_eq!(msb[0], B32::ZERO);
+		for (i, &value) in msb.iter().enumerate().skip(1) {
+			assert_eq!(value, B32::new(i.ilog2()));
+		}
+	}
+
+	#[test]
+	fn test_fill_parity() {
+		let max_size_log = 6;
+
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("test");
+		table.require_power_of_two_size();
+		let test_table_id = table.id();
+		let expected_col = table.add_committed::<B32, 1>("reference");
+		let structured_col =
+			table.add_structured::<B32>("parity", StructuredDynSize::Parity { max_size_log });
+		table.assert_zero("reference = structured", expected_col - structured_col);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		{
+			let table_witness = witness
+				.init_table(test_table_id, 1 << max_size_log)
+				.unwrap();
+			table_witness
+				.fill_sequential_with_segment_size(
+					&ClosureFiller::new(test_table_id, |events: &[u32], index| {
+						{
+							let mut expected_col = index.get_scalars_mut::<B32, 1>(expected_col)?;
+							for (&i, col_i) in iter::zip(events, &mut *expected_col) {
+								*col_i = B32::new(i & 1);
+							}
+						}
+
+						fill_parity(index, structured_col)?;
+						Ok(())
+					}),
+					&(0..1 << max_size_log).collect::<Vec<_>>(),
+					4,
+				)
+				.unwrap();
+
+			let segment = table_witness.full_segment();
+			let parity = segment.get_scalars(structured_col).unwrap();
+			for (i, &value) in parity.iter().enumerate() {
+				assert_eq!(value, if i % 2 == 0 { B32::ZERO } else { B32::ONE });
+			}
+		}
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+	}
+
+	#[test]
+	fn test_low_bits() {
+		let max_size_log = 6;
+		let low_bits = StructuredDynSize::LowBits {
+			bits: 3,
+			max_size_log,
+		};
+		let expr = ArithCircuit::from(&low_bits.expr::<B32>().unwrap());
+		for i in 0..1 << max_size_log {
+			let bits = decompose_index_to_hypercube_point::<B32>(max_size_log, i);
+			assert_eq!(expr.evaluate(&bits).unwrap(), B32::new((i & 7) as u32));
+		}
+
+		// One low bit is the parity column, and all the bits are the incrementing column.
+		assert_eq!(
+			StructuredDynSize::LowBits {
+				bits: 1,
+				max_size_log
+			}
+			.expr::<B32>()
+			.unwrap(),
+			parity_expr::<B32>(max_size_log)
+		);
+		assert_eq!(
+			StructuredDynSize::LowBits {
+				bits: max_size_log,
+				max_size_log
+			}
+			.expr::<B32>()
+			.unwrap(),
+			incrementing_expr::<B32>(max_size_log).unwrap()
+		);
+
+		assert_structured_matches_fill::<B32>(
+			low_bits,
+			|index, col| fill_low_bits(index, col, 3),
+			max_size_log,
+		);
+	}
+
+	#[test]
+	fn test_morton() {
+		// The position of each bit of the row index in the code, for unequal coordinate widths.
+		let positions = |x_bits, y_bits| {
+			(0..x_bits + y_bits)
+				.map(|i| morton_bit_position(x_bits, y_bits, i))
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(positions(2, 1), [0, 2, 1]);
+		assert_eq!(positions(1, 3), [0, 1, 2, 3]);
+		assert_eq!(positions(3, 2), [0, 2, 4, 1, 3]);
+
+		// A 4x4 grid, where row `x + 4y` holds the code of `(x, y)`.
+		let morton = StructuredDynSize::Morton {
+			x_bits: 2,
+			y_bits: 2,
+		};
+		let reference = |i: usize| {
+			let (x, y) = (i & 3, i >> 2);
+			(0..2)
+				.map(|k| (((x >> k) & 1) << (2 * k)) | (((y >> k) & 1) << (2 * k + 1)))
+				.sum::<usize>()
+		};
+		assert_eq!(
+			(0..16).map(reference).collect::<Vec<_>>(),
+			[0, 1, 4, 5, 2, 3, 6, 7, 8, 9, 12, 13, 10, 11, 14, 15]
+		);
+		let expr = ArithCircuit::from(&morton.expr::<B128>().unwrap());
+		for i in 0..16 {
+			let bits = decompose_index_to_hypercube_point::<B128>(4, i);
+			assert_eq!(expr.evaluate(&bits).unwrap(), B128::new(reference(i) as u128));
+		}
+
+		// The multilinear extension off the hypercube.
+		let mut rng = StdRng::seed_from_u64(0);
+		let point = repeat_with(|| B128::random(&mut rng))
+			.take(4)
+			.collect::<Vec<_>>();
+		let expected = eq_ind_partial_eval::<B128>(&point)
+			.into_iter()
+			.enumerate()
+			.map(|(i, eq)| eq * B128::new(reference(i) as u128))
+			.sum::<B128>();
+		assert_eq!(expr.evaluate(&point).unwrap(), expected);
+		assert_eq!(morton.evaluate(&point).unwrap(), expected);
+
+		assert_structured_matches_fill::<B16>(
+			StructuredDynSize::Morton {
+				x_bits: 3,
+				y_bits: 5,
+			},
+			|index, col| fill_morton(index, col, 3, 5),
+			6,
+		);
+	}
+
+	#[test]
+	fn test_row_value_beyond_table_size() {
+		let incrementing = StructuredDynSize::Incrementing { max_size_log: 8 };
+		let n_vars = 5;
+
+		// The rows of the table have the values of the fill.
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("incrementing");
+		table.require_power_of_two_size();
+		let col = table.add_structured::<B32>("incrementing", incrementing);
+		let table_id = table.id();
+		drop(table);
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |_events: &[()], index| {
+					fill_incrementing_b32(index, col)?;
+					Ok(())
+				}),
+				&[(); 1 << 5],
+			)
+			.unwrap();
+		let segment = witness.get_table(table_id).unwrap().full_segment();
+		let filled = segment.get_scalars(col).unwrap();
+		for (row, &value) in filled.iter().enumerate() {
+			for beyond in [
+				BeyondTableSize::Wrap,
+				BeyondTableSize::Saturate,
+				BeyondTableSize::Error,
+			] {
+				assert_eq!(incrementing.row_value::<B32>(n_vars, row, beyond).unwrap(), value);
+			}
+		}
+
+		// Row 40 is beyond the 32 rows of the table.
+		assert_eq!(
+			incrementing
+				.row_value::<B32>(n_vars, 40, BeyondTableSize::default())
+				.unwrap(),
+			filled[8]
+		);
+		assert_eq!(
+			incrementing
+				.row_value::<B32>(n_vars, 40, BeyondTableSize::Saturate)
+				.unwrap(),
+			filled[31]
+		);
+		assert_matches!(
+			incrementing.row_value::<B32>(n_vars, 40, BeyondTableSize::Error),
+			Err(Error::RowBeyondTableSize { row: 40, n_vars: 5 })
+		);
+
+		// Wrapping is the expression over the variables of the table, while the full expression
+		// continues the column of the maximum size.
+		let expr = ArithCircuit::from(&incrementing.expr::<B32>().unwrap());
+		let mut bits = decompose_index_to_hypercube_point::<B32>(8, 40);
+		assert_eq!(expr.evaluate(&bits).unwrap(), B32::new(40));
+		bits[n_vars..].fill(B32::ZERO);
+		assert_eq!(
+			expr.evaluate(&bits).unwrap(),
+			incrementing
+				.row_value::<B32>(n_vars, 40, BeyondTableSize::Wrap)
+				.unwrap()
+		);
+
+		assert_matches!(
+			incrementing.row_value::<B32>(9, 0, BeyondTableSize::Wrap),
+			Err(Error::MaxLogSizeTooLarge)
+		);
+	}
+
+	#[test]
+	fn test_fix_prefix() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let max_size_log = 8;
+		let incrementing = StructuredDynSize::Incrementing { max_size_log };
+		let challenges = repeat_with(|| B128::random(&mut rng))
+			.take(2)
+			.collect::<Vec<_>>();
+		let reduced = incrementing.fix_prefix(&challenges).unwrap();
+		assert_eq!(reduced.n_vars(), max_size_log - 2);
+		// The reduced incrementing column is over the remaining basis elements, with an offset.
+		assert_eq!(
+			reduced.offset(),
+			challenges[0] * B128::new(1) + challenges[1] * <B128 as ExtensionField<B1>>::basis(1)
+		);
+		assert_eq!(reduced.weights()[0], <B128 as ExtensionField<B1>>::basis(2));
+
+		let expr = ArithCircuit::from(&incrementing.expr::<B128>().unwrap());
+		for _ in 0..4 {
+			let rest = repeat_with(|| B128::random(&mut rng))
+				.take(max_size_log - 2)
+				.collect::<Vec<_>>();
+			let pinned = [challenges.clone(), rest.clone()].concat();
+			let expected = expr.evaluate(&pinned).unwrap();
+			assert_eq!(reduced.evaluate(&rest).unwrap(), expected);
+			// Fixing the prefix in two steps gives the same column.
+			let stepwise = incrementing
+				.fix_prefix(&challenges[..1])
+				.unwrap()
+				.fix_prefix(&challenges[1..])
+				.unwrap();
+			assert_eq!(stepwise, reduced);
+		}
+
+		// The affine columns agree with the folding of their circuits.
+		let complement = StructuredDynSize::Complement { max_size_log };
+		let reduced = complement.fix_prefix(&challenges).unwrap();
+		let folded = complement
+			.fold_first_var(challenges[0])
+			.unwrap()
+			.fold_first_var(challenges[1])
+			.unwrap();
+		let rest = repeat_with(|| B128::random(&mut rng))
+			.take(max_size_log - 2)
+			.collect::<Vec<_>>();
+		assert_eq!(reduced.evaluate(&rest).unwrap(), folded.evaluate(&rest).unwrap());
+
+		assert_matches!(incrementing.fix_prefix(&[B128::ONE; 9]), Err(Error::MaxLogSizeTooLarge));
+		assert_matches!(reduced.fix_prefix(&[B128::ONE; 7]), Err(Error::NoVariablesToFold));
+		assert_matches!(reduced.evaluate(&rest[1..]), Err(Error::Math { .. }));
+	}
+
+	#[test]
+	fn test_bit_permute() {
+		let max_size_log = 8;
+		let reversal = StructuredDynSize::BitPermute {
+			perm: BitPermutation::new(&(0..max_size_log).rev().collect::<Vec<_>>()).unwrap(),
+			max_size_log,
+		};
+		let big_endian = StructuredDynSize::IncrementingBigEndian { max_size_log };
+		let reversal_expr = ArithCircuit::from(&reversal.expr::<B32>().unwrap());
+		let big_endian_expr = ArithCircuit::from(&big_endian.expr::<B32>().unwrap());
+		for i in 0..1 << max_size_log {
+			let bits = decompose_index_to_hypercube_point::<B32>(max_size_log, i);
+			assert_eq!(
+				reversal_expr.evaluate(&bits).unwrap(),
+				big_endian_expr.evaluate(&bits).unwrap()
+			);
+		}
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let point = repeat_with(|| B128::random(&mut rng))
+			.take(max_size_log)
+			.collect::<Vec<_>>();
+		assert_eq!(reversal.evaluate(&point).unwrap(), big_endian.evaluate(&point).unwrap());
+		assert_eq!(
+			reversal.mle_coefficients::<B32>(max_size_log).unwrap(),
+			big_endian.mle_coefficients::<B32>(max_size_log).unwrap()
+		);
+
+		// The Morton code is a bit permutation.
+		let perm = (0..8)
+			.map(|i| morton_bit_position(5, 3, i))
+			.collect::<Vec<_>>();
+		assert_eq!(
+			StructuredDynSize::BitPermute {
+				perm: BitPermutation::new(&perm).unwrap(),
+				max_size_log: 8
+			}
+			.evaluate(&point)
+			.unwrap(),
+			StructuredDynSize::Morton {
+				x_bits: 5,
+				y_bits: 3
+			}
+			.evaluate(&point)
+			.unwrap()
+		);
+
+		assert_structured_matches_fill::<B16>(
+			StructuredDynSize::BitPermute {
+				perm: BitPermutation::new(&[3, 0, 7, 1, 6, 2, 5, 4]).unwrap(),
+				max_size_log: 8,
+			},
+			|index, col| fill_bit_permute(index, col, &[3, 0, 7, 1, 6, 2, 5, 4]),
+			6,
+		);
+
+		for perm in [
+			vec![0, 1, 1, 2],
+			vec![0, 1, 2, 4],
+			vec![0; BitPermutation::MAX_LEN + 1],
+		] {
+			assert_matches!(
+				BitPermutation::new(&perm),
+				Err(Error::InvalidBitPermutation { perm: ref rejected, .. }) if *rejected == perm
+			);
+		}
+
+		// A permutation of fewer bits than the row index.
+		let invalid = StructuredDynSize::BitPermute {
+			perm: BitPermutation::new(&[0, 1, 2]).unwrap(),
+			max_size_log: 4,
+		};
+		assert_matches!(
+			invalid.expr::<B32>(),
+			Err(Error::InvalidBitPermutation { perm: ref rejected, max_size_log: 4 })
+				if *rejected == [0, 1, 2]
+		);
+		assert_matches!(
+			invalid.circuit::<B32>(Lowering::Horner),
+			Err(Error::InvalidBitPermutation { .. })
+		);
+		assert_matches!(invalid.evaluate(&point[..4]), Err(Error::InvalidBitPermutation { .. }));
+	}
+
+	#[test]
+	fn test_structured_field_width_check() {
+		let mut cs = ConstraintSystem::<B128>::new();
+		let mut table = cs.add_table("test");
+		table.require_power_of_two_size();
+		assert_matches!(
+			table.try_add_structured::<B16>(
+				"incrementing",
+				StructuredDynSize::Incrementing { max_size_log: 32 },
+			),
+			Err(crate::builder::Error::Structured(Error::MaxLogSizeTooLarge))
+		);
+		// The rejected column is not added.
+		drop(table);
+		assert!(cs.tables[0].columns.is_empty());
+
+		let mut table = TableBuilder::new(&mut cs.tables[0]);
+		assert!(
+			table
+				.try_add_structured::<B16>(
+					"incrementing",
+					StructuredDynSize::Incrementing { max_size_log: 16 },
+				)
+				.is_ok()
+		);
+		// Only the low bits of the index need to fit in the field.
+		assert!(
+			table
+				.try_add_structured::<B8>(
+					"low_bits",
+					StructuredDynSize::LowBits {
+						bits: 8,
+						max_size_log: 32
+					},
+				)
+				.is_ok()
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "invalid structured column")]
+	fn test_add_structured_panics_on_field_width() {
+		let mut cs = ConstraintSystem::<B128>::new();
+		let mut table = cs.add_table("test");
+		table.require_power_of_two_size();
+		table.add_structured::<B16>(
+			"incrementing",
+			StructuredDynSize::Incrementing { max_size_log: 32 },
+		);
+	}
+
+	#[test]
+	fn test_complement() {
+		let max_size_log = 5;
+		let complement = StructuredDynSize::Complement { max_size_log };
+		let expr = ArithCircuit::from(&complement.expr::<B32>().unwrap());
+		for (i, bits) in hypercube_points::<B32>(max_size_log).enumerate() {
+			assert_eq!(expr.evaluate(&bits).unwrap(), B32::new(31 - i as u32));
+		}
+
+		// The complemented bits are set by the maximum size of the column, so a smaller table
+		// holds the same values on its rows.
+		for n_vars in [3, max_size_log] {
+			check_structured_variant::<B32>(
+				complement,
+				|index, col| fill_complement_b32(index, col, max_size_log),
+				n_vars,
+			);
+		}
+		assert_structured_matches_fill::<B32>(
+			complement,
+			|index, col| fill_complement_b32(index, col, max_size_log),
+			max_size_log,
+		);
+	}
+
+	#[test]
+	fn test_sub_element_index() {
+		let max_size_log = 6;
+		let index = StructuredDynSize::sub_element_index::<B128, B32>(max_size_log);
+		assert_eq!(
+			index,
+			StructuredDynSize::LowBits {
+				bits: 2,
+				max_size_log
+			}
+		);
+		check_structured_variant::<B8>(index, fill_sub_element_index::<_, B8, B32>, max_size_log);
+
+		let basis = SubElementBasis::<B32>::new(max_size_log);
+		// The expression is over the two variables of the position of B32 in B128 only.
+		let basis_expr = ArithCircuit::from(&Structured::<B128>::expr(&basis).unwrap());
+		assert_eq!(basis_expr.n_vars(), 2);
+		check_structured_variant::<B128>(basis, fill_sub_element_basis::<_, B32>, max_size_log);
+		assert_structured_matches_fill::<B128>(
+			basis,
+			fill_sub_element_basis::<_, B32>,
+			max_size_log,
+		);
+
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("sub-elements");
+		table.require_power_of_two_size();
+		let table_id = table.id();
+		let index_col = table.add_structured::<B8>("index", index);
+		let basis_col = table.add_structured::<B128>("basis", basis);
+		drop(table);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |_events: &[()], index| {
+					fill_sub_element_index::<_, B8, B32>(index, index_col)?;
+					fill_sub_element_basis::<_, B32>(index, basis_col)?;
+					Ok(())
+				}),
+				&vec![(); 1 << max_size_log],
+			)
+			.unwrap();
+		let segment = witness.get_table(table_id).unwrap().full_segment();
+		let positions = segment.get_scalars(index_col).unwrap();
+		let bases = segment.get_scalars(basis_col).unwrap();
+		for (i, (&position, &basis)) in iter::zip(&*positions, &*bases).enumerate() {
+			assert_eq!(position, B8::new((i % 4) as u8));
+			assert_eq!(basis, <B128 as ExtensionField<B32>>::basis(i % 4));
+			// The basis element of position `j` is the `32 * j`-th bit of B128.
+			assert_eq!(basis, <B128 as ExtensionField<B1>>::basis(32 * (i % 4)));
+		}
+
+		// Summing the values of each group of four rows times the basis column embeds them as
+		// the packed B128 element.
+		let mut rng = StdRng::seed_from_u64(0);
+		let values = repeat_with(|| B32::random(&mut rng))
+			.take(1 << max_size_log)
+			.collect::<Vec<_>>();
+		for (chunk, chunk_bases) in iter::zip(values.chunks(4), bases.chunks(4)) {
+			let embedded = iter::zip(chunk, chunk_bases)
+				.map(|(&value, &basis)| basis * value)
+				.sum::<B128>();
+			assert_eq!(embedded, B128::from_bases(chunk.iter().copied()).unwrap());
+		}
+	}
+
+	#[test]
+	fn test_scaled() {
+		let factor = B32::new(0x1234_5678);
+		let scaled = Scaled {
+			inner: StructuredDynSize::Incrementing { max_size_log: 8 },
+			factor: B128::from(factor),
+		};
+
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("scaled");
+		table.require_power_of_two_size();
+		let table_id = table.id();
+		let col = table.add_structured::<B32>("scaled", scaled.clone());
+		drop(table);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |_events: &[()], index| {
+					fill_scaled(index, col, factor, fill_incrementing_b32)?;
+					Ok(())
+				}),
+				&[(); 1 << 6],
+			)
+			.unwrap();
+		let values = witness
+			.get_table(table_id)
+			.unwrap()
+			.full_segment()
+			.get_scalars(col)
+			.unwrap()
+			.to_vec();
+		for i in [0, 1, 17, 63] {
+			assert_eq!(values[i], B32::new(i as u32) * factor);
+		}
+
+		assert_structured_matches_fill::<B32>(
+			scaled,
+			|index, col| fill_scaled(index, col, factor, fill_incrementing_b32),
+			6,
+		);
+
+		// The factor must be in the field of the column.
+		let mut table = cs.add_table("too wide");
+		table.require_power_of_two_size();
+		assert_matches!(
+			table.try_add_structured::<B16>(
+				"scaled",
+				Scaled {
+					inner: StructuredDynSize::Incrementing { max_size_log: 8 },
+					factor: B128::from(factor),
+				},
+			),
+			Err(crate::builder::Error::Structured(Error::FactorNotInField))
+		);
+	}
+
+	#[test]
+	fn test_sum() {
+		let offset = B32::new(0xabcd_0000);
+		let sum = Sum {
+			lhs: StructuredDynSize::Incrementing { max_size_log: 8 },
+			rhs: ConstantStructured {
+				value: B128::from(offset),
+				max_size_log: 8,
+			},
+		};
+		assert_eq!(Structured::<B128>::max_size_log(&sum), 8);
+
+		// The sum is the incrementing column starting from the offset, as the offset has no bits
+		// in common with the row indices.
+		let fill_incrementing_from = |index: &mut TableWitnessSegment<_>, col: Col<B32>| {
+			for (i, value) in index.get_scalars_mut(col)?.iter_mut().enumerate() {
+				*value = B32::new(0xabcd_0000 + i as u32);
+			}
+			Ok(())
+		};
+		let fill_constant = |index: &mut TableWitnessSegment<_>, col: Col<B32>| {
+			index.get_scalars_mut(col)?.fill(offset);
+			Ok(())
+		};
+		let fill = |index: &mut TableWitnessSegment<_>, col| {
+			fill_sum(index, col, fill_incrementing_b32, fill_constant)
+		};
+		check_structured_variant::<B32>(sum, fill_incrementing_from, 8);
+		assert_structured_matches_fill::<B32>(
+			Sum {
+				lhs: StructuredDynSize::Incrementing { max_size_log: 8 },
+				rhs: ConstantStructured {
+					value: B128::from(offset),
+					max_size_log: 8,
+				},
+			},
+			fill,
+			6,
+		);
+
+		// Both columns must fit the table.
+		let sum = Sum {
+			lhs: StructuredDynSize::Incrementing { max_size_log: 8 },
+			rhs: StructuredDynSize::Incrementing { max_size_log: 4 },
+		};
+		assert_matches!(Structured::<B128>::check_nvars(&sum, 6), Err(Error::MaxLogSizeTooLarge));
+		assert_matches!(Structured::<B128>::check_nvars(&sum, 4), Ok(()));
+	}
+
+	#[test]
+	fn test_affine() {
+		let max_size_log = 8;
+		let slope = B32::new(0x1234_5678);
+		let intercept = B32::new(0x9abc_def0);
+		let affine = Affine {
+			slope: B128::from(slope),
+			intercept: B128::from(intercept),
+			max_size_log,
+		};
+		let fill = |index: &mut TableWitnessSegment<OptimalPackedB128>, col| {
+			fill_affine(index, col, slope, intercept)
+		};
+		check_structured_variant::<B32>(affine.clone(), fill, 6);
+		assert_structured_matches_fill::<B32>(affine.clone(), fill, max_size_log);
+
+		// The values at sampled rows.
+		let expr = ArithCircuit::from(&affine.expr().unwrap());
+		for i in [0, 1, 2, 37, 128, 255] {
+			let bits = decompose_index_to_hypercube_point::<B128>(max_size_log, i);
+			assert_eq!(
+				expr.evaluate(&bits).unwrap(),
+				B128::from(slope * B32::new(i as u32) + intercept)
+			);
+		}
+
+		// The multilinear extension off the hypercube.
+		let mut rng = StdRng::seed_from_u64(0);
+		let point = repeat_with(|| B128::random(&mut rng))
+			.take(max_size_log)
+			.collect::<Vec<_>>();
+		let expected = eq_ind_partial_eval::<B128>(&point)
+			.into_iter()
+			.enumerate()
+			.map(|(i, eq)| eq * (slope * B32::new(i as u32) + intercept))
+			.sum::<B128>();
+		assert_eq!(expr.evaluate(&point).unwrap(), expected);
+
+		// The slope and intercept must be in the column field.
+		let mut cs = ConstraintSystem::<B128>::new();
+		let mut table = cs.add_table("test");
+		table.require_power_of_two_size();
+		assert_matches!(
+			table.try_add_structured::<B16>("affine", affine),
+			Err(crate::builder::Error::Structured(Error::FactorNotInField))
+		);
+	}
+
+	#[test]
+	fn test_structured_table() {
+		const LOG_SIZE: usize = 3;
+		// The first entries of the AES S-box.
+		let sbox = [0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5].map(B8::new);
+		let table_column =
+			StructuredTable::<B128>::new(sbox.iter().map(|&v| v.into()).collect(), LOG_SIZE)
+				.unwrap();
+
+		// The interpolated extension agrees with the values on and off the hypercube.
+		let expr = ArithCircuit::from(&table_column.expr().unwrap());
+		assert_eq!(expr.n_vars(), LOG_SIZE);
+		for (i, &value) in sbox.iter().enumerate() {
+			let bits = decompose_index_to_hypercube_point::<B128>(LOG_SIZE, i);
+			assert_eq!(expr.evaluate(&bits).unwrap(), value.into());
+		}
+		let mut rng = StdRng::seed_from_u64(0);
+		let point = repeat_with(|| B128::random(&mut rng))
+			.take(LOG_SIZE)
+			.collect::<Vec<_>>();
+		let expected = iter::zip(eq_ind_partial_eval::<B128>(&point), sbox)
+			.map(|(eq, value)| eq * value)
+			.sum::<B128>();
+		assert_eq!(expr.evaluate(&point).unwrap(), expected);
+
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("sbox");
+		table.require_power_of_two_size();
+		let table_id = table.id();
+		let reference = table.add_committed::<B8, 1>("reference");
+		let sbox_col = table.add_structured::<B8>("sbox", table_column);
+		table.assert_zero("reference = sbox", reference - sbox_col);
+		drop(table);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |events: &[B8], index| {
+					{
+						let mut reference = index.get_scalars_mut(reference)?;
+						for (&event, reference) in iter::zip(events, &mut *reference) {
+							*reference = event;
+						}
+					}
+					fill_table_values(index, sbox_col, &sbox)?;
+					Ok(())
+				}),
+				&sbox,
+			)
+			.unwrap();
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+	}
+
+	#[test]
+	fn test_structured_table_size_limits() {
+		assert_matches!(
+			StructuredTable::<B128>::new(vec![B128::ONE; 9], 3),
+			Err(Error::TableSizeTooLarge)
+		);
+
+		// The expression only uses the variables that the values depend on.
+		let single = StructuredTable::<B128>::new(vec![B128::ONE], 4).unwrap();
+		assert_eq!(ArithCircuit::from(&single.expr().unwrap()).n_vars(), 4);
+		let constant = StructuredTable::<B128>::new(vec![B128::ONE; 1 << 4], 4).unwrap();
+		let constant_expr = ArithCircuit::from(&constant.expr().unwrap());
+		assert_eq!(constant_expr.n_vars(), 0);
+		assert_eq!(constant_expr.evaluate(&[]).unwrap(), B128::ONE);
+		let low_bit =
+			StructuredTable::<B128>::new((0..1 << 4).map(|i| B128::new(i & 1)).collect(), 4)
+				.unwrap();
+		assert_eq!(ArithCircuit::from(&low_bit.expr().unwrap()).n_vars(), 1);
+
+		let log_size = StructuredTable::<B128>::MAX_INTERPOLATION_LOG_SIZE + 1;
+		let too_large = StructuredTable::<B128>::new(Vec::new(), log_size).unwrap();
+		assert_matches!(
+			too_large.expr(),
+			Err(Error::TableTooLargeToInterpolate { log_size: size }) if size == log_size
+		);
+	}
+
+	#[test]
+	fn test_fold_incrementing_first_var() {
+		let n_vars = 5;
+		let incrementing = StructuredDynSize::Incrementing {
+			max_size_log: n_vars,
+		};
+		let expr = ArithCircuit::from(&incrementing.expr::<B128>().unwrap());
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let challenge = B128::random(&mut rng);
+		let folded = incrementing.fold_first_var(challenge).unwrap();
+		assert_eq!(folded.n_vars(), n_vars - 1);
+		// The folded incrementing MLE remains linear.
+		assert_eq!(folded.expr().degree(), 1);
+
+		for _ in 0..16 {
+			let point = repeat_with(|| B128::random(&mut rng))
+				.take(n_vars - 1)
+				.collect::<Vec<_>>();
+			let pinned_point = iter::once(challenge)
+				.chain(point.iter().copied())
+				.collect::<Vec<_>>();
+			assert_eq!(folded.evaluate(&point).unwrap(), expr.evaluate(&pinned_point).unwrap());
+		}
+
+		// Fold all the way down to a constant.
+		let mut folded = folded;
+		let mut challenges = vec![challenge];
+		while folded.n_vars() > 0 {
+			let challenge = B128::random(&mut rng);
+			folded = folded.fold_first_var(challenge).unwrap();
+			challenges.push(challenge);
+		}
+		assert_eq!(folded.evaluate(&[]).unwrap(), expr.evaluate(&challenges).unwrap());
+		assert_matches!(folded.fold_first_var(challenge), Err(Error::NoVariablesToFold));
+	}
+
+	#[test]
+	fn test_expr_matches_materialize() {
+		let incrementing = StructuredDynSize::Incrementing { max_size_log: 8 };
+		for n_vars in [0, 1, 5, 8] {
+			assert_expr_matches_materialize::<B32, PackedType<OptimalUnderlier128b, B32>>(
+				&incrementing,
+				n_vars,
+			);
+		}
+
+		// Every built-in variant, with packings wider and narrower than the table.
+		let max_size_log = 7;
+		let variants = [
+			StructuredDynSize::Incrementing { max_size_log },
+			StructuredDynSize::IncrementingBigEndian { max_size_log },
+			StructuredDynSize::Parity { max_size_log },
+			StructuredDynSize::LowBits {
+				bits: 3,
+				max_size_log,
+			},
+			StructuredDynSize::Complement { max_size_log },
+			StructuredDynSize::Morton {
+				x_bits: 4,
+				y_bits: 3,
+			},
+			StructuredDynSize::BitPermute {
+				perm: BitPermutation::new(&[6, 0, 5, 1, 4, 2, 3]).unwrap(),
+				max_size_log,
+			},
+		];
+		for variant in &variants {
+			for n_vars in [0, 2, max_size_log] {
+				assert_expr_matches_materialize::<B16, PackedType<OptimalUnderlier128b, B16>>(
+					variant, n_vars,
+				);
+				assert_expr_matches_materialize::<B128, PackedType<OptimalUnderlier256b, B128>>(
+					variant, n_vars,
+				);
+			}
+		}
+
+		assert_matches!(
+			incrementing.materialize::<B32, PackedType<OptimalUnderlier128b, B32>>(9),
+			Err(Error::MaxLogSizeTooLarge)
+		);
 	}
-	let expr = (0..max_log_size)
-		.map(|i| ArithExpr::Var(i) * ArithExpr::Const(<F as ExtensionField<B1>>::basis(i)))
-		.sum::<ArithExpr<F>>();
-	Ok(expr)
-}
 
-#[cfg(test)]
-mod tests {
-	use std::iter::{self};
+	#[test]
+	fn test_evaluate_all_cube() {
+		let n_vars = 10;
+		let incrementing = StructuredDynSize::Incrementing {
+			max_size_log: n_vars,
+		};
+		let evaluator = ArithCircuitPoly::new(incrementing.expr::<B32>().unwrap().into());
+		let values = incrementing
+			.evaluate_all_cube::<PackedType<OptimalUnderlier128b, B32>>(n_vars)
+			.unwrap();
+		assert_eq!(values.len(), (1 << n_vars) / 4);
+		for (i, point) in hypercube_points::<B32>(n_vars).enumerate() {
+			assert_eq!(
+				binius_field::packed::get_packed_slice(&values, i),
+				evaluator.evaluate(&point).unwrap()
+			);
+		}
 
-	use binius_compute::cpu::alloc::CpuComputeAllocator;
-	use binius_core::polynomial::test_utils::decompose_index_to_hypercube_point;
-	use binius_fast_compute::arith_circuit::ArithCircuitPoly;
-	use binius_field::{BinaryField32b, arch::OptimalUnderlier128b, as_packed_field::PackedType};
-	use binius_math::{ArithCircuit, CompositionPoly};
-	use itertools::izip;
+		// A packing wider than the hypercube pads the single packed element with zeros.
+		let values = incrementing
+			.evaluate_all_cube::<PackedType<OptimalUnderlier256b, B32>>(2)
+			.unwrap();
+		assert_eq!(
+			binius_field::PackedField::iter_slice(&values).collect::<Vec<_>>(),
+			[0, 1, 2, 3, 0, 0, 0, 0].map(B32::new)
+		);
 
-	use super::*;
-	use crate::{
-		builder::{
-			B16, B32, B128, ConstraintSystem, WitnessIndex,
-			test_utils::{ClosureFiller, validate_system_witness},
-		},
-		gadgets::structured::fill_incrementing_b32,
-	};
+		assert_matches!(
+			incrementing.evaluate_all_cube::<PackedType<OptimalUnderlier128b, B32>>(n_vars + 1),
+			Err(Error::MaxLogSizeTooLarge)
+		);
+	}
 
 	#[test]
-	fn test_incrementing_expr() {
-		let expr = incrementing_expr::<B32>(5).unwrap();
-		let evaluator = ArithCircuitPoly::new(expr.into());
-		for i in 0..1 << 5 {
-			let bits = decompose_index_to_hypercube_point::<B32>(5, i);
-			assert_eq!(evaluator.evaluate(&bits).unwrap(), B32::new(i as u32));
+	fn test_transcript_bytes() {
+		let variants = [
+			StructuredDynSize::Incrementing { max_size_log: 4 },
+			StructuredDynSize::IncrementingBigEndian { max_size_log: 4 },
+			StructuredDynSize::Parity { max_size_log: 4 },
+			StructuredDynSize::LowBits {
+				bits: 2,
+				max_size_log: 4,
+			},
+			StructuredDynSize::LowBits {
+				bits: 4,
+				max_size_log: 2,
+			},
+			StructuredDynSize::Complement { max_size_log: 4 },
+			StructuredDynSize::Complement { max_size_log: 5 },
+			StructuredDynSize::Morton {
+				x_bits: 2,
+				y_bits: 2,
+			},
+			StructuredDynSize::BitPermute {
+				perm: BitPermutation::new(&[0, 1, 2, 3]).unwrap(),
+				max_size_log: 4,
+			},
+			StructuredDynSize::BitPermute {
+				perm: BitPermutation::new(&[1, 0, 2, 3]).unwrap(),
+				max_size_log: 4,
+			},
+		];
+		for (i, a) in variants.iter().enumerate() {
+			assert_eq!(a.transcript_bytes(), a.clone().transcript_bytes());
+			for b in &variants[i + 1..] {
+				assert_ne!(a.transcript_bytes(), b.transcript_bytes(), "{a:?} and {b:?}");
+			}
 		}
+
+		let mut expected = vec![0];
+		expected.extend(4u64.to_le_bytes());
+		assert_eq!(variants[0].transcript_bytes(), expected);
+	}
+
+	#[test]
+	fn test_add_incrementing() {
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("test");
+		table.require_power_of_two_size();
+		let test_table_id = table.id();
+		let explicit = table.add_structured::<B32>(
+			"explicit",
+			StructuredDynSize::Incrementing { max_size_log: 32 },
+		);
+		let shortcut = table.add_incrementing::<B32>("shortcut");
+		let big_endian = table.add_incrementing_big_endian::<B8>("big_endian");
+		let complement = table.add_complement::<B16>("complement");
+		table.assert_eq("explicit = shortcut", explicit, shortcut);
+		drop(table);
+
+		let variant = |col: ColumnId| match &cs.tables[test_table_id][col].col {
+			ColumnDef::StructuredDynSize(structured) => structured.as_dyn_size(),
+			_ => None,
+		};
+		assert_eq!(variant(shortcut.id()), variant(explicit.id()));
+		assert_eq!(
+			variant(big_endian.id()),
+			Some(StructuredDynSize::IncrementingBigEndian { max_size_log: 8 })
+		);
+		assert_eq!(
+			variant(complement.id()),
+			Some(StructuredDynSize::Complement { max_size_log: 16 })
+		);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(test_table_id, |_events: &[()], index| {
+					fill_incrementing_b32(index, explicit)?;
+					fill_incrementing_b32(index, shortcut)?;
+					Ok(())
+				}),
+				&[(); 1 << 5],
+			)
+			.unwrap();
+		witness.fill_structured_cols().unwrap();
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
 	}
 
 	#[test]
@@ -121,7 +3494,7 @@ mod tests {
 			"incrementing",
 			StructuredDynSize::Incrementing { max_size_log: 32 },
 		);
-		table.assert_zero("reference = structured", expected_col - structured_col);
+		table.assert_eq("reference = structured", expected_col, structured_col);
 
 		let mut allocator = CpuComputeAllocator::new(1 << 12);
 		let allocator = allocator.into_bump_allocator();
@@ -152,6 +3525,366 @@ mod tests {
 		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
 	}
 
+	#[test]
+	fn test_structured_column_macro() {
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("test");
+		table.require_power_of_two_size();
+		let test_table_id = table.id();
+		let expected_col = table.add_committed::<B32, 1>("reference");
+		let structured_col = crate::structured_column!(
+			table,
+			"incrementing",
+			B32,
+			Incrementing { max_size_log: 32 },
+			reference = expected_col,
+		);
+		drop(table);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(test_table_id, |events: &[u32], index| {
+					{
+						let mut expected_col = index.get_scalars_mut::<B32, 1>(expected_col)?;
+						for (&i, col_i) in iter::zip(events, &mut *expected_col) {
+							*col_i = B32::new(i);
+						}
+					}
+
+					fill_incrementing_b32(index, structured_col)?;
+					Ok(())
+				}),
+				&(0..1 << 5).collect::<Vec<_>>(),
+			)
+			.unwrap();
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+	}
+
+	#[test]
+	fn test_fill_incrementing_saturating() {
+		let max_size_log = 6;
+		let max_value = 20;
+
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("test");
+		table.require_power_of_two_size();
+		let test_table_id = table.id();
+		let saturating = SaturatingIncrementing::new(&mut table, max_size_log, max_value);
+		drop(table);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		{
+			let table_witness = witness
+				.init_table(test_table_id, 1 << max_size_log)
+				.unwrap();
+			table_witness
+				.fill_sequential_with_segment_size(
+					&ClosureFiller::new(test_table_id, |_events: &[()], index| {
+						saturating.populate(index)?;
+						Ok(())
+					}),
+					&vec![(); 1 << max_size_log],
+					4,
+				)
+				.unwrap();
+
+			let segment = table_witness.full_segment();
+			let output = segment.get_scalars(saturating.output).unwrap();
+			for (i, &value) in output.iter().enumerate() {
+				assert_eq!(value, B32::new((i as u32).min(max_value)));
+			}
+			// The column plateaus from the boundary row onwards.
+			assert!(
+				output[max_value as usize..]
+					.iter()
+					.all(|&value| value == B32::new(max_value))
+			);
+		}
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+	}
+
+	#[test]
+	fn test_saturating_incrementing_rejects_early_saturation() {
+		let max_size_log = 6;
+		let max_value = 20;
+
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("test");
+		table.require_power_of_two_size();
+		let test_table_id = table.id();
+		let saturating = SaturatingIncrementing::new(&mut table, max_size_log, max_value);
+		drop(table);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(test_table_id, |_events: &[()], index| {
+					saturating.populate(index)?;
+					// Saturate one row early, which satisfies the zero constraint but not the
+					// structured flag.
+					let row = max_value as usize - 1;
+					set_packed_slice(&mut index.get_mut(saturating.saturated)?, row, B1::ONE);
+					index.get_scalars_mut(saturating.output)?[row] = B32::new(max_value);
+					Ok(())
+				}),
+				&vec![(); 1 << max_size_log],
+			)
+			.unwrap();
+
+		assert!(witness.zero_constraint_failures().unwrap().is_empty());
+
+		let table_sizes = witness.table_sizes();
+		let ccs = cs.compile().unwrap();
+		let witness = witness.into_multilinear_extension_index();
+		assert!(
+			binius_core::constraint_system::validate::validate_witness(
+				&ccs,
+				&[],
+				&table_sizes,
+				&witness
+			)
+			.is_err()
+		);
+	}
+
+	#[test]
+	fn test_index_at_least() {
+		for threshold in [0, 1, 20, 37, 63] {
+			for n_vars in [0, 3, 6] {
+				check_structured_variant::<B1>(
+					IndexAtLeast {
+						threshold,
+						max_size_log: 6,
+					},
+					|index, col| fill_index_at_least(index, col, threshold),
+					n_vars,
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn test_inverse_table() {
+		let max_size_log = 6;
+
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("test");
+		table.require_power_of_two_size();
+		let test_table_id = table.id();
+		let inverse_table = InverseTable::new(&mut table, max_size_log);
+		drop(table);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		{
+			let table_witness = witness
+				.init_table(test_table_id, 1 << max_size_log)
+				.unwrap();
+			table_witness
+				.fill_sequential_with_segment_size(
+					&ClosureFiller::new(test_table_id, |_events: &[()], index| {
+						inverse_table.populate(index)?;
+						Ok(())
+					}),
+					&vec![(); 1 << max_size_log],
+					4,
+				)
+				.unwrap();
+
+			let segment = table_witness.full_segment();
+			let index = segment.get_scalars(inverse_table.index).unwrap();
+			let inverse = segment.get_scalars(inverse_table.inverse).unwrap();
+			assert_eq!(inverse[0], B32::ZERO);
+			for i in 1..1 << max_size_log {
+				assert_eq!(index[i], B32::new(i as u32));
+				assert_eq!(index[i] * inverse[i], B32::ONE);
+			}
+		}
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+	}
+
+	#[test]
+	fn test_prefix_xor_index() {
+		let max_size_log = 6;
+
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("test");
+		table.require_fixed_size(max_size_log);
+		let test_table_id = table.id();
+		let prefix_xor = PrefixXorIndex::new(&mut table, max_size_log);
+		drop(table);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		{
+			let table_witness = witness
+				.init_table(test_table_id, 1 << max_size_log)
+				.unwrap();
+			table_witness
+				.fill_sequential_with_segment_size(
+					&ClosureFiller::new(test_table_id, |_events: &[()], index| {
+						prefix_xor.populate(index)?;
+						Ok(())
+					}),
+					&vec![(); 1 << max_size_log],
+					// The carry is threaded into every segment after the first.
+					4,
+				)
+				.unwrap();
+
+			let segment = table_witness.full_segment();
+			let output = segment.get_scalars(prefix_xor.output).unwrap();
+			let mut expected = 0;
+			for (i, &value) in output.iter().enumerate() {
+				expected ^= i as u32;
+				assert_eq!(value, B32::new(expected));
+			}
+		}
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+	}
+
+	#[test]
+	fn test_prefix_xor_index_rejects_restarted_xor() {
+		let max_size_log = 6;
+
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("test");
+		table.require_fixed_size(max_size_log);
+		let test_table_id = table.id();
+		let prefix_xor = PrefixXorIndex::new(&mut table, max_size_log);
+		drop(table);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		{
+			let table_witness = witness
+				.init_table(test_table_id, 1 << max_size_log)
+				.unwrap();
+			table_witness
+				.fill_sequential_with_segment_size(
+					&ClosureFiller::new(test_table_id, |_events: &[()], index| {
+						prefix_xor.populate(index)?;
+						Ok(())
+					}),
+					&vec![(); 1 << max_size_log],
+					4,
+				)
+				.unwrap();
+
+			// Restart the running XOR in the middle of the table, keeping every row consistent
+			// with its own previous output. The running XOR before row 33 is nonzero.
+			let segment = table_witness.full_segment();
+			let mut output = segment.get_scalars_mut(prefix_xor.output).unwrap();
+			let mut output_prev = segment.get_scalars_mut(prefix_xor.output_prev).unwrap();
+			let start = 33;
+			let mut carry = 0;
+			for i in start..output.len() {
+				output_prev[i] = B32::new(carry);
+				carry ^= i as u32;
+				output[i] = B32::new(carry);
+			}
+		}
+
+		assert!(witness.zero_constraint_failures().unwrap().is_empty());
+
+		let table_sizes = witness.table_sizes();
+		let ccs = cs.compile().unwrap();
+		let witness = witness.into_multilinear_extension_index();
+		assert!(
+			binius_core::constraint_system::validate::validate_witness(
+				&ccs,
+				&[],
+				&table_sizes,
+				&witness
+			)
+			.is_err()
+		);
+	}
+
+	#[test]
+	fn test_var_allocator_composition() {
+		const LOG_SIZE: usize = 8;
+
+		// A sub-gadget that decomposes a nibble into its bits, placed at the given bit offset.
+		let nibble = |offset: usize| {
+			(0..4)
+				.map(|i| {
+					ArithExpr::Var(i)
+						* ArithExpr::Const(<B128 as ExtensionField<B1>>::basis(offset + i))
+				})
+				.sum::<ArithExpr<B128>>()
+		};
+
+		let mut vars = VarAllocator::new();
+		let low = vars.embed(4, &nibble(0)).unwrap();
+		let high = vars.embed(4, &nibble(4)).unwrap();
+		assert_eq!(vars.n_vars(), LOG_SIZE);
+		assert_eq!(ArithCircuit::from(&low).vars_usage(), [true; 4]);
+		assert_eq!(
+			ArithCircuit::from(&high).vars_usage(),
+			[false, false, false, false, true, true, true, true]
+		);
+
+		// Embedding an expression with more variables than requested allocates nothing.
+		let err = vars.embed(2, &nibble(0)).unwrap_err();
+		assert_matches!(err, Error::Math { .. });
+		assert!(
+			err.to_string()
+				.contains("embedding of an expression over 2 variables at variable 8")
+		);
+		assert_eq!(vars.alloc(0), LOG_SIZE..LOG_SIZE);
+
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("test");
+		table.require_fixed_size(LOG_SIZE);
+		let table_id = table.id();
+		let reference = table.add_committed::<B8, 1>("reference");
+		let byte = table.add_fixed::<B8>("byte", ArithCircuit::from(&(low + high)));
+		table.assert_zero("reference = byte", reference - byte);
+		drop(table);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |events: &[u8], index| {
+					let mut reference = index.get_scalars_mut(reference)?;
+					let mut byte = index.get_scalars_mut(byte)?;
+					for (&event, reference, byte) in izip!(events, &mut *reference, &mut *byte) {
+						*reference = B8::new(event);
+						*byte = B8::new(event);
+					}
+					Ok(())
+				}),
+				&(0..=u8::MAX).collect::<Vec<_>>(),
+			)
+			.unwrap();
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+	}
+
 	#[test]
 	fn test_fill_bitwise_and() {
 		let log_size = 8;
@@ -211,4 +3944,84 @@ mod tests {
 
 		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
 	}
+
+	#[test]
+	fn test_embed() {
+		let max_size_log = 8;
+		let incrementing = StructuredDynSize::Incrementing { max_size_log };
+		let embed = |inner, to_field_offset| Embed {
+			inner,
+			from_bits: 32,
+			to_field_offset,
+		};
+
+		// An incrementing B32 column in bits 32..64 of a B128 column.
+		let embedded = embed(incrementing, 32);
+		let expr = ArithCircuit::from(&Structured::<B128>::expr(&embedded).unwrap());
+		for (i, bits) in hypercube_points::<B128>(max_size_log).enumerate() {
+			assert_eq!(expr.evaluate(&bits).unwrap(), B128::new((i as u128) << 32));
+		}
+		assert_structured_matches_fill::<B128>(
+			embedded,
+			|index, col| {
+				let mut values = index.get_scalars_mut(col)?;
+				for (i, value) in values.iter_mut().enumerate() {
+					*value = B128::new((i as u128) << 32);
+				}
+				Ok(())
+			},
+			max_size_log,
+		);
+
+		// The constant term of the inner column is embedded too.
+		let complement = embed(StructuredDynSize::Complement { max_size_log }, 64);
+		let expr = ArithCircuit::from(&Structured::<B128>::expr(&complement).unwrap());
+		for (i, bits) in hypercube_points::<B128>(max_size_log).enumerate() {
+			assert_eq!(expr.evaluate(&bits).unwrap(), B128::new((255 - i as u128) << 64));
+		}
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let point = repeat_with(|| B128::random(&mut rng))
+			.take(max_size_log)
+			.collect::<Vec<_>>();
+		for column in [embedded, complement] {
+			let expr = ArithCircuit::from(&Structured::<B128>::expr(&column).unwrap());
+			assert_eq!(column.evaluate(&point).unwrap(), expr.evaluate(&point).unwrap());
+			let padded = point[..3]
+				.iter()
+				.copied()
+				.chain(iter::repeat_n(B128::ZERO, max_size_log - 3))
+				.collect::<Vec<_>>();
+			assert_eq!(column.evaluate(&point[..3]).unwrap(), expr.evaluate(&padded).unwrap());
+		}
+
+		// The embedded values must fit in the field of the column, and the inner values in
+		// `from_bits` bits.
+		assert_matches!(Structured::<B32>::expr(&embedded), Err(Error::MaxLogSizeTooLarge));
+		assert_matches!(
+			Structured::<B128>::expr(&embed(incrementing, 100)),
+			Err(Error::MaxLogSizeTooLarge)
+		);
+		let too_wide = Embed {
+			inner: incrementing,
+			from_bits: 4,
+			to_field_offset: 0,
+		};
+		assert_matches!(Structured::<B128>::expr(&too_wide), Err(Error::MaxLogSizeTooLarge));
+		assert_matches!(too_wide.evaluate(&point), Err(Error::MaxLogSizeTooLarge));
+
+		// The inner column is checked like a column of its own.
+		let invalid = embed(
+			StructuredDynSize::BitPermute {
+				perm: BitPermutation::new(&[1, 0]).unwrap(),
+				max_size_log: 4,
+			},
+			0,
+		);
+		assert_matches!(invalid.evaluate(&point[..4]), Err(Error::InvalidBitPermutation { .. }));
+		assert_matches!(
+			Structured::<B128>::expr(&invalid),
+			Err(Error::InvalidBitPermutation { .. })
+		);
+	}
 }
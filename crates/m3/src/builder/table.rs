@@ -1,6 +1,10 @@
 // Copyright 2025 Irreducible Inc.
 
-use std::{ops::Index, sync::Arc};
+use std::{
+	collections::{BTreeMap, HashSet},
+	ops::Index,
+	sync::Arc,
+};
 
 pub use binius_core::constraint_system::TableId;
 use binius_core::{
@@ -22,14 +26,16 @@ use binius_utils::{
 	checked_arithmetics::{checked_log_2, log2_ceil_usize, log2_strict_usize},
 	sparse_index::SparseIndex,
 };
+use rand::{Rng, SeedableRng, rngs::StdRng};
 
 use super::{
 	B1, ColumnIndex, ColumnPartitionIndex, FlushOpts,
 	channel::Flush,
 	column::{Col, ColumnDef, ColumnId, ColumnInfo, ColumnShape},
+	error::Error,
 	expr::{Expr, ZeroConstraint},
 	stat::TableStat,
-	structured::StructuredDynSize,
+	structured::{Structured, StructuredDynSize},
 	types::B128,
 	upcast_col,
 };
@@ -73,6 +79,25 @@ impl<'a, F: TowerField> TableBuilder<'a, F> {
 		self.table.table_size_spec = TableSizeSpec::Fixed { log_size };
 	}
 
+	/// Reserves `n` rows at the end of the table for random blinding values.
+	///
+	/// The blinding rows follow the rows populated from events and count towards the table size,
+	/// so the size requirements of the table, e.g. [`Self::require_power_of_two_size`], apply to
+	/// the number of events plus `n`. The committed columns are filled on the blinding rows with
+	/// [`TableWitnessIndex::fill_blinding_rows`].
+	///
+	/// The blinding rows do not hold events, so zero constraints over committed columns generally
+	/// do not hold on them. Such constraints must be gated with a selector that is zero on the
+	/// blinding rows for the witness to pass validation of the compiled constraint system. For the
+	/// same reason, every flush of the table must have a selector, which is set to zero on the
+	/// blinding rows, or the constraint system fails to compile with
+	/// [`Error::UnselectedFlushWithBlindingRows`].
+	///
+	/// [`TableWitnessIndex::fill_blinding_rows`]: super::TableWitnessIndex::fill_blinding_rows
+	pub fn add_blinding_rows(&mut self, n: usize) {
+		self.table.blinding_rows += n;
+	}
+
 	/// Returns a new `TableBuilder` with the specified namespace.
 	///
 	/// A namespace is a prefix that will be prepended to all column names and zero constraints
@@ -113,6 +138,68 @@ impl<'a, F: TowerField> TableBuilder<'a, F> {
 		self.table.id()
 	}
 
+	/// Returns the maximum degree of the zero constraints added so far.
+	///
+	/// See [`Table::max_constraint_degree`].
+	pub fn max_constraint_degree(&self) -> usize {
+		self.table.max_constraint_degree()
+	}
+
+	/// Returns the circuit of the zero constraint with the given name, if it exists.
+	///
+	/// See [`Table::constraint_circuit`].
+	pub fn constraint_circuit(&self, name: &str) -> Option<&ArithCircuit<F>> {
+		self.table.constraint_circuit(name)
+	}
+
+	/// Sets the metadata value of a column for `key`, replacing any previous value.
+	///
+	/// The metadata is a set of arbitrary string key/value pairs for external tooling, e.g. a
+	/// semantic tag such as `("role", "opcode")`. It does not affect the constraint system.
+	pub fn set_column_metadata<FSub: TowerField, const V: usize>(
+		&mut self,
+		col: Col<FSub, V>,
+		key: impl ToString,
+		value: impl ToString,
+	) {
+		assert_eq!(col.table_id, self.id());
+		self.table.columns[col.table_index.0]
+			.metadata
+			.insert(key.to_string(), value.to_string());
+	}
+
+	/// Returns the metadata of a column.
+	///
+	/// See [`Table::column_metadata`].
+	pub fn column_metadata<FSub: TowerField, const V: usize>(
+		&self,
+		col: Col<FSub, V>,
+	) -> &BTreeMap<String, String> {
+		self.table.column_metadata(col)
+	}
+
+	/// Adds a committed column with `VALUES_PER_ROW` values of `FSub` in each row.
+	///
+	/// The field and the number of values per row are chosen per column, so a table can mix, e.g.,
+	/// wide bit columns with narrow columns of a large field. Columns with the same number of
+	/// values per row form a partition of the table, and zero constraints relate the columns of
+	/// one partition.
+	///
+	/// The number of values per row must be a power of two, which is checked at compile time:
+	///
+	/// ```
+	/// # use binius_m3::builder::{B32, B128, ConstraintSystem};
+	/// let mut cs = ConstraintSystem::<B128>::new();
+	/// let mut table = cs.add_table("table");
+	/// let col = table.add_committed::<B32, 4>("col");
+	/// ```
+	///
+	/// ```compile_fail
+	/// # use binius_m3::builder::{B32, B128, ConstraintSystem};
+	/// let mut cs = ConstraintSystem::<B128>::new();
+	/// let mut table = cs.add_table("table");
+	/// let col = table.add_committed::<B32, 3>("col");
+	/// ```
 	pub fn add_committed<FSub, const VALUES_PER_ROW: usize>(
 		&mut self,
 		name: impl ToString,
@@ -129,6 +216,27 @@ impl<'a, F: TowerField> TableBuilder<'a, F> {
 		)
 	}
 
+	/// Adds a committed column that is written in the given phase of the witness fill.
+	///
+	/// The other columns are in phase `0`. The columns of a later phase may depend on challenges
+	/// that are only sampled once the columns of the earlier phases are committed, and are filled
+	/// with [`WitnessIndex::fill_table_phase`](super::WitnessIndex::fill_table_phase). The phases
+	/// only order the witness fill: the committed columns of all the phases compile to the same
+	/// kind of oracle.
+	pub fn add_committed_phase<FSub, const VALUES_PER_ROW: usize>(
+		&mut self,
+		name: impl ToString,
+		phase: usize,
+	) -> Col<FSub, VALUES_PER_ROW>
+	where
+		FSub: TowerField,
+		F: ExtensionField<FSub>,
+	{
+		let col = self.add_committed(name);
+		self.table.columns[col.table_index.0].phase = phase;
+		col
+	}
+
 	pub fn add_committed_multiple<FSub, const VALUES_PER_ROW: usize, const N: usize>(
 		&mut self,
 		name: impl ToString,
@@ -165,6 +273,46 @@ impl<'a, F: TowerField> TableBuilder<'a, F> {
 		)
 	}
 
+	/// Adds a column that shifts `col` by `offset` values across the rows of the table.
+	///
+	/// Unlike [`Self::add_shifted`], which shifts the values within blocks of a single row, this
+	/// shifts the column as one sequence of values in row-major order, so the shifted column
+	/// relates values of different rows. With [`ShiftVariant::LogicalLeft`], the value at index
+	/// `i` of the shifted column is the value at index `i - offset` of `col`, and the first
+	/// `offset` values are zero.
+	///
+	/// The shift spans the whole column, whose size is only static if the table size is fixed.
+	///
+	/// ## Pre-conditions
+	///
+	/// * The table size must have been fixed with [`Self::require_fixed_size`].
+	pub fn add_table_shifted<FSub, const VALUES_PER_ROW: usize>(
+		&mut self,
+		name: impl ToString,
+		col: Col<FSub, VALUES_PER_ROW>,
+		offset: usize,
+		variant: ShiftVariant,
+	) -> Col<FSub, VALUES_PER_ROW>
+	where
+		FSub: TowerField,
+		F: ExtensionField<FSub>,
+	{
+		let TableSizeSpec::Fixed { log_size } = self.table.table_size_spec else {
+			panic!("shifts across rows may only be added to tables with a fixed size");
+		};
+		let log_block_size = log_size + log2_strict_usize(VALUES_PER_ROW);
+		assert!(offset <= 1 << log_block_size);
+		self.table.new_column(
+			self.namespaced_name(name),
+			ColumnDef::Shifted {
+				col: col.id(),
+				offset,
+				log_block_size,
+				variant,
+			},
+		)
+	}
+
 	pub fn add_packed<FSubSub, const VALUES_PER_ROW_SUB: usize, FSub, const VALUES_PER_ROW: usize>(
 		&mut self,
 		name: impl ToString,
@@ -504,12 +652,37 @@ impl<'a, F: TowerField> TableBuilder<'a, F> {
 	/// Add a structured column to a table.
 	///
 	/// A structured column is one that has sufficient structure that its multilinear extension
-	/// can be evaluated succinctly. See [`StructuredDynSize`] for more information.
+	/// can be evaluated succinctly. See [`StructuredDynSize`](super::StructuredDynSize) for the
+	/// built-in structured columns and [`Structured`] for defining custom ones.
+	///
+	/// ## Panics
+	///
+	/// * If the values of the column do not fit in `FSub`, see [`Self::try_add_structured`].
 	pub fn add_structured<FSub>(
 		&mut self,
 		name: impl ToString,
-		variant: StructuredDynSize,
+		variant: impl Structured<F> + 'static,
 	) -> Col<FSub>
+	where
+		FSub: TowerField,
+		F: ExtensionField<FSub>,
+	{
+		self.try_add_structured(name, variant)
+			.unwrap_or_else(|err| panic!("invalid structured column: {err}"))
+	}
+
+	/// Add a structured dynamic-size column to a table, checking that its values fit in `FSub`.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::Structured`] if the variant rejects the bit width of `FSub`, see
+	///   [`Structured::check_field_bits`]. For example, an incrementing column with `max_size_log`
+	///   32 does not fit in a 16-bit field.
+	pub fn try_add_structured<FSub>(
+		&mut self,
+		name: impl ToString,
+		variant: impl Structured<F> + 'static,
+	) -> Result<Col<FSub>, Error>
 	where
 		FSub: TowerField,
 		F: ExtensionField<FSub>,
@@ -518,17 +691,78 @@ impl<'a, F: TowerField> TableBuilder<'a, F> {
 			self.table.requires_any_po2_size(),
 			"Structured dynamic size columns may only be added to tables that are power of two sized"
 		);
+		variant.check_field_bits(FSub::N_BITS)?;
 		let namespaced_name = self.namespaced_name(name);
-		self.table
-			.new_column(namespaced_name, ColumnDef::StructuredDynSize(variant))
+		Ok(self
+			.table
+			.new_column(namespaced_name, ColumnDef::StructuredDynSize(Arc::new(variant))))
+	}
+
+	/// Add an incrementing column as wide as `FSub`.
+	///
+	/// This is [`StructuredDynSize::Incrementing`] with `max_size_log` set to the bit width of
+	/// `FSub`, e.g. a column of all the 256 values of [`B8`](super::B8).
+	pub fn add_incrementing<FSub>(&mut self, name: impl ToString) -> Col<FSub>
+	where
+		FSub: TowerField,
+		F: ExtensionField<FSub>,
+	{
+		self.add_structured(
+			name,
+			StructuredDynSize::Incrementing {
+				max_size_log: FSub::N_BITS,
+			},
+		)
+	}
+
+	/// Add a big-endian incrementing column as wide as `FSub`.
+	///
+	/// This is [`StructuredDynSize::IncrementingBigEndian`] with `max_size_log` set to the bit
+	/// width of `FSub`.
+	pub fn add_incrementing_big_endian<FSub>(&mut self, name: impl ToString) -> Col<FSub>
+	where
+		FSub: TowerField,
+		F: ExtensionField<FSub>,
+	{
+		self.add_structured(
+			name,
+			StructuredDynSize::IncrementingBigEndian {
+				max_size_log: FSub::N_BITS,
+			},
+		)
+	}
+
+	/// Add a column of the complements of the row indices as wide as `FSub`.
+	///
+	/// This is [`StructuredDynSize::Complement`] with `max_size_log` set to the bit width of
+	/// `FSub`, so the values count down from the largest element of `FSub`.
+	pub fn add_complement<FSub>(&mut self, name: impl ToString) -> Col<FSub>
+	where
+		FSub: TowerField,
+		F: ExtensionField<FSub>,
+	{
+		self.add_structured(
+			name,
+			StructuredDynSize::Complement {
+				max_size_log: FSub::N_BITS,
+			},
+		)
 	}
 
 	/// Add a structured fixed-size column to a table.
-	pub fn add_fixed<FSub>(&mut self, name: impl ToString, expr: ArithCircuit<F>) -> Col<FSub>
+	///
+	/// The expression may be passed as an `Arc<ArithCircuit<F>>`, so that columns of several
+	/// tables with the same layout share one lowered circuit instead of each owning a copy.
+	pub fn add_fixed<FSub>(
+		&mut self,
+		name: impl ToString,
+		expr: impl Into<Arc<ArithCircuit<F>>>,
+	) -> Col<FSub>
 	where
 		FSub: TowerField,
 		F: ExtensionField<FSub>,
 	{
+		let expr = expr.into();
 		assert!(
 			matches!(self.table.table_size_spec, TableSizeSpec::Fixed { log_size } if log_size == expr.n_vars()),
 			"Structured fixed-size columns may only be added to tables with a fixed log_size that matches the n_vars of the expression"
@@ -544,17 +778,57 @@ impl<'a, F: TowerField> TableBuilder<'a, F> {
 	/// The zero constraint applies to all values stacked vertically within the column cells. That
 	/// means that the expression is evaluated independently `V` times per row, and each evaluation
 	/// in the stack must be zero.
+	///
+	/// ## Panics
+	///
+	/// * If the expression is over the columns of another table, which may have a different size.
+	///   The columns of an expression all belong to one table, which the arithmetic operators on
+	///   columns check.
 	pub fn assert_zero<FSub, const V: usize>(&mut self, name: impl ToString, expr: Expr<FSub, V>)
 	where
 		FSub: TowerField,
 		F: ExtensionField<FSub>,
 	{
 		let namespaced_name = self.namespaced_name(name);
+		assert_eq!(
+			expr.table_id(),
+			self.id(),
+			"zero constraint {namespaced_name:?} of table {:?} is over the columns of table {}",
+			self.table.name,
+			expr.table_id(),
+		);
 		self.table
 			.partition_mut(V)
 			.assert_zero(namespaced_name, expr)
 	}
 
+	/// Constrains that two expressions are equal on every row.
+	///
+	/// This is [`Self::assert_zero`] of `lhs - rhs`.
+	pub fn assert_eq<FSub, const V: usize>(
+		&mut self,
+		name: impl ToString,
+		lhs: impl Into<Expr<FSub, V>>,
+		rhs: impl Into<Expr<FSub, V>>,
+	) where
+		FSub: TowerField,
+		F: ExtensionField<FSub>,
+	{
+		self.assert_zero(name, lhs.into() - rhs.into())
+	}
+
+	/// Constrains that the expression evaluates to `0` or `1` on every row.
+	///
+	/// The constraint is $x (x - 1) = 0$, which in characteristic 2 is $x^2 + x = 0$. It has
+	/// degree 2 in the expression.
+	pub fn assert_boolean<FSub, const V: usize>(&mut self, name: impl ToString, expr: Expr<FSub, V>)
+	where
+		FSub: TowerField,
+		F: ExtensionField<FSub>,
+	{
+		self.assert_zero(name, expr.clone() * expr.clone() - expr)
+	}
+
 	/// Constrains that all values contained in this column are non-zero.
 	pub fn assert_nonzero<FSub, const V: usize>(&mut self, expr: Col<FSub, V>)
 	where
@@ -669,6 +943,36 @@ impl<'a, F: TowerField> TableBuilder<'a, F> {
 	}
 }
 
+/// A struct of the columns of a table, added to the table together.
+///
+/// This is usually derived with `#[derive(TableSchema)]`. The derived implementation adds a
+/// committed column for every field, named after the field. A field with a `#[structured(...)]`
+/// attribute is instead a structured column, and the attribute holds the
+/// [`StructuredDynSize`](super::StructuredDynSize) variant. The column types are those of the
+/// fields, and every field gets an accessor method of the same name returning its column.
+///
+/// ```
+/// use binius_m3::builder::{B1, B32, Col, ConstraintSystem, TableSchema};
+///
+/// #[derive(TableSchema)]
+/// struct Cols {
+///     #[structured(Incrementing { max_size_log: 20 })]
+///     index: Col<B32>,
+///     value: Col<B32>,
+///     bits: Col<B1, 32>,
+/// }
+///
+/// let mut cs = ConstraintSystem::new();
+/// let mut table = cs.add_table("table");
+/// table.require_power_of_two_size();
+/// let cols = Cols::add_columns(&mut table);
+/// table.assert_eq("value = index", cols.value(), cols.index());
+/// ```
+pub trait TableSchema: Sized {
+	/// Adds the columns to the table.
+	fn add_columns(table: &mut TableBuilder) -> Self;
+}
+
 /// A table in an M3 constraint system.
 ///
 /// ## Invariants
@@ -677,13 +981,15 @@ impl<'a, F: TowerField> TableBuilder<'a, F> {
 ///   number of table columns (the length of `column_info`).
 /// * All flushes in `flushes` contain column indices less than the number of table columns (the
 ///   length of `column_info`).
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Table<F: TowerField = B128> {
 	pub id: TableId,
 	pub name: String,
 	pub columns: Vec<ColumnInfo<F>>,
 	/// the size specification of a table
 	table_size_spec: TableSizeSpec,
+	/// the number of blinding rows at the end of the table
+	blinding_rows: usize,
 	pub(super) partitions: SparseIndex<TablePartition<F>>,
 }
 
@@ -691,7 +997,7 @@ pub struct Table<F: TowerField = B128> {
 /// as height) Tower level does not need to be the same.
 ///
 /// Zerocheck constraints can only be defined within table partitions.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(super) struct TablePartition<F: TowerField = B128> {
 	pub table_id: TableId,
 	pub values_per_row: usize,
@@ -765,6 +1071,7 @@ impl<F: TowerField> Table<F> {
 			name: name.to_string(),
 			columns: Vec::new(),
 			table_size_spec: TableSizeSpec::Arbitrary,
+			blinding_rows: 0,
 			partitions: SparseIndex::new(),
 		}
 	}
@@ -782,6 +1089,10 @@ impl<F: TowerField> Table<F> {
 		FSub: TowerField,
 		F: ExtensionField<FSub>,
 	{
+		const {
+			assert!(V.is_power_of_two(), "the number of values per row must be a power of two");
+		}
+
 		let table_id = self.id;
 		let table_index = ColumnIndex(self.columns.len());
 		let partition = self.partition_mut(V);
@@ -798,6 +1109,8 @@ impl<F: TowerField> Table<F> {
 				log_values_per_row: log2_strict_usize(V),
 			},
 			is_nonzero: false,
+			metadata: BTreeMap::new(),
+			phase: 0,
 		};
 
 		let partition_index = ColumnPartitionIndex(partition.columns.len());
@@ -817,6 +1130,23 @@ impl<F: TowerField> Table<F> {
 		matches!(self.table_size_spec, TableSizeSpec::PowerOfTwo)
 	}
 
+	/// Returns the number of blinding rows reserved at the end of this table.
+	pub fn blinding_rows(&self) -> usize {
+		self.blinding_rows
+	}
+
+	/// Returns the number of phases of the witness fill of this table.
+	///
+	/// This is one more than the largest phase of a column, see
+	/// [`TableBuilder::add_committed_phase`].
+	pub fn n_phases(&self) -> usize {
+		self.columns
+			.iter()
+			.map(|info| info.phase + 1)
+			.max()
+			.unwrap_or(1)
+	}
+
 	/// Returns the size constraint of this table.
 	pub(crate) fn size_spec(&self) -> TableSizeSpec {
 		self.table_size_spec
@@ -825,6 +1155,177 @@ impl<F: TowerField> Table<F> {
 	pub fn stat(&self) -> TableStat {
 		TableStat::new(self)
 	}
+
+	/// Checks that the names of the zero constraints in this table are unique.
+	///
+	/// The names identify constraints in diagnostics, such as
+	/// [`WitnessIndex::zero_constraint_failures`](super::WitnessIndex::zero_constraint_failures),
+	/// so they are ambiguous if reused.
+	pub fn check_constraint_names(&self) -> Result<(), Error> {
+		let mut names = HashSet::new();
+		for constraint in self
+			.partitions
+			.values()
+			.flat_map(|partition| &partition.zero_constraints)
+		{
+			if !names.insert(constraint.name.as_str()) {
+				return Err(Error::DuplicateConstraintName {
+					table_id: self.id,
+					name: constraint.name.clone(),
+				});
+			}
+		}
+		Ok(())
+	}
+
+	/// Returns the maximum degree of the zero constraints in this table, or zero if there are
+	/// none.
+	///
+	/// The degree is taken over the table columns, so the expressions defining structured, fixed,
+	/// or computed columns do not contribute to it.
+	pub fn max_constraint_degree(&self) -> usize {
+		self.partitions
+			.values()
+			.flat_map(|partition| &partition.zero_constraints)
+			.map(|constraint| constraint.expr.degree())
+			.max()
+			.unwrap_or(0)
+	}
+
+	/// Substitutes the structured columns that are constant into the zero constraints of this
+	/// table.
+	///
+	/// A structured column whose multilinear extension folds to a constant has that value on
+	/// every row, so a constraint may refer to the constant instead of the column. The other
+	/// structured columns are left in place, as their closed forms are over the variables of the
+	/// row index, which the constraints cannot refer to. A constraint is replaced by its
+	/// substituted and optimized form only if that lowers its degree or its number of steps.
+	///
+	/// Returns the number of replaced constraints.
+	pub fn simplify_structured_constraints(&mut self) -> Result<usize, Error> {
+		let constants = self
+			.columns
+			.iter()
+			.map(|info| {
+				let circuit = match &info.col {
+					ColumnDef::StructuredDynSize(structured) => ArithCircuit::from(
+						&structured
+							.expr()
+							.map_err(|err| err.for_column(&info.name))?,
+					),
+					ColumnDef::StructuredFixedSize { expr } => ArithCircuit::clone(expr),
+					_ => return Ok(None),
+				};
+				Ok(circuit.optimize().get_constant())
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		let mut n_replaced = 0;
+		for partition in self.partitions.values_mut() {
+			for constraint in &mut partition.zero_constraints {
+				let vars_usage = constraint.expr.vars_usage();
+				let substitutions = partition
+					.columns
+					.iter()
+					.zip(vars_usage)
+					.enumerate()
+					.filter_map(|(var, (col, used))| {
+						constants[col.table_index.0]
+							.filter(|_| used)
+							.map(|value| (var, value))
+					})
+					.collect::<Vec<_>>();
+				if substitutions.is_empty() {
+					continue;
+				}
+				let simplified = substitutions
+					.into_iter()
+					.fold(constraint.expr.clone(), |expr, (var, value)| {
+						expr.const_subst(var, value)
+					})
+					.optimize();
+				if simplified.degree() < constraint.expr.degree()
+					|| simplified.steps().len() < constraint.expr.steps().len()
+				{
+					constraint.expr = simplified;
+					n_replaced += 1;
+				}
+			}
+		}
+		Ok(n_replaced)
+	}
+
+	/// Returns the circuit of the zero constraint with the given name, if it exists.
+	///
+	/// The name is the full name of the constraint, including the namespaces of the builder it was
+	/// added with. Variable `i` of the circuit refers to the `i`-th column of the table partition
+	/// of the constraint, i.e. of the columns with the same number of values per row in the order
+	/// they were added.
+	pub fn constraint_circuit(&self, name: &str) -> Option<&ArithCircuit<F>> {
+		self.partitions
+			.values()
+			.flat_map(|partition| &partition.zero_constraints)
+			.find(|constraint| constraint.name == name)
+			.map(|constraint| &constraint.expr)
+	}
+
+	/// Evaluates every zero constraint at random assignments of its columns.
+	///
+	/// Each constraint is evaluated at `n_samples` assignments, with every column set to a value
+	/// drawn uniformly from the field of the column, independently of the other constraints and
+	/// of any structured or derived definition of the column. The report counts the assignments
+	/// satisfying each constraint. This is a quick check before filling a witness: a constraint
+	/// that is never satisfied is likely contradictory, and one that is always satisfied is
+	/// likely vacuous. A constraint that is hard to satisfy by chance, e.g. `a * b - c`, is
+	/// usually satisfied by no sample.
+	pub fn sample_satisfaction(&self, n_samples: usize, seed: u64) -> SampleReport {
+		let mut rng = StdRng::seed_from_u64(seed);
+		let constraints = self
+			.partitions
+			.values()
+			.flat_map(|partition| {
+				partition
+					.zero_constraints
+					.iter()
+					.map(move |constraint| (partition, constraint))
+			})
+			.map(|(partition, constraint)| {
+				let n_satisfied = (0..n_samples)
+					.filter(|_| {
+						let query = partition
+							.columns
+							.iter()
+							.map(|&col| {
+								random_tower_element(&mut rng, self[col].shape.tower_height)
+							})
+							.collect::<Vec<F>>();
+						constraint
+							.expr
+							.evaluate(&query)
+							.expect("the query has a value for every partition column")
+							== F::ZERO
+					})
+					.count();
+				ConstraintSamples {
+					name: constraint.name.clone(),
+					n_satisfied,
+				}
+			})
+			.collect();
+		SampleReport {
+			n_samples,
+			constraints,
+		}
+	}
+
+	/// Returns the metadata of a column, set with [`TableBuilder::set_column_metadata`].
+	pub fn column_metadata<FSub: TowerField, const V: usize>(
+		&self,
+		col: Col<FSub, V>,
+	) -> &BTreeMap<String, String> {
+		assert_eq!(col.table_id, self.id);
+		&self.columns[col.table_index.0].metadata
+	}
 }
 
 impl<F: TowerField> Index<ColumnIndex> for Table<F> {
@@ -844,6 +1345,41 @@ impl<F: TowerField> Index<ColumnId> for Table<F> {
 	}
 }
 
+/// The result of [`Table::sample_satisfaction`].
+#[derive(Debug, Clone)]
+pub struct SampleReport {
+	/// The number of random assignments each constraint was evaluated at.
+	pub n_samples: usize,
+	/// The results for each zero constraint of the table.
+	pub constraints: Vec<ConstraintSamples>,
+}
+
+impl SampleReport {
+	/// Returns the fraction of the samples that satisfy the constraint with the given name.
+	pub fn satisfied_fraction(&self, name: &str) -> Option<f64> {
+		self.constraints
+			.iter()
+			.find(|constraint| constraint.name == name)
+			.map(|constraint| constraint.n_satisfied as f64 / self.n_samples as f64)
+	}
+}
+
+/// The sampled satisfaction of one zero constraint.
+#[derive(Debug, Clone)]
+pub struct ConstraintSamples {
+	pub name: String,
+	/// The number of random assignments that satisfy the constraint.
+	pub n_satisfied: usize,
+}
+
+/// Returns a random element of the subfield of `F` with the given tower height.
+fn random_tower_element<F: TowerField>(rng: &mut impl Rng, tower_height: usize) -> F {
+	(0..1 << tower_height)
+		.filter(|_| rng.random())
+		.map(|i| <F as TowerField>::basis(0, i).expect("the tower height is at most that of F"))
+		.sum()
+}
+
 const fn partition_id<const V: usize>() -> usize {
 	checked_log_2(V)
 }
@@ -860,13 +1396,20 @@ pub fn log_capacity(table_size: usize) -> usize {
 
 #[cfg(test)]
 mod tests {
+	use assert_matches::assert_matches;
 	use binius_compute::cpu::alloc::CpuComputeAllocator;
-	use binius_field::{arch::OptimalUnderlier, as_packed_field::PackedType};
+	use binius_field::{
+		arch::OptimalUnderlier, as_packed_field::PackedType, packed::set_packed_slice,
+	};
 
-	use super::{B128, Table, TableBuilder};
+	use super::{Arc, ArithCircuit, B128, ColumnDef, Table, TableBuilder};
 	use crate::builder::{
-		B8, ConstraintSystem, FlushOpts, WitnessIndex,
-		test_utils::validate_system_witness_with_prove_verify,
+		B1, B8, B16, B32, ConstraintSystem, Error, FlushOpts, StructuredDynSize, WitnessIndex,
+		structured::incrementing_expr,
+		test_utils::{
+			ClosureFiller, ConstantStructured, validate_system_witness,
+			validate_system_witness_with_prove_verify,
+		},
 	};
 
 	#[test]
@@ -878,6 +1421,387 @@ mod tests {
 		assert_eq!(tb_ns_2.namespaced_name("column"), "ns1::ns2::column");
 	}
 
+	#[test]
+	#[should_panic(expected = "is over the columns of table 1")]
+	fn test_assert_zero_rejects_other_table() {
+		let mut cs = ConstraintSystem::<B128>::new();
+		let mut table = cs.add_table("small");
+		table.require_fixed_size(4);
+		table.add_committed::<B32, 1>("committed");
+		drop(table);
+		let mut table = cs.add_table("large");
+		table.require_power_of_two_size();
+		let structured = table.add_structured::<B32>(
+			"structured",
+			StructuredDynSize::Incrementing { max_size_log: 8 },
+		);
+		drop(table);
+
+		// A constraint in the fixed-size table over the structured column of the other table.
+		let mut table = TableBuilder::new(&mut cs.tables[0]);
+		table.assert_zero("structured = 0", structured.into());
+	}
+
+	#[test]
+	#[should_panic(expected = "cannot combine the columns of different tables")]
+	fn test_expr_rejects_columns_of_different_tables() {
+		let mut cs = ConstraintSystem::<B128>::new();
+		let mut table = cs.add_table("small");
+		table.require_fixed_size(4);
+		let committed = table.add_committed::<B32, 1>("committed");
+		drop(table);
+		let mut table = cs.add_table("large");
+		table.require_power_of_two_size();
+		let structured = table.add_structured::<B32>(
+			"structured",
+			StructuredDynSize::Incrementing { max_size_log: 8 },
+		);
+		table.assert_zero("committed = structured", committed - structured);
+	}
+
+	#[test]
+	fn test_simplify_structured_constraints() {
+		let scale = B32::new(0x1234_5678);
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("scaled");
+		table.require_power_of_two_size();
+		let a = table.add_committed::<B32, 1>("a");
+		let b = table.add_committed::<B32, 1>("b");
+		let c = table.add_committed::<B32, 1>("c");
+		let constant = table.add_structured::<B32>(
+			"scale",
+			ConstantStructured {
+				value: B128::from(scale),
+				max_size_log: 8,
+			},
+		);
+		let incrementing = table.add_structured::<B32>(
+			"incrementing",
+			StructuredDynSize::Incrementing { max_size_log: 8 },
+		);
+		table.assert_zero("scaled", a * constant - b);
+		table.assert_zero("indexed", a * incrementing - c);
+		table.assert_zero("linear", a * scale - b);
+		let table_id = table.id();
+		drop(table);
+
+		assert_eq!(cs.simplify_structured_constraints().unwrap(), 1);
+		let table = &cs.tables[table_id];
+		// The product with the constant column collapses to a scaling of `a`.
+		let scaled = table.constraint_circuit("scaled").unwrap();
+		assert_eq!(scaled.degree(), 1);
+		assert_eq!(scaled.vars_usage(), [true, true]);
+		assert_eq!(table.constraint_circuit("indexed").unwrap().degree(), 2);
+		// There is nothing left to simplify.
+		assert_eq!(cs.simplify_structured_constraints().unwrap(), 0);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness = WitnessIndex::<PackedType<OptimalUnderlier, B128>>::new(&cs, &allocator);
+		let rows = (0..1 << 6).collect::<Vec<u32>>();
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |rows: &[u32], index| {
+					let mut a_vals = index.get_scalars_mut(a)?;
+					let mut b_vals = index.get_scalars_mut(b)?;
+					let mut c_vals = index.get_scalars_mut(c)?;
+					let mut constant_vals = index.get_scalars_mut(constant)?;
+					let mut incrementing_vals = index.get_scalars_mut(incrementing)?;
+					for (i, &row) in rows.iter().enumerate() {
+						let value = B32::new(3 * row + 1);
+						a_vals[i] = value;
+						b_vals[i] = value * scale;
+						c_vals[i] = value * B32::new(row);
+						constant_vals[i] = scale;
+						incrementing_vals[i] = B32::new(row);
+					}
+					Ok(())
+				}),
+				&rows,
+			)
+			.unwrap();
+		validate_system_witness::<OptimalUnderlier>(&cs, witness, vec![]);
+	}
+
+	#[test]
+	fn test_sample_satisfaction() {
+		let mut table = Table::<B128>::new(0, "table");
+		let mut tb = TableBuilder::new(&mut table);
+		let a = tb.add_committed::<B32, 1>("a");
+		let b = tb.add_committed::<B32, 1>("b");
+		let bit = tb.add_committed::<B1, 1>("bit");
+		tb.assert_zero("tautology", (a + b) * (a + b) - a * a - b * b);
+		tb.assert_zero("contradiction", a - a + B32::new(1));
+		tb.assert_zero("boolean", bit * bit - bit);
+		tb.assert_zero("zero", a.into());
+
+		let report = table.sample_satisfaction(64, 0);
+		assert_eq!(report.n_samples, 64);
+		assert_eq!(report.satisfied_fraction("tautology"), Some(1.0));
+		assert_eq!(report.satisfied_fraction("contradiction"), Some(0.0));
+		// The B1 column is sampled in B1, where the boolean constraint always holds.
+		assert_eq!(report.satisfied_fraction("boolean"), Some(1.0));
+		assert!(report.satisfied_fraction("zero").unwrap() < 0.1);
+		assert_eq!(report.satisfied_fraction("missing"), None);
+	}
+
+	#[test]
+	fn test_max_constraint_degree() {
+		let mut cs = ConstraintSystem::<B128>::new();
+		let mut table = cs.add_table("mixed_degrees");
+		assert_eq!(table.max_constraint_degree(), 0);
+
+		let a = table.add_committed::<B8, 1>("a");
+		let b = table.add_committed::<B8, 1>("b");
+		let c = table.add_committed::<B8, 1>("c");
+		table.assert_zero("linear", a + b - c);
+		assert_eq!(table.max_constraint_degree(), 1);
+		table.assert_zero("quadratic", a * b - c);
+		assert_eq!(table.max_constraint_degree(), 2);
+
+		let table_id = table.id();
+		drop(table);
+		assert_eq!(cs.tables[table_id].max_constraint_degree(), 2);
+	}
+
+	#[test]
+	fn test_constraint_circuit() {
+		let mut cs = ConstraintSystem::<B128>::new();
+		let mut table = cs.add_table("and");
+		let a = table.add_committed::<B1, 1>("a");
+		let b = table.add_committed::<B1, 1>("b");
+		let c = table.add_committed::<B1, 1>("c");
+		table.assert_zero("c = a & b", c - a * b);
+		let mut ns = table.with_namespace("ns");
+		ns.assert_zero("a = b", a - b);
+
+		assert!(table.constraint_circuit("a = b").is_none());
+		assert!(table.constraint_circuit("ns::a = b").is_some());
+		assert!(table.constraint_circuit("missing").is_none());
+
+		let circuit = table.constraint_circuit("c = a & b").unwrap();
+		// The variables are the columns a, b and c of the partition.
+		for (a, b, c) in [(0, 0, 0), (0, 1, 0), (1, 0, 0), (1, 1, 1)] {
+			let query = [a, b, c].map(|bit| B128::from(bit as u128));
+			assert_eq!(circuit.evaluate(&query).unwrap(), B128::from(0));
+		}
+		let query = [1, 1, 0].map(|bit| B128::from(bit as u128));
+		assert_ne!(circuit.evaluate(&query).unwrap(), B128::from(0));
+	}
+
+	#[test]
+	fn test_column_metadata() {
+		let mut cs = ConstraintSystem::<B128>::new();
+		let mut table = cs.add_table("metadata");
+		table.require_power_of_two_size();
+		let incrementing = table.add_structured::<B32>(
+			"incrementing",
+			crate::builder::StructuredDynSize::Incrementing { max_size_log: 8 },
+		);
+		let flag = table.add_committed::<B1, 1>("flag");
+		table.set_column_metadata(incrementing, "role", "address");
+		table.set_column_metadata(incrementing, "unit", "bytes");
+		table.set_column_metadata(incrementing, "role", "index");
+		table.set_column_metadata(flag, "role", "flag");
+		let table_id = table.id();
+		drop(table);
+
+		let table = &cs.tables[table_id];
+		assert_eq!(
+			table
+				.column_metadata(incrementing)
+				.iter()
+				.collect::<Vec<_>>(),
+			[
+				(&"role".to_string(), &"index".to_string()),
+				(&"unit".to_string(), &"bytes".to_string())
+			]
+		);
+		assert_eq!(table.column_metadata(flag)["role"], "flag");
+	}
+
+	#[test]
+	fn test_duplicate_constraint_names() {
+		let mut cs = ConstraintSystem::<B128>::new();
+		let mut table = cs.add_table("duplicates");
+		let table_id = table.id();
+		let a = table.add_committed::<B8, 1>("a");
+		let b = table.add_committed::<B8, 1>("b");
+		table.assert_zero("a = b", a - b);
+		// The same name in a different namespace is distinct.
+		table.with_namespace("ns").assert_zero("a = b", a - b);
+		assert!(table.table.check_constraint_names().is_ok());
+
+		table.assert_zero("a = b", b - a);
+		drop(table);
+
+		assert_matches!(
+			cs.tables[table_id].check_constraint_names(),
+			Err(Error::DuplicateConstraintName { name, .. }) if name == "a = b"
+		);
+		assert_matches!(cs.compile(), Err(Error::DuplicateConstraintName { .. }));
+	}
+
+	#[test]
+	fn test_assert_boolean() {
+		for (values, is_valid) in [([0, 1, 1, 0], true), ([0, 1, 2, 0], false)] {
+			let mut cs = ConstraintSystem::<B128>::new();
+			let mut table = cs.add_table("boolean");
+			let table_id = table.id();
+			let col = table.add_committed::<B16, 1>("col");
+			table.assert_boolean("col is boolean", col.into());
+			drop(table);
+
+			let mut allocator = CpuComputeAllocator::new(1 << 12);
+			let allocator = allocator.into_bump_allocator();
+			let mut witness =
+				WitnessIndex::<PackedType<OptimalUnderlier, B128>>::new(&cs, &allocator);
+			witness
+				.fill_table_sequential(
+					&ClosureFiller::new(table_id, |events: &[u16], index| {
+						let mut col = index.get_scalars_mut(col)?;
+						for (c, &v) in col.iter_mut().zip(events) {
+							*c = B16::new(v);
+						}
+						Ok(())
+					}),
+					&values,
+				)
+				.unwrap();
+
+			let failures = witness.zero_constraint_failures().unwrap();
+			if is_valid {
+				assert!(failures.is_empty());
+				validate_system_witness::<OptimalUnderlier>(&cs, witness, vec![]);
+			} else {
+				let [failure] = failures.as_slice() else {
+					panic!("expected a single failure, got {failures:?}");
+				};
+				assert_eq!(failure.constraint_name, "col is boolean");
+				assert_eq!(failure.row, 2);
+			}
+		}
+	}
+
+	#[test]
+	fn test_assert_eq_matches_assert_zero() {
+		let mut table = Table::<B128>::new(0, "table");
+		let mut tb = TableBuilder::new(&mut table);
+		let a = tb.add_committed::<B8, 1>("a");
+		let b = tb.add_committed::<B8, 1>("b");
+		tb.assert_eq("a = b", a, b);
+		tb.assert_zero("a - b = 0", a - b);
+
+		let [eq, zero] = table.partitions[0].zero_constraints.as_slice() else {
+			panic!("expected two zero constraints");
+		};
+		assert_eq!(eq.expr, zero.expr);
+		assert_eq!(eq.tower_level, zero.tower_level);
+	}
+
+	#[test]
+	fn test_mixed_values_per_row() {
+		const N_ROWS: usize = 8;
+
+		let mut cs = ConstraintSystem::<B128>::new();
+		let mut table = cs.add_table("mixed");
+		let table_id = table.id();
+		let a = table.add_committed::<B1, 128>("a");
+		let b = table.add_committed::<B1, 128>("b");
+		let a_and_b = table.add_committed::<B1, 128>("a_and_b");
+		table.assert_zero("a_and_b = a * b", a_and_b - a * b);
+		let x = table.add_committed::<B32, 4>("x");
+		let x_squared = table.add_committed::<B32, 4>("x_squared");
+		table.assert_zero("x_squared = x * x", x_squared - x * x);
+		drop(table);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness = WitnessIndex::<PackedType<OptimalUnderlier, B128>>::new(&cs, &allocator);
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |events: &[u32], index| {
+					// Bit columns are not indexable as scalars, so they are set in the packed
+					// slices.
+					let mut a = index.get_mut(a)?;
+					let mut b = index.get_mut(b)?;
+					let mut a_and_b = index.get_mut(a_and_b)?;
+					for (i, &event) in events.iter().enumerate() {
+						for j in 0..128 {
+							let k = 128 * i + j;
+							let a_k = B1::from((event as usize + j) % 3 == 0);
+							let b_k = B1::from((event as usize * j) % 2 == 0);
+							set_packed_slice(&mut a, k, a_k);
+							set_packed_slice(&mut b, k, b_k);
+							set_packed_slice(&mut a_and_b, k, a_k * b_k);
+						}
+					}
+
+					let mut x = index.get_scalars_mut(x)?;
+					let mut x_squared = index.get_scalars_mut(x_squared)?;
+					for (i, &event) in events.iter().enumerate() {
+						for j in 0..4 {
+							let k = 4 * i + j;
+							x[k] = B32::new(event * 4 + j as u32 + 1);
+							x_squared[k] = x[k] * x[k];
+						}
+					}
+					Ok(())
+				}),
+				&(0..N_ROWS as u32).collect::<Vec<_>>(),
+			)
+			.unwrap();
+
+		validate_system_witness::<OptimalUnderlier>(&cs, witness, vec![]);
+	}
+
+	#[test]
+	fn test_shared_fixed_circuit() {
+		const LOG_SIZE: usize = 4;
+
+		let circuit =
+			Arc::new(ArithCircuit::<B128>::from(&incrementing_expr::<B128>(LOG_SIZE).unwrap()));
+		let mut cs = ConstraintSystem::<B128>::new();
+		let columns = ["first", "second"].map(|name| {
+			let mut table = cs.add_table(name);
+			table.require_fixed_size(LOG_SIZE);
+			let fixed = table.add_fixed::<B8>("fixed", circuit.clone());
+			let committed = table.add_committed::<B8, 1>("committed");
+			table.assert_zero("committed = fixed", committed - fixed);
+			(table.id(), fixed, committed)
+		});
+
+		for (table_id, fixed, _) in columns {
+			let ColumnDef::StructuredFixedSize { expr } = &cs.tables[table_id][fixed.id()].col
+			else {
+				panic!("expected a fixed column");
+			};
+			assert!(Arc::ptr_eq(expr, &circuit));
+		}
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness = WitnessIndex::<PackedType<OptimalUnderlier, B128>>::new(&cs, &allocator);
+		for (table_id, fixed, committed) in columns {
+			witness
+				.fill_table_sequential(
+					&ClosureFiller::new(table_id, |events: &[u8], index| {
+						let mut fixed = index.get_scalars_mut(fixed)?;
+						let mut committed = index.get_scalars_mut(committed)?;
+						for (i, &event) in events.iter().enumerate() {
+							fixed[i] = B8::new(event);
+							committed[i] = B8::new(event);
+						}
+						Ok(())
+					}),
+					&(0..1 << LOG_SIZE).collect::<Vec<_>>(),
+				)
+				.unwrap();
+		}
+
+		validate_system_witness::<OptimalUnderlier>(&cs, witness, vec![]);
+	}
+
 	// Test that the `read` method works correctly.
 	#[test]
 	fn test_read_method() {
@@ -1,23 +1,35 @@
 // Copyright 2025 Irreducible Inc.
 
 //! Utilities for testing M3 constraint systems and gadgets.
+use std::{collections::BTreeMap, iter, sync::Mutex};
+
 use anyhow::Result;
-use binius_compute::ComputeHolder;
-use binius_core::{constraint_system::channel::Boundary, fiat_shamir::HasherChallenger};
+use binius_compute::{ComputeHolder, cpu::alloc::CpuComputeAllocator};
+use binius_core::{
+	constraint_system::{channel::Boundary, validate::validate_witness},
+	fiat_shamir::HasherChallenger,
+	polynomial::hypercube_points,
+};
 use binius_fast_compute::layer::FastCpuLayerHolder;
 use binius_field::{
-	BinaryField128bPolyval, PackedField, PackedFieldIndexable, TowerField,
+	BinaryField128bPolyval, ExtensionField, Field, PackedExtension, PackedField,
+	PackedFieldIndexable, TowerField,
+	arch::OptimalUnderlier128b,
 	as_packed_field::{PackScalar, PackedType},
 	linear_transformation::PackedTransformationFactory,
+	packed::get_packed_slice,
 	tower::CanonicalTowerFamily,
 	underlier::UnderlierType,
 };
 use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_math::{ArithCircuit, ArithCircuitStep, ArithExpr};
 use binius_utils::env::boolean_env_flag_set;
+use itertools::Itertools;
 
 use super::{
-	B1, B8, B16, B32, B64,
+	B1, B8, B16, B32, B64, Col, Error, Structured, StructuredDynSize,
 	constraint_system::ConstraintSystem,
+	structured,
 	table::TableId,
 	witness::{TableFiller, TableWitnessSegment},
 };
@@ -25,6 +37,9 @@ use crate::builder::{B128, WitnessIndex};
 
 /// An easy-to-use implementation of [`TableFiller`] that is constructed with a closure.
 ///
+/// The `Event` type is the row descriptor passed to the closure. It can be any cloneable type,
+/// e.g. a plain `u32` or a struct with one field per input column.
+///
 /// Using this [`TableFiller`] implementation carries some overhead, so it is best to use it only
 /// for testing.
 #[allow(clippy::type_complexity)]
@@ -93,7 +108,7 @@ pub fn validate_system_witness<U>(
 
 pub fn validate_system_witness_with_prove_verify<U>(
 	cs: &ConstraintSystem<B128>,
-	witness: WitnessIndex<PackedType<U, B128>>,
+	mut witness: WitnessIndex<PackedType<U, B128>>,
 	boundaries: Vec<Boundary<B128>>,
 	prove_verify: bool,
 ) where
@@ -109,6 +124,18 @@ pub fn validate_system_witness_with_prove_verify<U>(
 		PackedFieldIndexable + PackedTransformationFactory<PackedType<U, BinaryField128bPolyval>>,
 	PackedType<U, BinaryField128bPolyval>: PackedTransformationFactory<PackedType<U, B128>>,
 {
+	// A committed column that the fill did not write would hold whatever was in the allocated
+	// memory, which may happen to satisfy the constraints.
+	witness.check_committed_columns_written().unwrap();
+
+	// Checking the zero constraints on the M3 witness reports failures with the column names and
+	// values of the offending row, but it is an extra pass over the witness, so it is opt-in.
+	const EXPLAIN_FAILURES_ENV_NAME: &str = "BINIUS_M3_TEST_EXPLAIN_FAILURES";
+	if boolean_env_flag_set(EXPLAIN_FAILURES_ENV_NAME) {
+		let failures = witness.zero_constraint_failures().unwrap();
+		assert!(failures.is_empty(), "{}", failures.iter().join(""));
+	}
+
 	let table_sizes = witness.table_sizes();
 	let ccs = cs.compile().unwrap();
 	let witness = witness.into_multilinear_extension_index();
@@ -119,7 +146,12 @@ pub fn validate_system_witness_with_prove_verify<U>(
 		&table_sizes,
 		&witness,
 	)
-	.unwrap();
+	.unwrap_or_else(|err| {
+		panic!(
+			"{err}; set {EXPLAIN_FAILURES_ENV_NAME}=1 to report the failing rows with their \
+			 column values"
+		)
+	});
 
 	if prove_verify {
 		const LOG_INV_RATE: usize = 1;
@@ -162,3 +194,303 @@ pub fn validate_system_witness_with_prove_verify<U>(
 		.unwrap();
 	}
 }
+
+/// Fills a table sequentially in segments of `2^log_segment_size` rows, threading a carry from
+/// each segment into the next one.
+///
+/// `fill` is called on the events and the witness of each segment in order, with the carry
+/// returned for the previous segment, or `init` for the first one. This is the fill of the gadgets
+/// whose segments depend on the previous ones, such as running sums.
+pub fn fill_table_with_carry<Event, C>(
+	witness: &mut WitnessIndex<PackedType<OptimalUnderlier128b, B128>>,
+	table_id: TableId,
+	events: &[Event],
+	log_segment_size: usize,
+	init: C,
+	fill: impl Fn(
+		&[Event],
+		&mut TableWitnessSegment<PackedType<OptimalUnderlier128b, B128>>,
+		C,
+	) -> Result<C>
+	+ Sync,
+) -> Result<(), Error>
+where
+	Event: Clone,
+	C: Copy + Send,
+{
+	let carry = Mutex::new(init);
+	witness
+		.init_table(table_id, events.len())?
+		.fill_sequential_with_segment_size(
+			&ClosureFiller::new(table_id, |events: &[Event], index| {
+				let mut carry = carry.lock().unwrap();
+				*carry = fill(events, index, *carry)?;
+				Ok(())
+			}),
+			events,
+			log_segment_size,
+		)
+}
+
+/// Asserts that a witness satisfies the zero constraints on every row, but not the compiled
+/// constraint system.
+///
+/// This is the check for a witness that is only inconsistent across rows, e.g. a running sum
+/// restarted in the middle of the table with its shifted column kept consistent with it, which
+/// only the shifted oracles of the compiled constraint system catch.
+pub fn assert_rejected_across_rows(
+	cs: &ConstraintSystem<B128>,
+	mut witness: WitnessIndex<PackedType<OptimalUnderlier128b, B128>>,
+) {
+	assert!(witness.zero_constraint_failures().unwrap().is_empty());
+
+	let table_sizes = witness.table_sizes();
+	let ccs = cs.compile().unwrap();
+	let witness = witness.into_multilinear_extension_index();
+	assert!(validate_witness(&ccs, &[], &table_sizes, &witness).is_err());
+}
+
+/// Asserts that a structured column agrees with the values written by `fill`.
+///
+/// This builds a power-of-two sized table with `2^n_vars` rows, containing the structured column
+/// defined by `variant` and a committed reference column constrained to be equal to it. Both
+/// columns are filled with `fill` and the system is validated, which checks the filled values
+/// against the multilinear extension of the structured column. This is a one-call test for custom
+/// [`Structured`] implementations and their fill gadgets.
+pub fn assert_structured_matches_fill<FSub>(
+	variant: impl Structured + 'static,
+	fill: impl Fn(
+		&mut TableWitnessSegment<PackedType<OptimalUnderlier128b, B128>>,
+		Col<FSub>,
+	) -> Result<(), Error>
+	+ Sync,
+	n_vars: usize,
+) where
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+	PackedType<OptimalUnderlier128b, B128>: PackedExtension<FSub>,
+{
+	let mut cs = ConstraintSystem::new();
+	let mut table = cs.add_table("structured");
+	table.require_power_of_two_size();
+	let table_id = table.id();
+	let structured = table.add_structured::<FSub>("structured", variant);
+	let reference = table.add_committed::<FSub, 1>("reference");
+	table.assert_zero("reference = structured", reference - structured);
+	drop(table);
+
+	let mut allocator = CpuComputeAllocator::new(1 << (n_vars + 4).max(12));
+	let allocator = allocator.into_bump_allocator();
+	let mut witness = WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+	witness
+		.fill_table_sequential(
+			&ClosureFiller::new(table_id, |_events: &[()], index| {
+				fill(index, structured)?;
+				fill(index, reference)?;
+				Ok(())
+			}),
+			&vec![(); 1 << n_vars],
+		)
+		.unwrap();
+
+	validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+}
+
+/// Asserts that the multilinear extension of a structured column agrees with its fill gadget on
+/// every row of a table with `2^n_vars` rows.
+///
+/// Unlike [`assert_structured_matches_fill`], this does not validate a constraint system, but
+/// evaluates the expression of `variant` at every point of the hypercube directly and compares it
+/// with the value that `fill` writes at the corresponding row. The variables of the expression from
+/// `n_vars` on are set to zero, as for a table smaller than the maximum size of the column.
+pub fn check_structured_variant<FSub>(
+	variant: impl Structured + 'static,
+	fill: impl Fn(
+		&mut TableWitnessSegment<PackedType<OptimalUnderlier128b, B128>>,
+		Col<FSub>,
+	) -> Result<(), Error>
+	+ Sync,
+	n_vars: usize,
+) where
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+	PackedType<OptimalUnderlier128b, B128>: PackedExtension<FSub>,
+{
+	let max_size_log = variant.max_size_log();
+	assert!(n_vars <= max_size_log, "n_vars must be at most the maximum size of the column");
+	let expr = ArithCircuit::from(&variant.expr().unwrap());
+
+	let mut cs = ConstraintSystem::new();
+	let mut table = cs.add_table("structured");
+	table.require_power_of_two_size();
+	let table_id = table.id();
+	let structured = table.add_structured::<FSub>("structured", variant);
+	drop(table);
+
+	let mut allocator = CpuComputeAllocator::new(1 << (n_vars + 4).max(12));
+	let allocator = allocator.into_bump_allocator();
+	let mut witness = WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+	witness
+		.fill_table_sequential(
+			&ClosureFiller::new(table_id, |_events: &[()], index| {
+				fill(index, structured)?;
+				Ok(())
+			}),
+			&vec![(); 1 << n_vars],
+		)
+		.unwrap();
+
+	let segment = witness.get_table(table_id).unwrap().full_segment();
+	let values = segment.get_dyn(structured.id()).unwrap();
+	for (i, point) in hypercube_points::<B128>(n_vars).enumerate() {
+		let query = point
+			.into_iter()
+			.chain(iter::repeat(B128::ZERO))
+			.take(max_size_log)
+			.collect::<Vec<_>>();
+		assert_eq!(
+			expr.evaluate(&query).unwrap(),
+			values.get(i),
+			"the expression and the fill disagree at row {i}"
+		);
+	}
+}
+
+/// Asserts that the expression of a built-in structured column agrees with its materialized
+/// values on every row of a table with `2^n_vars` rows.
+///
+/// [`StructuredDynSize::expr`] and [`StructuredDynSize::materialize`] are two code paths for the
+/// values of the same column, the first of which can also be evaluated off the hypercube. This
+/// evaluates the expression at every point of the hypercube, with the variables from `n_vars` on
+/// set to zero, and compares it with the materialized values packed as `P`. Every built-in variant
+/// should be checked with it.
+pub fn assert_expr_matches_materialize<F, P>(variant: &StructuredDynSize, n_vars: usize)
+where
+	F: TowerField,
+	P: PackedField<Scalar = F>,
+{
+	let max_size_log = variant.max_size_log();
+	let expr = ArithCircuit::from(&(*variant).expr::<F>().unwrap());
+	let packed = variant.materialize::<F, P>(n_vars).unwrap();
+	assert_eq!(packed.len(), (1usize << n_vars).div_ceil(P::WIDTH));
+	for (i, point) in hypercube_points::<F>(n_vars).enumerate() {
+		let query = point
+			.into_iter()
+			.chain(iter::repeat(F::ZERO))
+			.take(max_size_log)
+			.collect::<Vec<_>>();
+		assert_eq!(
+			expr.evaluate(&query).unwrap(),
+			get_packed_slice(&packed, i),
+			"the expression and the materialized values of {variant:?} disagree at row {i}"
+		);
+	}
+}
+
+/// Asserts that an expression is multilinear, i.e. that each variable has degree at most one.
+///
+/// The multilinear extension of a structured column must be multilinear to be evaluated
+/// succinctly, so this is a check for the [`Structured::expr`] of custom structured columns. The
+/// degrees are syntactic: `Var(0) * Var(0)` is rejected even though it equals `Var(0)` on the
+/// hypercube.
+pub fn assert_multilinear<F: Field>(expr: &ArithExpr<F>) {
+	let circuit = ArithCircuit::from(expr);
+
+	// The degree of each variable in each step of the circuit.
+	let mut step_degrees = Vec::<BTreeMap<usize, u64>>::with_capacity(circuit.steps().len());
+	for step in circuit.steps() {
+		let degrees = match *step {
+			ArithCircuitStep::Const(_) => BTreeMap::new(),
+			ArithCircuitStep::Var(var) => BTreeMap::from([(var, 1)]),
+			ArithCircuitStep::Add(left, right) => {
+				let mut degrees = step_degrees[left].clone();
+				for (&var, &degree) in &step_degrees[right] {
+					let entry = degrees.entry(var).or_default();
+					*entry = (*entry).max(degree);
+				}
+				degrees
+			}
+			ArithCircuitStep::Mul(left, right) => {
+				let mut degrees = step_degrees[left].clone();
+				for (&var, &degree) in &step_degrees[right] {
+					*degrees.entry(var).or_default() += degree;
+				}
+				degrees
+			}
+			ArithCircuitStep::Pow(base, exp) => step_degrees[base]
+				.iter()
+				.map(|(&var, &degree)| (var, degree.saturating_mul(exp)))
+				.collect(),
+		};
+		step_degrees.push(degrees);
+	}
+
+	if let Some(degrees) = step_degrees.last() {
+		for (var, degree) in degrees {
+			assert!(*degree <= 1, "variable {var} has degree {degree} in a multilinear expression");
+		}
+	}
+}
+
+/// A custom structured column with the same value on every row.
+///
+/// The expression uses none of the `max_size_log` variables, so the value does not depend on the
+/// row. It is a fixture for the code paths of structured columns that are not built in.
+#[derive(Debug, Clone, Copy)]
+pub struct ConstantStructured {
+	pub value: B128,
+	pub max_size_log: usize,
+}
+
+impl Structured for ConstantStructured {
+	fn expr(&self) -> Result<ArithExpr<B128>, structured::Error> {
+		Ok(ArithExpr::Const(self.value))
+	}
+
+	fn max_size_log(&self) -> usize {
+		self.max_size_log
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{builder::StructuredDynSize, gadgets::structured::fill_incrementing_b32};
+
+	#[test]
+	fn test_assert_multilinear_incrementing() {
+		assert_multilinear(
+			&StructuredDynSize::Incrementing { max_size_log: 8 }
+				.expr::<B128>()
+				.unwrap(),
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "variable 0 has degree 2")]
+	fn test_assert_multilinear_rejects_quadratic() {
+		let x = ArithExpr::<B128>::Var(0);
+		let y = ArithExpr::<B128>::Var(1);
+		assert_multilinear(&((x.clone() + y.clone()) * (x + y)));
+	}
+
+	#[test]
+	fn test_check_structured_variant_incrementing() {
+		for n_vars in [0, 3, 6] {
+			check_structured_variant::<B32>(
+				StructuredDynSize::Incrementing { max_size_log: 6 },
+				fill_incrementing_b32,
+				n_vars,
+			);
+		}
+	}
+
+	#[test]
+	fn test_assert_structured_matches_fill_incrementing() {
+		assert_structured_matches_fill::<B32>(
+			StructuredDynSize::Incrementing { max_size_log: 8 },
+			fill_incrementing_b32,
+			6,
+		);
+	}
+}
@@ -0,0 +1,436 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::sync::Arc;
+
+use binius_core::transcript::TranscriptWriter;
+use binius_field::TowerField;
+use binius_math::ArithCircuit;
+use binius_utils::bytes::BufMut;
+
+use super::{
+	B128, ColumnId, ConstraintSystem, StructuredDynSize, Table, TableId, ZeroConstraint,
+	column::{ColumnDef, ColumnInfo},
+	error::Error,
+};
+
+/// The part of a [`ConstraintSystem`] that a verifier needs to evaluate the constraints.
+///
+/// This is created with [`ConstraintSystem::to_verifier`]. It holds only the arithmetic circuits
+/// of the structured columns and of the zero constraints, and none of the column definitions that
+/// are only used to fill a witness.
+#[derive(Debug, Clone)]
+pub struct VerifierConstraintSystem<F: TowerField = B128> {
+	pub tables: Vec<VerifierTable<F>>,
+}
+
+/// The verifier's view of a table. See [`VerifierConstraintSystem`].
+#[derive(Debug, Clone)]
+pub struct VerifierTable<F: TowerField = B128> {
+	pub id: TableId,
+	pub name: String,
+	/// The structured columns of the table, in the order they were added.
+	///
+	/// With [`ConstraintSystem::structured_as_committed`] set, the dynamically-sized structured
+	/// columns are committed and are not listed here.
+	pub structured_columns: Vec<VerifierStructuredColumn<F>>,
+	/// The zero constraints of the table, in the order of the table partitions.
+	pub zero_constraints: Vec<VerifierZeroConstraint<F>>,
+}
+
+/// A structured column whose multilinear extension the verifier evaluates itself.
+#[derive(Debug, Clone)]
+pub struct VerifierStructuredColumn<F: TowerField = B128> {
+	pub id: ColumnId,
+	pub name: String,
+	pub log_values_per_row: usize,
+	/// The number of variables of the multilinear extension, the base-2 logarithm of the maximum
+	/// size of the column.
+	pub n_vars: usize,
+	/// The arithmetic circuit of the multilinear extension, over at most [`Self::n_vars`]
+	/// variables.
+	pub expr: Arc<ArithCircuit<F>>,
+}
+
+/// A zero constraint over the columns of a table partition.
+#[derive(Debug, Clone)]
+pub struct VerifierZeroConstraint<F: TowerField = B128> {
+	pub log_values_per_row: usize,
+	/// The columns of the partition. Variable `i` of the constraint refers to `columns[i]`.
+	pub columns: Vec<ColumnId>,
+	pub constraint: ZeroConstraint<F>,
+}
+
+/// A compact description of a built-in structured column.
+///
+/// The verifier evaluates the multilinear extension of the column from its variant, so it needs
+/// neither the builder nor the arithmetic circuit of the column. The descriptors are created with
+/// [`ConstraintSystem::structured_descriptors`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StructuredColumnDescriptor {
+	pub table_id: TableId,
+	pub id: ColumnId,
+	pub variant: StructuredDynSize,
+}
+
+impl StructuredColumnDescriptor {
+	/// The number of variables of the multilinear extension of the column.
+	pub fn n_vars(&self) -> usize {
+		self.variant.max_size_log()
+	}
+
+	/// Evaluates the multilinear extension of the column at `point`.
+	///
+	/// As for [`VerifierStructuredColumn::evaluate`], the point may have fewer coordinates than
+	/// [`Self::n_vars`], in which case the remaining variables are set to zero.
+	pub fn evaluate<F: TowerField>(&self, point: &[F]) -> Result<F, Error> {
+		Ok(self.variant.evaluate(point)?)
+	}
+
+	/// Returns a canonical encoding of the descriptor, for observing in a transcript.
+	///
+	/// This is the table ID and the index of the column in the table as little-endian `u64`s,
+	/// followed by [`StructuredDynSize::transcript_bytes`].
+	pub fn transcript_bytes(&self) -> Vec<u8> {
+		let mut bytes = Vec::new();
+		bytes.extend((self.table_id as u64).to_le_bytes());
+		bytes.extend((self.id.table_index.0 as u64).to_le_bytes());
+		bytes.extend(self.variant.transcript_bytes());
+		bytes
+	}
+}
+
+impl<F: TowerField> ConstraintSystem<F> {
+	/// Returns the descriptors of the built-in structured columns of all tables.
+	///
+	/// The columns of custom [`Structured`](super::Structured) implementations have no compact
+	/// descriptor and are only in [`Self::to_verifier`]. With [`Self::structured_as_committed`]
+	/// set, the dynamically-sized structured columns are committed and there are no descriptors.
+	pub fn structured_descriptors(&self) -> Vec<StructuredColumnDescriptor> {
+		if self.structured_as_committed {
+			return Vec::new();
+		}
+		self.tables
+			.iter()
+			.flat_map(|table| &table.columns)
+			.filter_map(|info| match &info.col {
+				ColumnDef::StructuredDynSize(structured) => {
+					structured
+						.as_dyn_size()
+						.map(|variant| StructuredColumnDescriptor {
+							table_id: info.id.table_id,
+							id: info.id,
+							variant,
+						})
+				}
+				_ => None,
+			})
+			.collect()
+	}
+
+	/// Writes the specifications of the structured columns of all tables to a transcript.
+	///
+	/// The verifier evaluates these columns itself, so the prover and the verifier observe their
+	/// specifications to bind them to the Fiat-Shamir challenges. The number of descriptors is
+	/// written first, as a little-endian `u64`, followed by the
+	/// [`StructuredColumnDescriptor::transcript_bytes`] of every descriptor. The other structured
+	/// columns, i.e. the custom [`Structured`](super::Structured) columns and the fixed-size ones,
+	/// have no descriptor and are bound by their circuits: their number follows, and then for each
+	/// the table ID, the index of the column in the table and the number of variables as
+	/// little-endian `u64`s, and the serialized arithmetic circuit of
+	/// [`VerifierStructuredColumn::expr`].
+	///
+	/// ## Throws
+	///
+	/// * [`Error::Structured`] if the expression of a custom structured column cannot be built.
+	pub fn observe_structured_descriptors<B: BufMut>(
+		&self,
+		transcript: &mut TranscriptWriter<B>,
+	) -> Result<(), Error> {
+		let descriptors = self.structured_descriptors();
+		transcript.write_bytes(&(descriptors.len() as u64).to_le_bytes());
+		for descriptor in &descriptors {
+			transcript.write_bytes(&descriptor.transcript_bytes());
+		}
+
+		let columns = self
+			.to_verifier()?
+			.tables
+			.into_iter()
+			.flat_map(|table| table.structured_columns)
+			.filter(|col| !descriptors.iter().any(|descriptor| descriptor.id == col.id))
+			.collect::<Vec<_>>();
+		transcript.write_bytes(&(columns.len() as u64).to_le_bytes());
+		for col in &columns {
+			transcript.write_bytes(&(col.id.table_id as u64).to_le_bytes());
+			transcript.write_bytes(&(col.id.table_index.0 as u64).to_le_bytes());
+			transcript.write_bytes(&(col.n_vars as u64).to_le_bytes());
+			transcript.write(&*col.expr);
+		}
+		Ok(())
+	}
+
+	/// Returns the verification-only representation of the constraint system.
+	pub fn to_verifier(&self) -> Result<VerifierConstraintSystem<F>, Error> {
+		let tables = self
+			.tables
+			.iter()
+			.map(|table| VerifierTable::new(table, self.structured_as_committed))
+			.collect::<Result<_, _>>()?;
+		Ok(VerifierConstraintSystem { tables })
+	}
+}
+
+impl<F: TowerField> VerifierConstraintSystem<F> {
+	/// Returns the structured column with the given ID, if it exists.
+	pub fn structured_column(&self, id: ColumnId) -> Option<&VerifierStructuredColumn<F>> {
+		self.tables
+			.get(id.table_id)?
+			.structured_columns
+			.iter()
+			.find(|col| col.id == id)
+	}
+}
+
+impl<F: TowerField> VerifierTable<F> {
+	fn new(table: &Table<F>, structured_as_committed: bool) -> Result<Self, Error> {
+		let mut structured_columns = Vec::new();
+		for ColumnInfo {
+			id,
+			col,
+			name,
+			shape,
+			..
+		} in &table.columns
+		{
+			let (n_vars, expr) = match col {
+				ColumnDef::StructuredDynSize(_) if structured_as_committed => continue,
+				ColumnDef::StructuredDynSize(structured) => (
+					structured.max_size_log(),
+					Arc::new(ArithCircuit::from(
+						&structured.expr().map_err(|err| err.for_column(name))?,
+					)),
+				),
+				ColumnDef::StructuredFixedSize { expr } => (expr.n_vars(), Arc::clone(expr)),
+				_ => continue,
+			};
+			structured_columns.push(VerifierStructuredColumn {
+				id: *id,
+				name: name.clone(),
+				log_values_per_row: shape.log_values_per_row,
+				n_vars,
+				expr,
+			});
+		}
+
+		let zero_constraints = table
+			.partitions
+			.values()
+			.flat_map(|partition| {
+				let log_values_per_row = partition.values_per_row.ilog2() as usize;
+				partition
+					.zero_constraints
+					.iter()
+					.map(move |constraint| VerifierZeroConstraint {
+						log_values_per_row,
+						columns: partition.columns.clone(),
+						constraint: constraint.clone(),
+					})
+			})
+			.collect();
+
+		Ok(Self {
+			id: table.id,
+			name: table.name.clone(),
+			structured_columns,
+			zero_constraints,
+		})
+	}
+}
+
+impl<F: TowerField> VerifierStructuredColumn<F> {
+	/// Evaluates the multilinear extension of the column at `point`.
+	///
+	/// The point may have fewer coordinates than [`Self::n_vars`], when the table is smaller than
+	/// the maximum size of the column. The remaining variables are set to zero, which is how the
+	/// structured oracle is evaluated by the verifier.
+	pub fn evaluate(&self, point: &[F]) -> Result<F, Error> {
+		if point.len() > self.n_vars {
+			return Err(binius_math::Error::IncorrectQuerySize {
+				expected: self.n_vars,
+				actual: point.len(),
+			}
+			.into());
+		}
+		Ok(self.expr.evaluate_partial(point))
+	}
+}
+
+impl<F: TowerField> VerifierZeroConstraint<F> {
+	/// Evaluates the constraint given the evaluations of the partition columns.
+	pub fn evaluate(&self, column_evals: &[F]) -> Result<F, Error> {
+		if column_evals.len() != self.columns.len() {
+			return Err(binius_math::Error::IncorrectQuerySize {
+				expected: self.columns.len(),
+				actual: column_evals.len(),
+			}
+			.into());
+		}
+		Ok(self.constraint.expr.evaluate(column_evals)?)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::{self, repeat_with};
+
+	use binius_compute::cpu::alloc::CpuComputeAllocator;
+	use binius_core::{
+		fiat_shamir::{CanSample, HasherChallenger},
+		transcript::ProverTranscript,
+	};
+	use binius_field::{Field, arch::OptimalUnderlier128b, as_packed_field::PackedType};
+	use binius_hash::groestl::Groestl256;
+	use binius_math::eq_ind_partial_eval;
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::{
+		builder::{
+			B32, Scaled, Structured, StructuredDynSize, TableBuilder, WitnessIndex,
+			structured::incrementing_expr,
+			test_utils::{ClosureFiller, ConstantStructured, validate_system_witness},
+		},
+		gadgets::structured::fill_incrementing_b32,
+	};
+
+	#[test]
+	fn test_verifier_structured_evaluation() {
+		const LOG_SIZE: usize = 6;
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("incrementing");
+		table.require_power_of_two_size();
+		let table_id = table.id();
+		let incrementing = table.add_structured::<B32>(
+			"incrementing",
+			StructuredDynSize::Incrementing { max_size_log: 8 },
+		);
+		let committed = table.add_committed::<B32, 1>("committed");
+		table.assert_zero("committed = incrementing", committed - incrementing);
+		drop(table);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |_events: &[()], index| {
+					fill_incrementing_b32(index, incrementing)?;
+					let src = index.get(incrementing)?;
+					index.get_mut(committed)?.copy_from_slice(&src);
+					Ok(())
+				}),
+				&[(); 1 << LOG_SIZE],
+			)
+			.unwrap();
+
+		let values = witness
+			.get_table(table_id)
+			.unwrap()
+			.full_segment()
+			.get_scalars(incrementing)
+			.unwrap()
+			.to_vec();
+
+		let verifier_cs = cs.to_verifier().unwrap();
+		let structured = verifier_cs.structured_column(incrementing.id()).unwrap();
+		assert_eq!(structured.name, "incrementing");
+		assert!(verifier_cs.structured_column(committed.id()).is_none());
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let point = repeat_with(|| B128::random(&mut rng))
+			.take(LOG_SIZE)
+			.collect::<Vec<_>>();
+		let expected = iter::zip(&values, eq_ind_partial_eval::<B128>(&point))
+			.map(|(&value, eq)| eq * value)
+			.sum::<B128>();
+		assert_eq!(structured.evaluate(&point).unwrap(), expected);
+
+		let [ref descriptor] = cs.structured_descriptors()[..] else {
+			panic!("expected a single structured column descriptor");
+		};
+		assert_eq!(descriptor.id, incrementing.id());
+		assert_eq!(descriptor.table_id, table_id);
+		assert_eq!(descriptor.n_vars(), 8);
+		assert_eq!(descriptor.evaluate(&point).unwrap(), expected);
+
+		let [constraint] = verifier_cs.tables[table_id].zero_constraints.as_slice() else {
+			panic!("expected a single zero constraint");
+		};
+		assert_eq!(constraint.constraint.name, "committed = incrementing");
+		let column_evals = constraint
+			.columns
+			.iter()
+			.map(|&id| {
+				if id == committed.id() || id == incrementing.id() {
+					expected
+				} else {
+					B128::ZERO
+				}
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(constraint.evaluate(&column_evals).unwrap(), B128::ZERO);
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+	}
+
+	#[test]
+	fn test_observe_structured_descriptors() {
+		fn challenge(add_column: impl FnOnce(&mut TableBuilder)) -> B128 {
+			let mut cs = ConstraintSystem::<B128>::new();
+			let mut table = cs.add_table("structured");
+			add_column(&mut table);
+			drop(table);
+
+			let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+			cs.observe_structured_descriptors(&mut transcript.observe())
+				.unwrap();
+			transcript.sample()
+		}
+		fn structured(column: impl Structured + 'static) -> impl FnOnce(&mut TableBuilder) {
+			move |table| {
+				table.require_power_of_two_size();
+				table.add_structured::<B32>("structured", column);
+			}
+		}
+
+		let incrementing = StructuredDynSize::Incrementing { max_size_log: 8 };
+		assert_eq!(challenge(structured(incrementing)), challenge(structured(incrementing)));
+		assert_ne!(
+			challenge(structured(incrementing)),
+			challenge(structured(StructuredDynSize::Complement { max_size_log: 8 }))
+		);
+
+		// The columns without a descriptor are bound by their circuits.
+		let constant = |value| ConstantStructured {
+			value: B128::new(value),
+			max_size_log: 8,
+		};
+		assert_ne!(challenge(structured(constant(1))), challenge(structured(constant(2))));
+		let scaled = |factor| Scaled {
+			inner: incrementing,
+			factor: B128::new(factor),
+		};
+		assert_ne!(challenge(structured(scaled(1))), challenge(structured(scaled(2))));
+		let fixed = |table: &mut TableBuilder| {
+			table.require_fixed_size(3);
+			table.add_fixed::<B32>(
+				"fixed",
+				ArithCircuit::from(&incrementing_expr::<B128>(3).unwrap()),
+			);
+		};
+		assert_eq!(challenge(fixed), challenge(fixed));
+		assert_ne!(challenge(fixed), challenge(|_| ()));
+		assert_ne!(challenge(fixed), challenge(structured(constant(1))));
+	}
+}
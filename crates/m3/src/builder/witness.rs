@@ -1,15 +1,22 @@
 // Copyright 2025 Irreducible Inc.
 
 use std::{
+	borrow::Cow,
 	cell::{Ref, RefCell, RefMut},
-	iter,
-	ops::{Deref, DerefMut},
+	collections::BTreeSet,
+	fmt::Debug,
+	iter, mem,
+	ops::{Deref, DerefMut, Range},
 	slice,
 	sync::Arc,
+	time::{Duration, Instant},
 };
 
-use binius_compute::alloc::{ComputeAllocator, HostBumpAllocator};
-use binius_core::witness::{MultilinearExtensionIndex, MultilinearWitness};
+use binius_compute::alloc::{ComputeAllocator, Error as AllocError, HostBumpAllocator};
+use binius_core::{
+	constraint_system::TableSizeSpec,
+	witness::{MultilinearExtensionIndex, MultilinearWitness},
+};
 use binius_fast_compute::arith_circuit::ArithCircuitPoly;
 use binius_field::{
 	ExtensionField, PackedExtension, PackedField, PackedFieldIndexable, PackedSubfield, TowerField,
@@ -25,18 +32,28 @@ use binius_utils::checked_arithmetics::checked_log_2;
 use bytemuck::{Pod, must_cast_slice, must_cast_slice_mut, zeroed_vec};
 use either::Either;
 use getset::CopyGetters;
-use itertools::Itertools;
+use itertools::{Itertools, izip};
+use rand::{CryptoRng, Rng, SeedableRng, rngs::StdRng};
 
 use super::{
 	ColumnDef, ColumnId, ColumnInfo, ConstraintSystem, Expr,
 	column::{Col, ColumnShape},
 	constraint_system::OracleMapping,
 	error::Error,
-	table::{self, Table, TableId},
+	expr::{ArithExprNamedVars, ZeroConstraint},
+	table::{self, Table, TableId, TablePartition},
 	types::{B1, B8, B16, B32, B64, B128},
 };
 use crate::builder::multi_iter::MultiIterator;
 
+/// The packed field with the widest underlier available on the target CPU.
+///
+/// The underlier is [`OptimalUnderlier`], which is selected at compile time from the enabled target
+/// features: the SIMD implementations are only compiled when the corresponding features are
+/// enabled, so building with `-C target-cpu=native` on AVX-512 hardware selects the 512-bit
+/// underlier.
+pub type OptimalPackedB128 = PackedType<OptimalUnderlier, B128>;
+
 /// Holds witness column data for all tables in a constraint system, indexed by column ID.
 ///
 /// The struct has two lifetimes: `'cs` is the lifetime of the constraint system, and `'alloc` is
@@ -51,8 +68,15 @@ where
 {
 	cs: &'cs ConstraintSystem<P::Scalar>,
 	allocator: &'alloc HostBumpAllocator<'alloc, P>,
+	/// The allocators of the tables that do not allocate from the shared allocator, indexed by
+	/// table ID.
+	table_allocators: Vec<Option<&'alloc HostBumpAllocator<'alloc, P>>>,
 	/// Each entry is Left if the index hasn't been initialized & filled, and Right if it has.
 	tables: Vec<Either<&'cs Table<P::Scalar>, TableWitnessIndex<'cs, 'alloc, P>>>,
+	/// The observer passed to every table index initialized after it is set.
+	fill_observer: Option<Arc<dyn FillObserver>>,
+	/// The observer notified of the columns of every filled table.
+	commit_observer: Option<Arc<dyn CommitObserver<P>>>,
 }
 
 impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> WitnessIndex<'cs, 'alloc, P> {
@@ -64,22 +88,88 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> WitnessIndex<'cs, '
 		Self {
 			cs,
 			allocator,
+			table_allocators: vec![None; cs.tables.len()],
 			tables: cs.tables.iter().map(Either::Left).collect(),
+			fill_observer: None,
+			commit_observer: None,
 		}
 	}
 
+	/// Sets an observer that is notified about the progress of the table fills.
+	///
+	/// The observer applies to the tables initialized after this call. See [`FillObserver`].
+	pub fn set_fill_observer(&mut self, observer: Arc<dyn FillObserver>) {
+		self.fill_observer = Some(observer);
+	}
+
+	/// Sets an observer that is notified about every column as soon as it is filled.
+	///
+	/// The observer applies to the tables filled after this call. See [`CommitObserver`].
+	pub fn set_commit_observer(&mut self, observer: Arc<dyn CommitObserver<P>>) {
+		self.commit_observer = Some(observer);
+	}
+
+	/// Sets the allocator of the columns of a table, instead of the allocator shared by all
+	/// tables.
+	///
+	/// The allocator applies to the columns allocated after this call, when the table is
+	/// initialized or grows with [`Self::append_events`]. Tables of very different sizes can then
+	/// allocate from arenas sized for them, rather than from one arena sized for the worst case.
+	pub fn set_table_allocator(
+		&mut self,
+		table_id: TableId,
+		allocator: &'alloc HostBumpAllocator<'alloc, P>,
+	) -> Result<(), Error> {
+		let entry = self
+			.table_allocators
+			.get_mut(table_id)
+			.ok_or(Error::MissingTable { table_id })?;
+		*entry = Some(allocator);
+		Ok(())
+	}
+
+	/// Returns the allocator of the columns of a table.
+	fn table_allocator(&self, table_id: TableId) -> &'alloc HostBumpAllocator<'alloc, P> {
+		self.table_allocators
+			.get(table_id)
+			.copied()
+			.flatten()
+			.unwrap_or(self.allocator)
+	}
+
 	pub fn init_table(
 		&mut self,
 		table_id: TableId,
 		size: usize,
 	) -> Result<&mut TableWitnessIndex<'cs, 'alloc, P>, Error> {
+		self.init_table_with_allocator(table_id, size, None)
+	}
+
+	/// Initializes a table like [`Self::init_table`], with the columns allocated by `allocator`
+	/// if given.
+	///
+	/// Without an allocator, the columns are allocated by the allocator of the table, see
+	/// [`Self::set_table_allocator`]. A given allocator also becomes the allocator of the table.
+	pub fn init_table_with_allocator(
+		&mut self,
+		table_id: TableId,
+		size: usize,
+		allocator: Option<&'alloc HostBumpAllocator<'alloc, P>>,
+	) -> Result<&mut TableWitnessIndex<'cs, 'alloc, P>, Error> {
+		if let Some(allocator) = allocator
+			&& matches!(self.tables.get(table_id), Some(Either::Left(_)))
+		{
+			self.set_table_allocator(table_id, allocator)?;
+		}
+		let allocator = self.table_allocator(table_id);
 		match self.tables.get_mut(table_id) {
 			Some(entry) => match entry {
 				Either::Left(table) => {
 					if size == 0 {
 						Err(Error::EmptyTable { table_id })
 					} else {
-						let table_witness = TableWitnessIndex::new(self.allocator, table, size)?;
+						let mut table_witness = TableWitnessIndex::new(allocator, table, size)?;
+						table_witness.fill_observer = self.fill_observer.clone();
 						*entry = Either::Right(table_witness);
 						let Either::Right(table_witness) = entry else {
 							unreachable!("entry is assigned to this pattern on the previous line")
@@ -108,11 +198,150 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> WitnessIndex<'cs, '
 		filler: &T,
 		rows: &[T::Event],
 	) -> Result<(), Error> {
-		self.init_and_fill_table(
-			filler.id(),
-			|table_witness, rows| table_witness.fill_sequential(filler, rows),
-			rows,
-		)
+		self.init_and_fill_table(filler.id(), rows.len(), None, |table_witness| {
+			table_witness.fill_sequential(filler, rows)
+		})
+	}
+
+	/// Fills a table one chunk of rows at a time, in a single buffer of the size of a chunk.
+	///
+	/// This is for tables whose witness does not fit in memory: the table is split into chunks of
+	/// `1 << log_chunk_size` rows, which is clamped like the segment size in
+	/// [`TableWitnessIndex::segments`], and only one chunk is held at a time. The buffer is
+	/// allocated once from the allocator of the table, and each chunk of events is filled into it
+	/// in row order, then passed to `on_chunk` with the number of rows of the chunk that hold
+	/// events, e.g. to stream them into a commitment, before the buffer is reused for the next
+	/// chunk. Only those rows are filled from the events of the chunk: the last chunk may hold
+	/// fewer events than rows, and `on_chunk` must not read the rows after them. The rows past the
+	/// events are not passed to `on_chunk`, so the padding of the table is up to the caller.
+	///
+	/// The witness of the table is not kept, so the table stays unfilled in this index. The
+	/// structured column fills that read the row offset from the segment, such as
+	/// [`fill_incrementing_b32`](crate::gadgets::structured::fill_incrementing_b32), work unchanged
+	/// on chunks.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::ChunkedFillWithBlindingRows`] if the table has blinding rows, which
+	///   [`TableWitnessIndex::fill_blinding_rows`] fills in a table index.
+	pub fn fill_table_chunked<T: TableFiller<P>>(
+		&self,
+		filler: &T,
+		rows: &[T::Event],
+		log_chunk_size: usize,
+		mut on_chunk: impl FnMut(&TableWitnessSegment<P>, usize) -> anyhow::Result<()>,
+	) -> Result<(), Error> {
+		let table_id = filler.id();
+		let table = self
+			.cs
+			.tables
+			.get(table_id)
+			.ok_or(Error::MissingTable { table_id })?;
+		let Some(last_event) = rows.last() else {
+			return Err(Error::EmptyTable { table_id });
+		};
+		if table.blinding_rows() != 0 {
+			return Err(Error::ChunkedFillWithBlindingRows { table_id });
+		}
+
+		let log_capacity = table::log_capacity(rows.len());
+		let log_chunk_size =
+			log_chunk_size.clamp(min_log_segment_size::<P>(table, log_capacity), log_capacity);
+		let chunk_size = 1 << log_chunk_size;
+		let mut chunk = TableWitnessIndex::with_log_capacity(
+			self.table_allocator(table_id),
+			table,
+			chunk_size,
+			log_chunk_size,
+		)?;
+
+		for (index, events) in rows.chunks(chunk_size).enumerate() {
+			let mut segment = chunk.segment_at(index);
+			if events.len() == chunk_size {
+				filler.fill(events, &mut segment)
+			} else {
+				// The filler takes a full chunk of events, so the last event is cloned into the
+				// rows that are not passed to on_chunk.
+				let mut padded = events.to_vec();
+				padded.resize(chunk_size, last_event.clone());
+				filler.fill(&padded, &mut segment)
+			}
+			.map_err(Error::TableFill)?;
+			on_chunk(&segment, events.len()).map_err(Error::ChunkCallback)?;
+		}
+		Ok(())
+	}
+
+	/// Fills one phase of a table, with the challenges sampled before the phase.
+	///
+	/// The phases of a table, see [`Table::n_phases`], must be filled in order and with the same
+	/// events. Phase `0` initializes the table index, like [`Self::fill_table_sequential`], and the
+	/// later phases fill the rows of the initialized index again. The challenges of a phase are
+	/// typically sampled after the columns of the earlier phases are committed, and are passed to
+	/// [`PhasedTableFiller::fill_phase`]. A table without events is not initialized, and all its
+	/// phases are no-ops.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::PhaseOutOfOrder`] if `phase` is not the next phase of the table to fill.
+	pub fn fill_table_phase<T: PhasedTableFiller<P>>(
+		&mut self,
+		filler: &T,
+		phase: usize,
+		challenges: &[F],
+		rows: &[T::Event],
+	) -> Result<(), Error> {
+		let table_id = filler.id();
+		let (expected, n_phases) = match self.tables.get(table_id) {
+			Some(Either::Left(_)) if rows.is_empty() => return Ok(()),
+			Some(Either::Left(table)) => (0, table.n_phases()),
+			Some(Either::Right(table_witness)) => {
+				(table_witness.next_phase, table_witness.table.n_phases())
+			}
+			None => return Err(Error::MissingTable { table_id }),
+		};
+		if phase != expected || phase >= n_phases {
+			return Err(Error::PhaseOutOfOrder {
+				table_id,
+				phase,
+				expected,
+				n_phases,
+			});
+		}
+
+		let phase_filler = PhaseFiller {
+			filler,
+			phase,
+			challenges,
+		};
+		self.init_and_fill_table(table_id, rows.len(), Some(phase), |table_witness| {
+			table_witness.fill_sequential(&phase_filler, rows)
+		})?;
+		if let Some(table_witness) = self.get_table(table_id) {
+			table_witness.next_phase += 1;
+		}
+		Ok(())
+	}
+
+	/// Fills a table with events taken lazily from an iterator, in a single thread.
+	///
+	/// This is the same as [`Self::fill_table_sequential`], except that the events are not
+	/// collected up front: only the events of one segment are held in memory at a time. For
+	/// example, a table of row indices can be filled from the range `0..1 << 24` directly.
+	pub fn fill_table_sequential_from_iter<T, I>(
+		&mut self,
+		filler: &T,
+		events: I,
+	) -> Result<(), Error>
+	where
+		T: TableFiller<P>,
+		I: IntoIterator<Item = T::Event>,
+		I::IntoIter: ExactSizeIterator,
+	{
+		let events = events.into_iter();
+		self.init_and_fill_table(filler.id(), events.len(), None, |table_witness| {
+			table_witness.fill_sequential_from_iter(filler, events)
+		})
 	}
 
 	pub fn fill_table_parallel<T>(&mut self, filler: &T, rows: &[T::Event]) -> Result<(), Error>
@@ -120,45 +349,170 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> WitnessIndex<'cs, '
 		T: TableFiller<P> + Sync,
 		T::Event: Sync,
 	{
-		self.init_and_fill_table(
-			filler.id(),
-			|table_witness, rows| table_witness.fill_parallel(filler, rows),
-			rows,
-		)
+		self.init_and_fill_table(filler.id(), rows.len(), None, |table_witness| {
+			table_witness.fill_parallel(filler, rows)
+		})
 	}
 
-	fn init_and_fill_table<Event>(
+	/// Fills a table, initializing its index first if needed, and notifies the commit observer
+	/// of its columns, only of those of `phase` if given.
+	fn init_and_fill_table(
 		&mut self,
 		table_id: TableId,
-		fill: impl FnOnce(&mut TableWitnessIndex<'cs, 'alloc, P>, &[Event]) -> Result<(), Error>,
-		rows: &[Event],
+		n_rows: usize,
+		phase: Option<usize>,
+		fill: impl FnOnce(&mut TableWitnessIndex<'cs, 'alloc, P>) -> Result<(), Error>,
 	) -> Result<(), Error> {
+		let allocator = self.table_allocator(table_id);
 		match self.tables.get_mut(table_id) {
 			Some(entry) => match entry {
-				Either::Right(witness) => fill(witness, rows),
+				Either::Right(witness) => fill(witness)?,
 				Either::Left(table) => {
-					if rows.is_empty() {
-						Ok(())
-					} else {
-						let mut table_witness =
-							TableWitnessIndex::new(self.allocator, table, rows.len())?;
-						fill(&mut table_witness, rows)?;
-						*entry = Either::Right(table_witness);
-						Ok(())
+					if n_rows == 0 {
+						return Ok(());
 					}
+					let mut table_witness = TableWitnessIndex::new(allocator, table, n_rows)?;
+					fill(&mut table_witness)?;
+					*entry = Either::Right(table_witness);
 				}
 			},
+			None => return Err(Error::MissingTable { table_id }),
+		}
+		self.notify_filled_columns(table_id, phase);
+		Ok(())
+	}
+
+	/// Passes the data of the columns of a filled table to the commit observer, only of the
+	/// columns of `phase` if given.
+	fn notify_filled_columns(&self, table_id: TableId, phase: Option<usize>) {
+		let (Some(observer), Some(Either::Right(table_witness))) =
+			(&self.commit_observer, self.tables.get(table_id))
+		else {
+			return;
+		};
+		for (info, col) in iter::zip(&table_witness.table.columns, &table_witness.cols) {
+			if phase.is_some_and(|phase| info.phase != phase) {
+				continue;
+			}
+			if let WitnessDataMut::Owned(data) = &col.data {
+				observer.on_column_filled(table_id, col.column_id, data);
+			}
+		}
+	}
+
+	/// Appends events to a table, after the events it was filled with.
+	///
+	/// The table must have been filled before, otherwise this is the same as
+	/// [`Self::fill_table_sequential`]. The columns are reallocated with a larger capacity if the
+	/// new events do not fit, and the rows of the new events are filled with `filler`, which must
+	/// be the filler the table was filled with. The rows of the earlier events keep their values,
+	/// and structured columns take the values of their definition on the new rows, as if the table
+	/// had been filled with all the events at once. The blinding rows of the table are overwritten,
+	/// so [`TableWitnessIndex::fill_blinding_rows`] must be called again after appending.
+	pub fn append_events<T: TableFiller<P>>(
+		&mut self,
+		filler: &T,
+		events: &[T::Event],
+	) -> Result<(), Error>
+	where
+		P: PackedExtension<B1>,
+	{
+		let table_id = filler.id();
+		let allocator = self.table_allocator(table_id);
+		match self.tables.get_mut(table_id) {
+			Some(Either::Right(table_witness)) => {
+				table_witness.append_events(allocator, filler, events)?;
+				self.notify_filled_columns(table_id, None);
+				Ok(())
+			}
+			Some(Either::Left(_)) => self.fill_table_sequential(filler, events),
 			None => Err(Error::MissingTable { table_id }),
 		}
 	}
 
+	/// Checks that every row of every committed column of the filled tables was written.
+	///
+	/// See [`TableWitnessIndex::check_committed_columns_written`].
+	pub fn check_committed_columns_written(&self) -> Result<(), Error> {
+		for table_witness in &self.tables {
+			if let Either::Right(table_witness) = table_witness {
+				table_witness.check_committed_columns_written()?;
+			}
+		}
+		Ok(())
+	}
+
+	/// Rebinds the witness to another constraint system with the same table layout.
+	///
+	/// The column storage of the filled tables is kept, so a witness for a sequence of related
+	/// statements is allocated once, and each statement refills the tables of the retargeted index
+	/// in place, see [`Self::fill_table_sequential`]. The tables that are not filled yet stay
+	/// unfilled. The new constraint system must be compiled before the retargeted witness is
+	/// converted with [`Self::into_multilinear_extension_index`].
+	///
+	/// The constraint systems are compatible if they have the same number of tables, and each pair
+	/// of tables has the same size specification, number of blinding rows, and columns, where the
+	/// columns at the same index have the same shape and kind of definition. The names and the
+	/// constraints of the tables may differ.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::IncorrectNumberOfTables`] if the constraint systems have different numbers of
+	///   tables.
+	/// * [`Error::IncompatibleTable`] if a table of `cs` has a different layout.
+	pub fn retarget<'cs2>(
+		self,
+		cs: &'cs2 ConstraintSystem<F>,
+	) -> Result<WitnessIndex<'cs2, 'alloc, P>, Error> {
+		if cs.tables.len() != self.tables.len() {
+			return Err(Error::IncorrectNumberOfTables {
+				expected: self.tables.len(),
+				actual: cs.tables.len(),
+			});
+		}
+		for (old, new) in self.tables.iter().zip(&cs.tables) {
+			let old = match old {
+				Either::Left(table) => table,
+				Either::Right(table_witness) => table_witness.table,
+			};
+			check_table_layout(old, new)?;
+		}
+
+		let tables = self
+			.tables
+			.into_iter()
+			.zip(&cs.tables)
+			.map(|(entry, table)| match entry {
+				Either::Left(_) => Either::Left(table),
+				Either::Right(table_witness) => Either::Right(TableWitnessIndex {
+					table,
+					cols: table_witness.cols,
+					size: table_witness.size,
+					log_capacity: table_witness.log_capacity,
+					min_log_segment_size: table_witness.min_log_segment_size,
+					fill_observer: table_witness.fill_observer,
+					written: table_witness.written,
+					next_phase: table_witness.next_phase,
+				}),
+			})
+			.collect();
+		Ok(WitnessIndex {
+			cs,
+			allocator: self.allocator,
+			table_allocators: self.table_allocators,
+			tables,
+			fill_observer: self.fill_observer,
+			commit_observer: self.commit_observer,
+		})
+	}
+
 	/// Returns the sizes of all tables in the witness, indexed by table ID.
 	pub fn table_sizes(&self) -> Vec<usize> {
 		self.tables
 			.iter()
 			.map(|entry| match entry {
 				Either::Left(_) => 0,
-				Either::Right(index) => index.size(),
+				Either::Right(index) => index.size() + index.table().blinding_rows(),
 			})
 			.collect()
 	}
@@ -257,6 +611,19 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> WitnessIndex<'cs, '
 	}
 }
 
+impl<'cs, 'alloc> WitnessIndex<'cs, 'alloc, OptimalPackedB128> {
+	/// Creates and allocates the witness index with the optimal packing width for the target CPU.
+	///
+	/// See [`OptimalPackedB128`]. The logical column values do not depend on the packing width, so
+	/// a witness filled with this index is the same as one filled with any other packing.
+	pub fn with_optimal_packing(
+		cs: &'cs ConstraintSystem<B128>,
+		allocator: &'alloc HostBumpAllocator<'alloc, OptimalPackedB128>,
+	) -> Self {
+		Self::new(cs, allocator)
+	}
+}
+
 impl<'cs, 'alloc, P> WitnessIndex<'cs, 'alloc, P>
 where
 	P: PackedField<Scalar: TowerField>
@@ -267,6 +634,32 @@ where
 		+ PackedExtension<B64>
 		+ PackedExtension<B128>,
 {
+	/// Exports the witness of a table in columnar form, for analysis with external data tools.
+	///
+	/// See [`TableWitnessIndex::to_columnar`]. A table without a witness has no rows, so its
+	/// column arrays are empty.
+	pub fn to_columnar(&mut self, table_id: TableId) -> Result<ColumnBatch, Error> {
+		match self.tables.get_mut(table_id) {
+			Some(Either::Right(table_witness)) => table_witness.to_columnar(),
+			Some(Either::Left(table)) => Ok(ColumnBatch {
+				table_id,
+				n_events: 0,
+				n_rows: 0,
+				columns: table
+					.columns
+					.iter()
+					.map(|col| ColumnArray {
+						id: col.id,
+						name: col.name.clone(),
+						values_per_row: 1 << col.shape.log_values_per_row,
+						values: ColumnValues::with_capacity(col.shape.tower_height, 0),
+					})
+					.collect(),
+			}),
+			None => Err(Error::MissingTable { table_id }),
+		}
+	}
+
 	/// Automatically populate the witness data for all the constant columns in all the tables with
 	/// a [`TableWitnessIndex<P>`].
 	pub fn fill_constant_cols(&mut self) -> Result<(), Error> {
@@ -291,6 +684,266 @@ where
 		}
 		Ok(())
 	}
+
+	/// Automatically populate the witness data for all the structured columns in all the tables
+	/// with a [`TableWitnessIndex<P>`].
+	///
+	/// The values are computed from the columns' specifications, see
+	/// [`TableWitnessSegment::get_structured_values`]. This is needed when the constraint system
+	/// commits the structured columns, see [`ConstraintSystem::structured_as_committed`].
+	pub fn fill_structured_cols(&mut self) -> Result<(), Error> {
+		for table in self.tables.iter_mut() {
+			let Either::Right(table_witness_index) = table.as_mut() else {
+				continue;
+			};
+			let table = table_witness_index.table();
+			let capacity = table_witness_index.capacity();
+			let segment = table_witness_index.full_segment();
+			for col in table.columns.iter() {
+				let ColumnDef::StructuredDynSize(structured) = &col.col else {
+					continue;
+				};
+				let n_vars = structured.max_size_log();
+				let expr = structured.expr().map_err(|err| err.for_column(&col.name))?;
+				let expr = ArithCircuit::from(&expr);
+				let mut witness_data = segment.get_dyn_mut(col.id)?;
+				let len = witness_data.size().min(capacity);
+				for i in 0..len {
+					witness_data.set(i, eval_structured_at_index(&expr, n_vars, i))?;
+				}
+			}
+		}
+		Ok(())
+	}
+
+	/// Evaluates every zero constraint on every row of the witness and reports the first row at
+	/// which each violated constraint does not hold.
+	///
+	/// The blinding rows of tables, see
+	/// [`TableBuilder::add_blinding_rows`](super::TableBuilder::add_blinding_rows), are skipped.
+	///
+	/// This is slower than validating the compiled constraint system, but the failures refer to
+	/// the table columns by name and carry their values at the failing row, which makes them
+	/// useful for debugging a witness.
+	///
+	/// The constraints are evaluated over packed blocks of values, in parallel across the blocks.
+	/// The failures are the same as those of [`Self::zero_constraint_failures_scalar`].
+	pub fn zero_constraint_failures(
+		&mut self,
+	) -> Result<Vec<ZeroConstraintFailure<P::Scalar>>, Error> {
+		// The number of packed elements evaluated at once by each thread.
+		const LOG_BLOCK_SIZE: usize = 8;
+
+		let mut failures = Vec::new();
+		for table_witness in self.tables.iter_mut() {
+			let Either::Right(table_witness) = table_witness else {
+				continue;
+			};
+			if table_witness.size() == 0 {
+				continue;
+			}
+			let table = table_witness.table();
+			let size = table_witness.size();
+			let segment = table_witness.full_segment();
+			for partition in table.partitions.values() {
+				if partition.zero_constraints.is_empty() {
+					continue;
+				}
+				let cols = partition
+					.columns
+					.iter()
+					.map(|&col_id| segment.get_dyn(col_id))
+					.collect::<Result<Vec<_>, _>>()?;
+				let n_values = segment.size() * partition.values_per_row;
+				let blinding_rows = size..size + table.blinding_rows();
+
+				// The columns as multilinears over the largest field, so that constraints over
+				// columns of different fields evaluate in the same packing. Each block is upcast
+				// from the packed column data only when it is evaluated.
+				let col_data = partition
+					.columns
+					.iter()
+					.map(|&col_id| segment.borrow_col_data(col_id))
+					.collect::<Result<Vec<_>, _>>()?;
+				let mles = partition
+					.columns
+					.iter()
+					.zip(&col_data)
+					.map(|(&col_id, data)| {
+						Self::mk_column_witness(segment.log_size, table[col_id].shape, data)
+					})
+					.collect::<Vec<_>>();
+				let n_vars = segment.log_size + partition.values_per_row.ilog2() as usize;
+				let subcube_vars = n_vars.min(LOG_BLOCK_SIZE + P::LOG_WIDTH);
+				let block_size = 1 << subcube_vars.saturating_sub(P::LOG_WIDTH);
+
+				for constraint in &partition.zero_constraints {
+					let composition = ArithCircuitPoly::new(constraint.expr.clone());
+					let first_failure = (0..1 << (n_vars - subcube_vars))
+						.into_par_iter()
+						.find_map_first(|block| {
+							let start = block * block_size;
+							let rows = match mles
+								.iter()
+								.map(|mle| {
+									let mut evals = vec![P::zero(); block_size];
+									mle.subcube_evals(subcube_vars, block, 0, &mut evals)
+										.map(|()| evals)
+								})
+								.collect::<Result<Vec<_>, _>>()
+							{
+								Ok(rows) => rows,
+								Err(err) => return Some(Err(err)),
+							};
+							let rows = rows.iter().map(Vec::as_slice).collect::<Vec<_>>();
+							let mut evals = vec![P::zero(); block_size];
+							if let Err(err) = composition
+								.batch_evaluate(&RowsBatchRef::new(&rows, block_size), &mut evals)
+							{
+								return Some(Err(err));
+							}
+							evals
+								.iter()
+								.flat_map(|eval| eval.iter())
+								.enumerate()
+								.map(|(j, eval)| (start * P::WIDTH + j, eval))
+								.take_while(|&(i, _)| i < n_values)
+								.find(|&(i, eval)| {
+									eval != <P::Scalar as binius_field::Field>::ZERO
+										&& !blinding_rows.contains(&(i / partition.values_per_row))
+								})
+								.map(|(i, _)| Ok(i))
+						})
+						.transpose()?;
+					if let Some(i) = first_failure {
+						failures
+							.push(zero_constraint_failure(table, partition, constraint, &cols, i)?);
+					}
+				}
+			}
+		}
+		Ok(failures)
+	}
+
+	/// Evaluates every zero constraint on every row of the witness, one value at a time.
+	///
+	/// This is the reference implementation of [`Self::zero_constraint_failures`], which returns
+	/// the same failures.
+	pub fn zero_constraint_failures_scalar(
+		&mut self,
+	) -> Result<Vec<ZeroConstraintFailure<P::Scalar>>, Error> {
+		let mut failures = Vec::new();
+		for table_witness in self.tables.iter_mut() {
+			let Either::Right(table_witness) = table_witness else {
+				continue;
+			};
+			if table_witness.size() == 0 {
+				continue;
+			}
+			let table = table_witness.table();
+			let size = table_witness.size();
+			let segment = table_witness.full_segment();
+			for partition in table.partitions.values() {
+				if partition.zero_constraints.is_empty() {
+					continue;
+				}
+				let cols = partition
+					.columns
+					.iter()
+					.map(|&col_id| segment.get_dyn(col_id))
+					.collect::<Result<Vec<_>, _>>()?;
+				let n_values = segment.size() * partition.values_per_row;
+				let blinding_rows = size..size + table.blinding_rows();
+				for constraint in &partition.zero_constraints {
+					for i in 0..n_values {
+						if blinding_rows.contains(&(i / partition.values_per_row)) {
+							continue;
+						}
+						let query = cols.iter().map(|col| col.get(i)).collect::<Vec<_>>();
+						let result = constraint.expr.evaluate(&query)?;
+						if result == <P::Scalar as binius_field::Field>::ZERO {
+							continue;
+						}
+						failures
+							.push(zero_constraint_failure(table, partition, constraint, &cols, i)?);
+						break;
+					}
+				}
+			}
+		}
+		Ok(failures)
+	}
+}
+
+/// Describes the failure of a zero constraint at the value with index `i` of a partition.
+fn zero_constraint_failure<F: TowerField>(
+	table: &Table<F>,
+	partition: &TablePartition<F>,
+	constraint: &ZeroConstraint<F>,
+	cols: &[Box<dyn WitnessColView<F> + '_>],
+	i: usize,
+) -> Result<ZeroConstraintFailure<F>, Error> {
+	let query = cols.iter().map(|col| col.get(i)).collect::<Vec<_>>();
+	let result = constraint.expr.evaluate(&query)?;
+	let names = partition
+		.columns
+		.iter()
+		.map(|&col_id| table[col_id].name.clone())
+		.collect::<Vec<_>>();
+	let value_strings = query
+		.iter()
+		.map(|value| value.to_string())
+		.collect::<Vec<_>>();
+	let values = izip!(constraint.expr.vars_usage(), names, query)
+		.filter_map(|(used, name, value)| used.then_some((name, value)))
+		.collect();
+	Ok(ZeroConstraintFailure {
+		table_name: table.name.clone(),
+		constraint_name: constraint.name.clone(),
+		row: i / partition.values_per_row,
+		value_index: i % partition.values_per_row,
+		values,
+		substituted_expr: ArithExprNamedVars(&constraint.expr, &value_strings).to_string(),
+		result,
+	})
+}
+
+/// A zero constraint that does not hold at a row of a table witness.
+///
+/// This is returned by [`WitnessIndex::zero_constraint_failures`].
+#[derive(Debug, Clone)]
+pub struct ZeroConstraintFailure<F: TowerField> {
+	pub table_name: String,
+	pub constraint_name: String,
+	/// The row of the table at which the constraint is violated.
+	pub row: usize,
+	/// The index of the violating value within the row, for columns with multiple values per row.
+	pub value_index: usize,
+	/// The names and values at the failing row of the columns referenced by the constraint.
+	pub values: Vec<(String, F)>,
+	/// The constraint expression with every column replaced by its value at the failing row.
+	pub substituted_expr: String,
+	/// The value of the constraint expression at the failing row.
+	pub result: F,
+}
+
+impl<F: TowerField> std::fmt::Display for ZeroConstraintFailure<F> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		writeln!(
+			f,
+			"zero constraint {} in table {} is violated at row {}[{}]: {} = {}",
+			self.constraint_name,
+			self.table_name,
+			self.row,
+			self.value_index,
+			self.substituted_expr,
+			self.result
+		)?;
+		for (name, value) in &self.values {
+			writeln!(f, "    {name} = {value}")?;
+		}
+		Ok(())
+	}
 }
 
 fn multilin_poly_from_underlier_data<P>(
@@ -331,6 +984,62 @@ where
 	}
 }
 
+/// Checks that the witness of table `old` can hold the witness of table `new`.
+///
+/// See [`WitnessIndex::retarget`].
+fn check_table_layout<F: TowerField>(old: &Table<F>, new: &Table<F>) -> Result<(), Error> {
+	let incompatible = |reason: String| Error::IncompatibleTable {
+		table_id: new.id(),
+		reason,
+	};
+	let same_size_spec = match (old.size_spec(), new.size_spec()) {
+		(TableSizeSpec::Arbitrary, TableSizeSpec::Arbitrary)
+		| (TableSizeSpec::PowerOfTwo, TableSizeSpec::PowerOfTwo) => true,
+		(TableSizeSpec::Fixed { log_size: old }, TableSizeSpec::Fixed { log_size: new }) => {
+			old == new
+		}
+		_ => false,
+	};
+	if !same_size_spec {
+		return Err(incompatible(format!(
+			"the size specification is {:?} instead of {:?}",
+			new.size_spec(),
+			old.size_spec()
+		)));
+	}
+	if old.blinding_rows() != new.blinding_rows() {
+		return Err(incompatible(format!(
+			"the table has {} blinding rows instead of {}",
+			new.blinding_rows(),
+			old.blinding_rows()
+		)));
+	}
+	if old.columns.len() != new.columns.len() {
+		return Err(incompatible(format!(
+			"the table has {} columns instead of {}",
+			new.columns.len(),
+			old.columns.len()
+		)));
+	}
+	for (old_col, new_col) in old.columns.iter().zip(&new.columns) {
+		if old_col.shape.tower_height != new_col.shape.tower_height
+			|| old_col.shape.log_values_per_row != new_col.shape.log_values_per_row
+		{
+			return Err(incompatible(format!(
+				"column {:?} has shape {:?} instead of {:?}",
+				new_col.name, new_col.shape, old_col.shape
+			)));
+		}
+		if mem::discriminant(&old_col.col) != mem::discriminant(&new_col.col) {
+			return Err(incompatible(format!(
+				"column {:?} has a different kind of definition than {:?}",
+				new_col.name, old_col.name
+			)));
+		}
+	}
+	Ok(())
+}
+
 /// Holds witness column data for a table, indexed by column index.
 #[derive(Debug, CopyGetters)]
 pub struct TableWitnessIndex<'cs, 'alloc, P = PackedType<OptimalUnderlier, B128>>
@@ -352,6 +1061,10 @@ where
 	/// iteration. It is the maximum number of logical rows occupied by a single underlier.
 	#[get_copy = "pub"]
 	min_log_segment_size: usize,
+	fill_observer: Option<Arc<dyn FillObserver>>,
+	written: WrittenRows,
+	/// The next phase to fill with [`WitnessIndex::fill_table_phase`].
+	next_phase: usize,
 }
 
 #[derive(Debug)]
@@ -371,17 +1084,78 @@ enum WitnessColumnInfo<T> {
 type WitnessDataMut<'a, P> = WitnessColumnInfo<&'a mut [P]>;
 
 impl<'a, P: PackedField> WitnessDataMut<'a, P> {
-	pub fn new_owned(allocator: &'a HostBumpAllocator<'a, P>, log_underlier_count: usize) -> Self {
-		let slice = allocator
-			.alloc(1 << log_underlier_count)
-			.expect("failed to allocate witness data slice");
-
-		Self::Owned(slice)
+	pub fn new_owned(
+		allocator: &'a HostBumpAllocator<'a, P>,
+		log_underlier_count: usize,
+	) -> Result<Self, AllocError> {
+		let slice = allocator.alloc(1 << log_underlier_count)?;
+		Ok(Self::Owned(slice))
 	}
 }
 
 type RefCellData<'a, P> = WitnessColumnInfo<RefCell<&'a mut [P]>>;
 
+/// The rows of each column of a table witness on which the column was borrowed mutably.
+///
+/// This detects committed columns that a filler forgot to populate, whose values are whatever was
+/// in the allocated memory. A column counts as written on all the rows of a segment once it is
+/// borrowed mutably from the segment. The rows are only recorded in debug builds.
+#[derive(Debug)]
+struct WrittenRows {
+	#[cfg(debug_assertions)]
+	cols: Vec<std::sync::Mutex<Vec<std::ops::Range<usize>>>>,
+}
+
+impl WrittenRows {
+	#[cfg_attr(not(debug_assertions), allow(unused_variables))]
+	fn new(n_cols: usize) -> Self {
+		Self {
+			#[cfg(debug_assertions)]
+			cols: iter::repeat_with(Default::default).take(n_cols).collect(),
+		}
+	}
+
+	/// Records that the column at `index` was written on the segment of rows `rows`.
+	#[cfg_attr(not(debug_assertions), allow(unused_variables))]
+	fn mark(&self, index: usize, rows: std::ops::Range<usize>) {
+		#[cfg(debug_assertions)]
+		{
+			let mut ranges = self.cols[index]
+				.lock()
+				.expect("no thread panics while holding the lock");
+			// A filler usually borrows the same column several times per segment.
+			if ranges.last() != Some(&rows) {
+				ranges.push(rows);
+			}
+		}
+	}
+
+	/// Returns the first of the rows `0..n_rows` on which the column at `index` was not written.
+	///
+	/// This is always `None` in release builds.
+	#[cfg_attr(not(debug_assertions), allow(unused_variables))]
+	fn first_unwritten(&self, index: usize, n_rows: usize) -> Option<usize> {
+		#[cfg(debug_assertions)]
+		{
+			let mut ranges = self.cols[index]
+				.lock()
+				.expect("no thread panics while holding the lock")
+				.clone();
+			ranges.sort_by_key(|range| range.start);
+			let mut next = 0;
+			for range in ranges {
+				if range.start > next {
+					break;
+				}
+				next = next.max(range.end);
+			}
+			(next < n_rows).then_some(next)
+		}
+		#[cfg(not(debug_assertions))]
+		None
+	}
+}
+
 #[derive(Debug)]
 struct ImmutableWitnessIndexColumn<'a, P: PackedField> {
 	shape: ColumnShape,
@@ -420,7 +1194,17 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> TableWitnessIndex<'
 			});
 		}
 
-		let log_capacity = table::log_capacity(size);
+		let log_capacity = table::log_capacity(size + table.blinding_rows());
+		Self::with_log_capacity(allocator, table, size, log_capacity)
+	}
+
+	/// Creates an index for `size` events with `1 << log_capacity` rows.
+	fn with_log_capacity(
+		allocator: &'alloc HostBumpAllocator<'alloc, P>,
+		table: &'cs Table<F>,
+		size: usize,
+		log_capacity: usize,
+	) -> Result<Self, Error> {
 		let packed_elem_log_bits = P::LOG_WIDTH + F::TOWER_LEVEL;
 
 		let mut cols = Vec::with_capacity(table.columns.len());
@@ -434,6 +1218,10 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> TableWitnessIndex<'
 					allocator,
 					(shape.log_cell_size() + log_capacity).saturating_sub(packed_elem_log_bits),
 				)
+				.map_err(|source| Error::Alloc {
+					table_id: table.id(),
+					source,
+				})?
 			};
 			cols.push(WitnessIndexColumn {
 				shape: *shape,
@@ -442,29 +1230,25 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> TableWitnessIndex<'
 			});
 		}
 
-		// The minimum segment size is chosen such that the segment of each column is at least one
-		// underlier in size.
-		let min_log_segment_size = packed_elem_log_bits
-			- table
-				.columns
-				.iter()
-				.map(|col| col.shape.log_cell_size())
-				.fold(packed_elem_log_bits, |a, b| a.min(b));
-
-		// But, in case the minimum segment size is larger than the capacity, we lower it so the
-		// caller can get the full witness index in one segment. This is OK because the extra field
-		// elements in the smallest columns are just padding.
-		let min_log_segment_size = min_log_segment_size.min(log_capacity);
-
 		Ok(Self {
 			table,
+			written: WrittenRows::new(cols.len()),
 			cols,
 			size,
 			log_capacity,
-			min_log_segment_size,
+			min_log_segment_size: min_log_segment_size::<P>(table, log_capacity),
+			fill_observer: None,
+			next_phase: 0,
 		})
 	}
 
+	/// Sets an observer that is notified about the progress of the sequential fills of this table.
+	///
+	/// See [`FillObserver`].
+	pub fn set_fill_observer(&mut self, observer: Arc<dyn FillObserver>) {
+		self.fill_observer = Some(observer);
+	}
+
 	pub fn table_id(&self) -> TableId {
 		self.table.id
 	}
@@ -473,8 +1257,35 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> TableWitnessIndex<'
 		1 << self.log_capacity
 	}
 
+	/// Returns the number of events the table was initialized with.
+	///
+	/// This excludes the blinding rows and the padding rows up to [`Self::padded_size`].
+	pub fn logical_size(&self) -> usize {
+		self.size
+	}
+
+	/// Returns the number of rows allocated for the table, which is the number of events plus the
+	/// blinding rows, rounded up to a power of two.
+	///
+	/// The rows from [`Self::logical_size`] on do not hold events. The sequential fills clone the
+	/// last event to complete its segment and copy that segment into the segments after it, so
+	/// every column, including the structured columns written by the table filler, repeats the
+	/// values of that segment there rather than taking the values of its multilinear extension.
+	/// [`Self::fill_blinding_rows`] then overwrites the blinding rows, which follow the events.
+	/// [`WitnessIndex::fill_structured_cols`] writes the values of the extension to every row of
+	/// the structured columns.
+	pub fn padded_size(&self) -> usize {
+		self.capacity()
+	}
+
 	/// Returns a witness index segment covering the entire table.
 	pub fn full_segment(&mut self) -> TableWitnessSegment<P> {
+		self.segment_at(0)
+	}
+
+	/// Returns a segment covering the entire index, as the segment at `index` of a larger table
+	/// with segments of the size of this index.
+	fn segment_at(&mut self, index: usize) -> TableWitnessSegment<P> {
 		let cols = self
 			.cols
 			.iter_mut()
@@ -486,23 +1297,107 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> TableWitnessIndex<'
 		TableWitnessSegment {
 			table: self.table,
 			cols,
+			written: &self.written,
 			log_size: self.log_capacity,
-			index: 0,
+			index,
 		}
 	}
 
-	/// Fill a full table witness index using the given row data.
+	/// Checks that every row of every committed column was written.
 	///
-	/// This function iterates through witness segments sequentially in a single thread.
-	pub fn fill_sequential<T: TableFiller<P>>(
-		&mut self,
-		table: &T,
-		rows: &[T::Event],
-	) -> Result<(), Error> {
-		let log_size = self.optimal_segment_size_heuristic();
+	/// A column counts as written on the rows of a segment once it has been borrowed mutably from
+	/// the segment, e.g. with [`TableWitnessSegment::get_mut`]. Structured and derived columns are
+	/// computed from their definitions and are not checked. The writes are only tracked in debug
+	/// builds, so in release builds this always succeeds.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::UnwrittenColumn`] with the first unwritten row of the first unwritten column.
+	pub fn check_committed_columns_written(&self) -> Result<(), Error> {
+		for (index, col) in self.table.columns.iter().enumerate() {
+			if !matches!(col.col, ColumnDef::Committed { .. }) {
+				continue;
+			}
+			if let Some(row) = self.written.first_unwritten(index, self.capacity()) {
+				return Err(Error::UnwrittenColumn {
+					table_id: self.table.id(),
+					column: col.name.clone(),
+					row,
+				});
+			}
+		}
+		Ok(())
+	}
+
+	/// Overwrites the values of one column of a filled table, leaving the other columns untouched.
+	///
+	/// This is for columns that depend on challenges sampled after the table was filled, so that
+	/// the table needn't be filled again. `values` must have one value per row of the padded table,
+	/// or `V` values per row for a column with `V` values per row.
+	pub fn update_column<FSub, const V: usize>(
+		&mut self,
+		col: Col<FSub, V>,
+		values: &[FSub],
+	) -> Result<(), Error>
+	where
+		FSub: TowerField,
+		F: ExtensionField<FSub>,
+		P: PackedExtension<FSub>,
+	{
+		let expected = self.capacity() * V;
+		if values.len() != expected {
+			return Err(Error::IncorrectColumnLength {
+				column: col.id(),
+				expected,
+				actual: values.len(),
+			});
+		}
+
+		let segment = self.full_segment();
+		let mut data = segment.get_mut(col)?;
+		for (i, &value) in values.iter().enumerate() {
+			set_packed_slice(&mut data, i, value);
+		}
+		Ok(())
+	}
+
+	/// Fill a full table witness index using the given row data.
+	///
+	/// This function iterates through witness segments sequentially in a single thread.
+	pub fn fill_sequential<T: TableFiller<P>>(
+		&mut self,
+		table: &T,
+		rows: &[T::Event],
+	) -> Result<(), Error> {
+		let log_size = self.optimal_segment_size_heuristic();
 		self.fill_sequential_with_segment_size(table, rows, log_size)
 	}
 
+	/// Fill a full table witness index with events taken lazily from an iterator.
+	///
+	/// This function iterates through witness segments sequentially in a single thread, like
+	/// [`Self::fill_sequential`]. The events of each segment are collected from the iterator right
+	/// before the segment is filled, so the whole sequence of events is never materialized.
+	pub fn fill_sequential_from_iter<T, I>(&mut self, table: &T, events: I) -> Result<(), Error>
+	where
+		T: TableFiller<P>,
+		I: IntoIterator<Item = T::Event>,
+		I::IntoIter: ExactSizeIterator,
+	{
+		let mut events = events.into_iter();
+		let n_rows = events.len();
+		let log_size = self.optimal_segment_size_heuristic();
+		self.fill_observed(|index, observer| {
+			index.fill_segments_sequential(
+				table,
+				n_rows,
+				|len| Cow::Owned(events.by_ref().take(len).collect()),
+				log_size,
+				observer,
+			)
+		})
+	}
+
 	/// Fill a full table witness index using the given row data.
 	///
 	/// This function iterates through witness segments in parallel in multiple threads.
@@ -515,6 +1410,60 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> TableWitnessIndex<'
 		self.fill_parallel_with_segment_size(table, rows, log_size)
 	}
 
+	/// Fill a full table witness index using the given row data, with segments of the given size.
+	///
+	/// This function iterates through witness segments sequentially in a single thread.
+	pub fn fill_sequential_with<T: TableFiller<P>>(
+		&mut self,
+		table: &T,
+		rows: &[T::Event],
+		segment_size: SegmentSize,
+	) -> Result<(), Error> {
+		let log_size = self.log_segment_size(segment_size);
+		self.fill_sequential_with_segment_size(table, rows, log_size)
+	}
+
+	/// Fill a full table witness index using the given row data, with segments of the given size.
+	///
+	/// This function iterates through witness segments in parallel in multiple threads.
+	pub fn fill_parallel_with<T>(
+		&mut self,
+		table: &T,
+		rows: &[T::Event],
+		segment_size: SegmentSize,
+	) -> Result<(), Error>
+	where
+		T: TableFiller<P> + Sync,
+		T::Event: Sync,
+	{
+		let log_size = self.log_segment_size(segment_size);
+		self.fill_parallel_with_segment_size(table, rows, log_size)
+	}
+
+	/// Returns the binary logarithm of the number of rows of the segments of the given size.
+	///
+	/// The size is clamped like in [`Self::segments`], so a segment of every column is at least
+	/// one packed element.
+	pub fn log_segment_size(&self, segment_size: SegmentSize) -> usize {
+		let log_size = match segment_size {
+			SegmentSize::Fixed { log_size } => log_size,
+			SegmentSize::Auto { cache_bytes } => {
+				let row_bits = self
+					.cols
+					.iter()
+					.filter(|col| matches!(col.data, WitnessDataMut::Owned(_)))
+					.map(|col| 1 << col.shape.log_cell_size())
+					.sum::<usize>();
+				match (cache_bytes * 8).checked_div(row_bits) {
+					Some(0) => 0,
+					Some(max_rows) => max_rows.ilog2() as usize,
+					None => self.log_capacity,
+				}
+			}
+		};
+		log_size.clamp(self.min_log_segment_size, self.log_capacity)
+	}
+
 	fn optimal_segment_size_heuristic(&self) -> usize {
 		// As a heuristic, choose log_size so that the median column segment size is 4 KiB.
 		const TARGET_SEGMENT_LOG_BITS: usize = 12 + 3;
@@ -540,6 +1489,43 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> TableWitnessIndex<'
 		table: &T,
 		rows: &[T::Event],
 		log_size: usize,
+	) -> Result<(), Error> {
+		let mut rest = rows;
+		self.fill_observed(|index, observer| {
+			index.fill_segments_sequential(
+				table,
+				rows.len(),
+				|len| {
+					let (chunk, tail) = rest.split_at(len.min(rest.len()));
+					rest = tail;
+					Cow::Borrowed(chunk)
+				},
+				log_size,
+				observer,
+			)
+		})
+	}
+
+	/// Fill a full table witness index using the given row data, with explicit segment
+	/// boundaries.
+	///
+	/// Unlike [`Self::fill_sequential_with_segment_size`], the segments may have different
+	/// sizes, e.g. to align them with the structure of the events. The segments are filled
+	/// sequentially in order. They must be consecutive ranges of rows that start at zero and cover
+	/// the whole [`Self::capacity`]. Each segment must have a power of two length that is at
+	/// least `1 << self.min_log_segment_size()`, and start at a multiple of its length, so e.g.
+	/// `[0..4, 4..8, 8..16, 16..32]` is valid for a table with capacity 32. The segments past the
+	/// last event are filled with copies of the last event.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::InvalidSegment`] if a segment has an invalid position or length.
+	/// * [`Error::IncompleteSegments`] if the segments do not cover the whole table.
+	pub fn fill_sequential_with_segments<T: TableFiller<P>>(
+		&mut self,
+		table: &T,
+		rows: &[T::Event],
+		segments: &[Range<usize>],
 	) -> Result<(), Error> {
 		if rows.len() != self.size {
 			return Err(Error::IncorrectNumberOfTableEvents {
@@ -548,16 +1534,267 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> TableWitnessIndex<'
 			});
 		}
 
+		let min_len = 1 << self.min_log_segment_size;
+		let capacity = self.capacity();
+		let mut covered = 0;
+		for segment in segments {
+			let len = segment.len();
+			if segment.start != covered
+				|| !len.is_power_of_two()
+				|| len < min_len
+				|| len > capacity
+				|| segment.start % len != 0
+			{
+				return Err(Error::InvalidSegment {
+					segment: segment.clone(),
+					min_len,
+				});
+			}
+			covered = segment.end;
+		}
+		if covered != capacity {
+			return Err(Error::IncompleteSegments { covered, capacity });
+		}
+
+		let last_event = rows
+			.last()
+			.expect("rows is non-empty because self.size is non-zero");
+		self.fill_observed(|index, observer| {
+			for segment in segments {
+				let mut row_chunk = Cow::Borrowed(
+					&rows[segment.start.min(rows.len())..segment.end.min(rows.len())],
+				);
+				if row_chunk.len() != segment.len() {
+					row_chunk.to_mut().resize(segment.len(), last_event.clone());
+				}
+				index.fill_segment(table, segment.clone(), &row_chunk, observer)?;
+			}
+			Ok(())
+		})
+	}
+
+	/// Fills the segment of rows `segment` with the events `row_chunk`, one per row.
+	///
+	/// The segment must have a power of two length of at least the minimum segment size, and
+	/// start at a multiple of its length.
+	fn fill_segment<T: TableFiller<P>>(
+		&mut self,
+		table: &T,
+		segment: Range<usize>,
+		row_chunk: &[T::Event],
+		observer: Option<&dyn FillObserver>,
+	) -> Result<(), Error> {
+		let table_id = self.table.id();
+		let log_size = segment.len().ilog2() as usize;
 		let mut segmented_view = TableWitnessSegmentedView::new(self, log_size);
+		let (_, mut rest) = segmented_view.split_at(segment.start >> log_size);
+		let (view, _) = rest.split_at(1);
+		let mut witness_segment = view
+			.into_iter()
+			.next()
+			.expect("split_at called with 1 must return a view with exactly one segment");
+		observe_segment(observer, table_id, witness_segment.index(), || {
+			table
+				.fill(row_chunk, &mut witness_segment)
+				.map_err(Error::TableFill)
+		})
+	}
+
+	/// Grows the table by the events `events` and fills the rows of the new events.
+	///
+	/// See [`WitnessIndex::append_events`].
+	fn append_events<T: TableFiller<P>>(
+		&mut self,
+		allocator: &'alloc HostBumpAllocator<'alloc, P>,
+		table: &T,
+		events: &[T::Event],
+	) -> Result<(), Error>
+	where
+		P: PackedExtension<B1>,
+	{
+		let Some(last_event) = events.last() else {
+			return Ok(());
+		};
+		let old_size = self.size;
+		let log_capacity =
+			table::log_capacity(old_size + events.len() + self.table.blinding_rows());
+		if log_capacity > self.log_capacity {
+			self.grow(allocator, log_capacity)?;
+		}
+		self.size += events.len();
+
+		// The filler fills whole segments, so the filled rows start at the beginning of the
+		// smallest segment that holds the first new row. The old rows of that segment are filled
+		// with copies of the first new event, and restored afterwards.
+		let min_segment_size = 1 << self.min_log_segment_size;
+		let start = old_size / min_segment_size * min_segment_size;
+		let old_rows = self.read_row_bits(start..old_size);
+		let rows = iter::repeat_n(&events[0], old_size - start)
+			.chain(events)
+			.cloned()
+			.collect::<Vec<_>>();
+		let capacity = self.capacity();
+		self.fill_observed(|index, observer| {
+			let mut segment_start = start;
+			while segment_start < capacity {
+				// The longest segment that starts at a multiple of its length.
+				let mut len = if segment_start == 0 {
+					capacity
+				} else {
+					1 << segment_start.trailing_zeros()
+				};
+				while segment_start + len > capacity {
+					len /= 2;
+				}
+				let segment = segment_start..segment_start + len;
+				let chunk_start = (segment.start - start).min(rows.len());
+				let chunk_end = (segment.end - start).min(rows.len());
+				let mut row_chunk = Cow::Borrowed(&rows[chunk_start..chunk_end]);
+				if row_chunk.len() != len {
+					row_chunk.to_mut().resize(len, last_event.clone());
+				}
+				index.fill_segment(table, segment, &row_chunk, observer)?;
+				segment_start += len;
+			}
+			Ok(())
+		})?;
+		self.write_row_bits(start..old_size, old_rows);
+		Ok(())
+	}
+
+	/// Reallocates the columns of the table with a capacity of `2^log_capacity` rows, keeping the
+	/// values of the current rows.
+	fn grow(
+		&mut self,
+		allocator: &'alloc HostBumpAllocator<'alloc, P>,
+		log_capacity: usize,
+	) -> Result<(), Error> {
+		let packed_elem_log_bits = P::LOG_WIDTH + F::TOWER_LEVEL;
+		for col in &mut self.cols {
+			if let WitnessDataMut::Owned(data) = &mut col.data {
+				let WitnessDataMut::Owned(new_data) = WitnessDataMut::new_owned(
+					allocator,
+					(col.shape.log_cell_size() + log_capacity).saturating_sub(packed_elem_log_bits),
+				)
+				.map_err(|source| Error::Alloc {
+					table_id: self.table.id(),
+					source,
+				})?
+				else {
+					unreachable!("new_owned returns an owned column");
+				};
+				new_data[..data.len()].copy_from_slice(data);
+				*data = new_data;
+			}
+		}
+		self.log_capacity = log_capacity;
+		self.min_log_segment_size = min_log_segment_size::<P>(self.table, log_capacity);
+		Ok(())
+	}
+
+	/// Returns the bits of the cells on the rows `rows` of every owned column.
+	fn read_row_bits(&self, rows: Range<usize>) -> Vec<Vec<B1>>
+	where
+		P: PackedExtension<B1>,
+	{
+		self.cols
+			.iter()
+			.map(|col| match &col.data {
+				WitnessDataMut::Owned(data) => {
+					let bits = P::cast_bases(data);
+					let log_cell_size = col.shape.log_cell_size();
+					((rows.start << log_cell_size)..(rows.end << log_cell_size))
+						.map(|i| get_packed_slice(bits, i))
+						.collect()
+				}
+				WitnessDataMut::SameAsIndex(_) => Vec::new(),
+			})
+			.collect()
+	}
+
+	/// Writes back the bits returned by [`Self::read_row_bits`] for the same rows.
+	fn write_row_bits(&mut self, rows: Range<usize>, row_bits: Vec<Vec<B1>>)
+	where
+		P: PackedExtension<B1>,
+	{
+		for (col, col_bits) in iter::zip(&mut self.cols, row_bits) {
+			if let WitnessDataMut::Owned(data) = &mut col.data {
+				let bits = P::cast_bases_mut(data);
+				let start = rows.start << col.shape.log_cell_size();
+				for (i, bit) in col_bits.into_iter().enumerate() {
+					set_packed_slice(bits, start + i, bit);
+				}
+			}
+		}
+	}
+
+	/// Runs a fill of the table, notifying the fill observer of its start and end.
+	fn fill_observed(
+		&mut self,
+		fill: impl FnOnce(&mut Self, Option<&dyn FillObserver>) -> Result<(), Error>,
+	) -> Result<(), Error> {
+		let observer = self.fill_observer.clone();
+		let table_id = self.table.id();
+		if let Some(observer) = &observer {
+			observer.on_table_fill_start(table_id);
+		}
+		let start = Instant::now();
+		let result = fill(self, observer.as_deref());
+		if let Some(observer) = &observer {
+			observer.on_table_fill_end(table_id, start.elapsed());
+		}
+		result
+	}
+
+	/// Fills the segments of the table sequentially with `n_rows` events.
+	///
+	/// The events are pulled with `next_chunk`, which returns the next `len` events, or fewer if
+	/// the events run out.
+	fn fill_segments_sequential<'r, T: TableFiller<P>>(
+		&mut self,
+		table: &T,
+		n_rows: usize,
+		mut next_chunk: impl FnMut(usize) -> Cow<'r, [T::Event]>,
+		log_chunk_size: usize,
+		observer: Option<&dyn FillObserver>,
+	) -> Result<(), Error>
+	where
+		T::Event: 'r,
+	{
+		let table_id = self.table.id();
+		if n_rows != self.size {
+			return Err(Error::IncorrectNumberOfTableEvents {
+				expected: self.size,
+				actual: n_rows,
+			});
+		}
+		// An iterator may yield fewer events than its length, so every chunk is checked to be full
+		// up to the remaining number of events.
+		let mut n_taken = 0;
+		let mut take_chunk = |len: usize| {
+			let chunk = next_chunk(len);
+			let expected_len = len.min(n_rows - n_taken);
+			n_taken += chunk.len();
+			if chunk.len() == expected_len {
+				Ok(chunk)
+			} else {
+				Err(Error::IncorrectNumberOfTableEvents {
+					expected: n_rows,
+					actual: n_taken,
+				})
+			}
+		};
+
+		let mut segmented_view = TableWitnessSegmentedView::new(self, log_chunk_size);
 
 		// Overwrite log_size because it may need to get clamped.
 		let log_size = segmented_view.log_segment_size;
 		let segment_size = 1 << log_size;
 
-		// rows.len() equals self.size and self.size is check to be non-zero in the constructor
-		debug_assert_ne!(rows.len(), 0);
+		// n_rows equals self.size and self.size is check to be non-zero in the constructor
+		debug_assert_ne!(n_rows, 0);
 		// number of chunks is rounded up
-		let n_chunks = (rows.len() - 1) / segment_size + 1;
+		let n_chunks = (n_rows - 1) / segment_size + 1;
 
 		let (full_chunk_segments, mut rest_segments) = segmented_view.split_at(n_chunks - 1);
 
@@ -565,40 +1802,38 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> TableWitnessIndex<'
 		full_chunk_segments
 			.into_iter()
 			// by taking n_chunks - 1, we guarantee that all row chunks are full
-			.zip(rows.chunks(segment_size).take(n_chunks - 1))
-			.try_for_each(|(mut witness_segment, row_chunk)| {
-				table
-					.fill(row_chunk, &mut witness_segment)
-					.map_err(Error::TableFill)
+			.try_for_each(|mut witness_segment| {
+				let row_chunk = take_chunk(segment_size)?;
+				observe_segment(observer, table_id, witness_segment.index(), || {
+					table
+						.fill(&row_chunk, &mut witness_segment)
+						.map_err(Error::TableFill)
+				})
 			})?;
 
 		// Fill the last segment. There may not be enough events to match the size of the segment,
 		// which is a pre-condition for TableFiller::fill. In that case, we clone the last event to
 		// pad the row_chunk. Since it's a clone, the filled witness should satisfy all row-wise
 		// constraints as long as all the given events do.
-		let row_chunk = &rows[(n_chunks - 1) * segment_size..];
-		let mut padded_row_chunk = Vec::new();
-		let row_chunk = if row_chunk.len() != segment_size {
-			padded_row_chunk.reserve(segment_size);
-			padded_row_chunk.extend_from_slice(row_chunk);
+		let mut row_chunk = take_chunk(segment_size)?;
+		if row_chunk.len() != segment_size {
 			let last_event = row_chunk
 				.last()
 				.expect("row_chunk must be non-empty because of how n_chunk is calculated")
 				.clone();
-			padded_row_chunk.resize(segment_size, last_event);
-			&padded_row_chunk
-		} else {
-			row_chunk
-		};
+			row_chunk.to_mut().resize(segment_size, last_event);
+		}
 
 		let (partial_chunk_segments, rest_segments) = rest_segments.split_at(1);
 		let mut partial_chunk_segment_iter = partial_chunk_segments.into_iter();
 		let mut witness_segment = partial_chunk_segment_iter.next().expect(
 			"segmented_view.split_at called with 1 must return a view with exactly one segment",
 		);
-		table
-			.fill(row_chunk, &mut witness_segment)
-			.map_err(Error::TableFill)?;
+		observe_segment(observer, table_id, witness_segment.index(), || {
+			table
+				.fill(&row_chunk, &mut witness_segment)
+				.map_err(Error::TableFill)
+		})?;
 		assert!(partial_chunk_segment_iter.next().is_none());
 
 		// Finally, copy the last filled segment to the remaining segments. This should satisfy all
@@ -613,12 +1848,15 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> TableWitnessIndex<'
 			.collect::<Vec<_>>();
 
 		rest_segments.into_iter().for_each(|mut segment| {
-			for (dst_col, src_col) in iter::zip(&mut segment.cols, &last_segment_cols) {
-				if let (RefCellData::Owned(dst), WitnessColumnInfo::Owned(src)) = (dst_col, src_col)
-				{
-					dst.get_mut().copy_from_slice(src)
+			observe_segment(observer, table_id, segment.index(), || {
+				for (dst_col, src_col) in iter::zip(&mut segment.cols, &last_segment_cols) {
+					if let (RefCellData::Owned(dst), WitnessColumnInfo::Owned(src)) =
+						(dst_col, src_col)
+					{
+						dst.get_mut().copy_from_slice(src)
+					}
 				}
-			}
+			});
 		});
 
 		Ok(())
@@ -741,53 +1979,363 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> TableWitnessIndex<'
 	}
 }
 
-/// A view over a table witness that splits the table into segments.
-///
-/// The purpose of this struct is to implement the `split_at` method, which safely splits the view
-/// of the table witness vertically. This aids in the implementation of `fill_sequential` and
-/// `fill_parallel`.
-#[derive(Debug)]
-struct TableWitnessSegmentedView<'a, P = PackedType<OptimalUnderlier, B128>>
+impl<'cs, 'alloc, P> TableWitnessIndex<'cs, 'alloc, P>
 where
-	P: PackedField,
-	P::Scalar: TowerField,
+	P: PackedField<Scalar: TowerField>
+		+ PackedExtension<B1>
+		+ PackedExtension<B8>
+		+ PackedExtension<B16>
+		+ PackedExtension<B32>
+		+ PackedExtension<B64>
+		+ PackedExtension<B128>,
 {
-	table: &'a Table<P::Scalar>,
-	cols: Vec<WitnessColumnInfo<(&'a mut [P], usize)>>,
-	log_segment_size: usize,
-	start_index: usize,
-	n_segments: usize,
-}
-
-impl<'a, F: TowerField, P: PackedField<Scalar = F>> TableWitnessSegmentedView<'a, P> {
-	fn new(witness: &'a mut TableWitnessIndex<P>, log_segment_size: usize) -> Self {
-		// Clamp the segment size.
-		let log_segment_size = log_segment_size
-			.min(witness.log_capacity)
-			.max(witness.min_log_segment_size);
+	/// Fills the committed columns on the blinding rows of the table with random non-zero values.
+	///
+	/// The values are sampled from `rng`, which must be a cryptographically secure generator for
+	/// the blinding to hide the witness. The selector columns of the flushes of the table are set
+	/// to zero instead, so that the blinding rows are not flushed. This must be called after the
+	/// event rows are filled, because filling the events pads all the remaining rows of the table,
+	/// including the blinding rows.
+	///
+	/// See [`TableBuilder::add_blinding_rows`](super::TableBuilder::add_blinding_rows).
+	pub fn fill_blinding_rows(&mut self, rng: &mut impl CryptoRng) -> Result<(), Error> {
+		let table = self.table;
+		let start = self.size;
+		let end = start + table.blinding_rows();
+		let selectors = table
+			.partitions
+			.values()
+			.flat_map(|partition| &partition.flushes)
+			.flat_map(|flush| &flush.selectors)
+			.copied()
+			.collect::<BTreeSet<_>>();
+
+		let segment = self.full_segment();
+		for col in &table.columns {
+			if !matches!(col.col, ColumnDef::Committed { .. }) {
+				continue;
+			}
+			let values_per_row = 1 << col.shape.log_values_per_row;
+			let mut data = segment.get_dyn_mut(col.id)?;
+			for i in start * values_per_row..end * values_per_row {
+				let value = if selectors.contains(&col.id) {
+					<P::Scalar as binius_field::Field>::ZERO
+				} else {
+					random_nonzero_subfield_elem(rng, col.shape.tower_height)
+				};
+				data.set(i, value)?;
+			}
+		}
+		Ok(())
+	}
 
-		let cols = witness
-			.cols
-			.iter_mut()
-			.map(|col| match &mut col.data {
-				WitnessColumnInfo::Owned(data) => {
-					let chunk_size = (log_segment_size + col.shape.log_cell_size())
-						.saturating_sub(P::LOG_WIDTH + F::TOWER_LEVEL);
-					WitnessColumnInfo::Owned((&mut **data, 1 << chunk_size))
+	/// Exports the witness of the table in columnar form, for analysis with external data tools.
+	///
+	/// There is one typed array per column, holding the values of all the rows of the table,
+	/// including the padding and blinding rows, as the integer bit patterns of the field elements.
+	/// The `V` values of a cell with several values per row are consecutive in the array. The
+	/// export is meant for offline analysis and is not read back into a witness.
+	pub fn to_columnar(&mut self) -> Result<ColumnBatch, Error> {
+		let table = self.table;
+		let n_rows = self.capacity();
+		let n_events = self.size();
+		let segment = self.full_segment();
+		let columns = table
+			.columns
+			.iter()
+			.map(|col| {
+				let values_per_row = 1 << col.shape.log_values_per_row;
+				let data = segment.get_dyn(col.id)?;
+				let mut values =
+					ColumnValues::with_capacity(col.shape.tower_height, n_rows * values_per_row);
+				for i in 0..n_rows * values_per_row {
+					values.push(subfield_elem_bits(data.get(i), col.shape.tower_height));
 				}
-				WitnessColumnInfo::SameAsIndex(id) => WitnessColumnInfo::SameAsIndex(*id),
+				Ok(ColumnArray {
+					id: col.id,
+					name: col.name.clone(),
+					values_per_row,
+					values,
+				})
 			})
-			.collect::<Vec<_>>();
-		Self {
-			table: witness.table,
-			cols,
-			log_segment_size,
-			start_index: 0,
-			n_segments: 1 << (witness.log_capacity - log_segment_size),
-		}
+			.collect::<Result<_, Error>>()?;
+		Ok(ColumnBatch {
+			table_id: table.id(),
+			n_events,
+			n_rows,
+			columns,
+		})
 	}
 
-	fn split_at(
+	/// Renders the first `max_rows` rows of the table as a grid, for debugging small tables.
+	///
+	/// There is one line per row and one column of the grid per table column, headed by the column
+	/// name. The values are formatted in hexadecimal at the width of the column's field, and the
+	/// `V` values of a cell with several values per row are separated by spaces. The padding and
+	/// blinding rows are included when `max_rows` exceeds the number of events.
+	pub fn to_grid_string(&mut self, max_rows: usize) -> Result<String, Error> {
+		let table = self.table;
+		let n_rows = max_rows.min(self.capacity());
+		let segment = self.full_segment();
+
+		let mut grid_cols = vec![
+			iter::once("row".to_string())
+				.chain((0..n_rows).map(|row| row.to_string()))
+				.collect::<Vec<_>>(),
+		];
+		for col in &table.columns {
+			let values_per_row = 1 << col.shape.log_values_per_row;
+			let data = segment.get_dyn(col.id)?;
+			let cells = (0..n_rows).map(|row| {
+				(row * values_per_row..(row + 1) * values_per_row)
+					.map(|i| format_subfield_elem(data.get(i), col.shape.tower_height))
+					.join(" ")
+			});
+			grid_cols.push(iter::once(col.name.clone()).chain(cells).collect());
+		}
+
+		let widths = grid_cols
+			.iter()
+			.map(|cells| cells.iter().map(String::len).max().unwrap_or_default())
+			.collect::<Vec<_>>();
+		let mut grid = String::new();
+		for line in 0..=n_rows {
+			let line = iter::zip(&grid_cols, &widths)
+				.map(|(cells, &width)| format!("{:<width$}", cells[line]))
+				.join(" | ");
+			grid.push_str(line.trim_end());
+			grid.push('\n');
+		}
+		Ok(grid)
+	}
+}
+
+/// Formats an element of the subfield with the given tower height, embedded in `F`, in
+/// hexadecimal with one digit per four bits of the subfield.
+fn format_subfield_elem<F: TowerField>(value: F, tower_height: usize) -> String {
+	let n_bits = 1 << tower_height;
+	let repr = subfield_elem_bits(value, tower_height);
+	format!("0x{repr:0>width$x}", width = n_bits.max(4) / 4)
+}
+
+/// Returns the bits of an element of the subfield with the given tower height, embedded in `F`.
+fn subfield_elem_bits<F: TowerField>(value: F, tower_height: usize) -> u128 {
+	(0..1 << tower_height)
+		.filter(|&i| ExtensionField::<B1>::get_base(&value, i) != <B1 as binius_field::Field>::ZERO)
+		.fold(0u128, |repr, i| repr | (1 << i))
+}
+
+/// The witness of a table in columnar form, returned by [`TableWitnessIndex::to_columnar`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnBatch {
+	pub table_id: TableId,
+	/// The number of events the table was filled with.
+	pub n_events: usize,
+	/// The number of rows of every column, which is the capacity of the table.
+	pub n_rows: usize,
+	/// The columns of the table, in the order they were added.
+	pub columns: Vec<ColumnArray>,
+}
+
+impl ColumnBatch {
+	/// Returns the column with the given name, if there is one.
+	pub fn column(&self, name: &str) -> Option<&ColumnArray> {
+		self.columns.iter().find(|col| col.name == name)
+	}
+}
+
+/// The values of a column of a [`ColumnBatch`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnArray {
+	pub id: ColumnId,
+	pub name: String,
+	/// The number of consecutive values of the array in each row.
+	pub values_per_row: usize,
+	pub values: ColumnValues,
+}
+
+/// An array of field elements as the integer bit patterns of the narrowest fitting type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColumnValues {
+	B1(Vec<bool>),
+	B8(Vec<u8>),
+	B16(Vec<u16>),
+	B32(Vec<u32>),
+	B64(Vec<u64>),
+	B128(Vec<u128>),
+}
+
+impl ColumnValues {
+	fn with_capacity(tower_height: usize, capacity: usize) -> Self {
+		match tower_height {
+			0 => Self::B1(Vec::with_capacity(capacity)),
+			3 => Self::B8(Vec::with_capacity(capacity)),
+			4 => Self::B16(Vec::with_capacity(capacity)),
+			5 => Self::B32(Vec::with_capacity(capacity)),
+			6 => Self::B64(Vec::with_capacity(capacity)),
+			7 => Self::B128(Vec::with_capacity(capacity)),
+			_ => panic!("Unsupported tower height: {tower_height}"),
+		}
+	}
+
+	/// Appends a value, which must fit in the bit width of the array.
+	fn push(&mut self, bits: u128) {
+		match self {
+			Self::B1(values) => values.push(bits != 0),
+			Self::B8(values) => values.push(bits as u8),
+			Self::B16(values) => values.push(bits as u16),
+			Self::B32(values) => values.push(bits as u32),
+			Self::B64(values) => values.push(bits as u64),
+			Self::B128(values) => values.push(bits),
+		}
+	}
+
+	/// Returns the number of values.
+	pub fn len(&self) -> usize {
+		match self {
+			Self::B1(values) => values.len(),
+			Self::B8(values) => values.len(),
+			Self::B16(values) => values.len(),
+			Self::B32(values) => values.len(),
+			Self::B64(values) => values.len(),
+			Self::B128(values) => values.len(),
+		}
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+}
+
+/// Evaluates the multilinear extension of a structured column at the hypercube point of row `i`.
+fn eval_structured_at_index<F: TowerField>(expr: &ArithCircuit<F>, n_vars: usize, i: usize) -> F {
+	let query = (0..n_vars)
+		.map(|j| if (i >> j) & 1 == 1 { F::ONE } else { F::ZERO })
+		.collect::<Vec<_>>();
+	expr.evaluate(&query)
+		.expect("query length matches the number of variables")
+}
+
+/// How the number of rows of the segments of a table fill is chosen.
+///
+/// See [`TableWitnessIndex::fill_sequential_with`] and [`TableWitnessIndex::log_segment_size`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SegmentSize {
+	/// Segments of `2^log_size` rows.
+	Fixed { log_size: usize },
+	/// The largest segments whose rows of the committed columns fit in a cache of `cache_bytes`
+	/// bytes, typically the L2 cache of a core.
+	///
+	/// Each thread of a fill works on one segment at a time, so segments that fit in the cache are
+	/// filled without evictions, while larger segments have less per-segment overhead.
+	Auto { cache_bytes: usize },
+}
+
+impl SegmentSize {
+	/// The L2 cache size assumed by [`Self::auto`].
+	pub const DEFAULT_CACHE_BYTES: usize = 1 << 20;
+
+	/// Returns [`Self::Auto`] with [`Self::DEFAULT_CACHE_BYTES`].
+	pub const fn auto() -> Self {
+		Self::Auto {
+			cache_bytes: Self::DEFAULT_CACHE_BYTES,
+		}
+	}
+}
+
+impl Default for SegmentSize {
+	fn default() -> Self {
+		Self::auto()
+	}
+}
+
+/// Returns the binary logarithm of the minimum segment size of a table witness with a capacity of
+/// `2^log_capacity` rows.
+fn min_log_segment_size<P: PackedField<Scalar: TowerField>>(
+	table: &Table<P::Scalar>,
+	log_capacity: usize,
+) -> usize {
+	let packed_elem_log_bits = P::LOG_WIDTH + <P::Scalar as TowerField>::TOWER_LEVEL;
+
+	// The minimum segment size is chosen such that the segment of each column is at least one
+	// underlier in size.
+	let min_log_segment_size = packed_elem_log_bits
+		- table
+			.columns
+			.iter()
+			.map(|col| col.shape.log_cell_size())
+			.fold(packed_elem_log_bits, |a, b| a.min(b));
+
+	// But, in case the minimum segment size is larger than the capacity, we lower it so the caller
+	// can get the full witness index in one segment. This is OK because the extra field elements
+	// in the smallest columns are just padding.
+	min_log_segment_size.min(log_capacity)
+}
+
+/// Samples a random non-zero element of the subfield with the given tower height, embedded in `F`.
+fn random_nonzero_subfield_elem<F: TowerField>(rng: &mut impl Rng, tower_height: usize) -> F {
+	let n_bits = 1 << tower_height;
+	loop {
+		let bits = rng.random::<u128>();
+		let elem = (0..n_bits)
+			.filter(|&i| (bits >> i) & 1 == 1)
+			.map(<F as ExtensionField<B1>>::basis)
+			.sum::<F>();
+		if elem != F::ZERO {
+			return elem;
+		}
+	}
+}
+
+/// A view over a table witness that splits the table into segments.
+///
+/// The purpose of this struct is to implement the `split_at` method, which safely splits the view
+/// of the table witness vertically. This aids in the implementation of `fill_sequential` and
+/// `fill_parallel`.
+#[derive(Debug)]
+struct TableWitnessSegmentedView<'a, P = PackedType<OptimalUnderlier, B128>>
+where
+	P: PackedField,
+	P::Scalar: TowerField,
+{
+	table: &'a Table<P::Scalar>,
+	cols: Vec<WitnessColumnInfo<(&'a mut [P], usize)>>,
+	written: &'a WrittenRows,
+	log_segment_size: usize,
+	start_index: usize,
+	n_segments: usize,
+}
+
+impl<'a, F: TowerField, P: PackedField<Scalar = F>> TableWitnessSegmentedView<'a, P> {
+	fn new(witness: &'a mut TableWitnessIndex<P>, log_segment_size: usize) -> Self {
+		// Clamp the segment size.
+		let log_segment_size = log_segment_size
+			.min(witness.log_capacity)
+			.max(witness.min_log_segment_size);
+
+		let cols = witness
+			.cols
+			.iter_mut()
+			.map(|col| match &mut col.data {
+				WitnessColumnInfo::Owned(data) => {
+					let chunk_size = (log_segment_size + col.shape.log_cell_size())
+						.saturating_sub(P::LOG_WIDTH + F::TOWER_LEVEL);
+					WitnessColumnInfo::Owned((&mut **data, 1 << chunk_size))
+				}
+				WitnessColumnInfo::SameAsIndex(id) => WitnessColumnInfo::SameAsIndex(*id),
+			})
+			.collect::<Vec<_>>();
+		Self {
+			table: witness.table,
+			cols,
+			written: &witness.written,
+			log_segment_size,
+			start_index: 0,
+			n_segments: 1 << (witness.log_capacity - log_segment_size),
+		}
+	}
+
+	fn split_at(
 		&mut self,
 		index: usize,
 	) -> (TableWitnessSegmentedView<P>, TableWitnessSegmentedView<P>) {
@@ -812,6 +2360,7 @@ impl<'a, F: TowerField, P: PackedField<Scalar = F>> TableWitnessSegmentedView<'a
 			TableWitnessSegmentedView {
 				table: self.table,
 				cols: cols_0,
+				written: self.written,
 				log_segment_size: self.log_segment_size,
 				start_index: self.start_index,
 				n_segments: index,
@@ -819,6 +2368,7 @@ impl<'a, F: TowerField, P: PackedField<Scalar = F>> TableWitnessSegmentedView<'a
 			TableWitnessSegmentedView {
 				table: self.table,
 				cols: cols_1,
+				written: self.written,
 				log_segment_size: self.log_segment_size,
 				start_index: self.start_index + index,
 				n_segments: self.n_segments - index,
@@ -830,6 +2380,7 @@ impl<'a, F: TowerField, P: PackedField<Scalar = F>> TableWitnessSegmentedView<'a
 		let TableWitnessSegmentedView {
 			table,
 			cols,
+			written,
 			log_segment_size,
 			start_index,
 			n_segments,
@@ -855,6 +2406,7 @@ impl<'a, F: TowerField, P: PackedField<Scalar = F>> TableWitnessSegmentedView<'a
 			.map(move |(index, cols)| TableWitnessSegment {
 				table,
 				cols,
+				written,
 				log_size: log_segment_size,
 				index: start_index + index,
 			});
@@ -866,6 +2418,7 @@ impl<'a, F: TowerField, P: PackedField<Scalar = F>> TableWitnessSegmentedView<'a
 		let TableWitnessSegmentedView {
 			table,
 			cols,
+			written,
 			log_segment_size,
 			start_index,
 			n_segments,
@@ -914,6 +2467,7 @@ impl<'a, F: TowerField, P: PackedField<Scalar = F>> TableWitnessSegmentedView<'a
 			TableWitnessSegment {
 				table,
 				cols: col_strides,
+				written,
 				log_size: log_segment_size,
 				index: start_index + i,
 			}
@@ -936,6 +2490,8 @@ where
 	///
 	/// The order of the columns corresponds to the same order as defined in the table.
 	cols: Vec<RefCellData<'a, P>>,
+	/// The rows on which the columns of the table witness were written.
+	written: &'a WrittenRows,
 	#[get_copy = "pub"]
 	log_size: usize,
 	/// The index of the segment in the segmented table witness.
@@ -980,10 +2536,7 @@ impl<'a, F: TowerField, P: PackedField<Scalar = F>> TableWitnessSegment<'a, P> {
 			});
 		}
 
-		let col = self
-			.get_col_data(col.id())
-			.ok_or_else(|| Error::MissingColumn(col.id()))?;
-		let col_ref = col.try_borrow_mut().map_err(Error::WitnessBorrowMut)?;
+		let col_ref = self.borrow_col_data_mut(col.id())?;
 		Ok(RefMut::map(col_ref, |packed| PackedExtension::cast_bases_mut(packed)))
 	}
 
@@ -1043,13 +2596,71 @@ impl<'a, F: TowerField, P: PackedField<Scalar = F>> TableWitnessSegment<'a, P> {
 			});
 		}
 
-		let col = self
-			.get_col_data(col.id())
-			.ok_or_else(|| Error::MissingColumn(col.id()))?;
-		let col_ref = col.try_borrow_mut().map_err(Error::WitnessBorrowMut)?;
+		let col_ref = self.borrow_col_data_mut(col.id())?;
 		Ok(RefMut::map(col_ref, |col| must_cast_slice_mut(P::unpack_scalars_mut(col))))
 	}
 
+	/// Fills the column segment with zeros.
+	///
+	/// This zeroes the packed backing storage of the column directly, which is much faster than
+	/// writing the scalars one at a time through [`Self::get_scalars_mut`].
+	pub fn fill_zero<FSub: TowerField, const V: usize>(
+		&self,
+		col: Col<FSub, V>,
+	) -> Result<(), Error> {
+		if col.table_id != self.table.id() {
+			return Err(Error::TableMismatch {
+				column_table_id: col.table_id,
+				witness_table_id: self.table.id(),
+			});
+		}
+
+		let mut col_ref = self.borrow_col_data_mut(col.id())?;
+		col_ref.fill(P::zero());
+		Ok(())
+	}
+
+	/// Returns an iterator over the values of a structured column on the rows of the segment.
+	///
+	/// The values are computed from the column's [`StructuredDynSize`] specification instead of
+	/// being read from the witness. Hence the column need not be populated yet, and the iterator
+	/// does not borrow the witness data, so it can be consumed while other columns are written.
+	///
+	/// [`StructuredDynSize`]: super::StructuredDynSize
+	pub fn get_structured_values<FSub>(
+		&self,
+		col: Col<FSub>,
+	) -> Result<impl Iterator<Item = FSub> + use<FSub, F, P>, Error>
+	where
+		FSub: TowerField,
+		F: ExtensionField<FSub>,
+	{
+		if col.table_id != self.table.id() {
+			return Err(Error::TableMismatch {
+				column_table_id: col.table_id,
+				witness_table_id: self.table.id(),
+			});
+		}
+
+		let ColumnDef::StructuredDynSize(structured) = &self.table[col.id()].col else {
+			return Err(Error::NotStructuredColumn(col.id()));
+		};
+		let n_vars = structured.max_size_log();
+		let expr = structured
+			.expr()
+			.map_err(|err| err.for_column(&self.table[col.id()].name))?;
+		let expr = ArithCircuit::from(&expr);
+
+		let start_index = self.index << self.log_size;
+		Ok((start_index..start_index + self.size()).map(move |i| {
+			let value = eval_structured_at_index(&expr, n_vars, i);
+			// The column values lie in the subfield, which is spanned by the first basis element.
+			ExtensionField::<FSub>::iter_bases(&value)
+				.next()
+				.expect("extension field has at least one basis element")
+		}))
+	}
+
 	/// Evaluate an expression over columns that are assumed to be already populated.
 	///
 	/// This function evaluates an expression over the columns in the segment and returns an
@@ -1117,18 +2728,69 @@ impl<'a, F: TowerField, P: PackedField<Scalar = F>> TableWitnessSegment<'a, P> {
 		1 << self.log_size
 	}
 
+	/// Returns the index in the table of the first row of the segment.
+	pub fn start_row(&self) -> usize {
+		self.index << self.log_size
+	}
+
+	/// Returns a random number generator seeded with `seed` and the first row of the segment.
+	///
+	/// Gadgets that fill columns with randomness should draw it from this generator. The segments
+	/// get distinct streams, and a stream does not depend on the order in which the segments are
+	/// filled, so a sequential and a parallel fill with the same segment size produce identical
+	/// columns.
+	pub fn seeded_rng(&self, seed: u64) -> StdRng {
+		let mut rng_seed = <StdRng as SeedableRng>::Seed::default();
+		rng_seed[..8].copy_from_slice(&seed.to_le_bytes());
+		rng_seed[8..16].copy_from_slice(&(self.start_row() as u64).to_le_bytes());
+		StdRng::from_seed(rng_seed)
+	}
+
 	fn get_col_data(&self, column_id: ColumnId) -> Option<&RefCell<&'a mut [P]>> {
 		let column_index = column_id.table_index.0;
 		self.get_col_data_by_index(column_index)
 	}
 
 	fn get_col_data_by_index(&self, index: usize) -> Option<&RefCell<&'a mut [P]>> {
+		self.owner_index(index).map(|index| {
+			let RefCellData::Owned(data) = &self.cols[index] else {
+				unreachable!("owner_index returns the index of an owned column");
+			};
+			data
+		})
+	}
+
+	/// Returns the index of the column that stores the data of the column at `index`.
+	fn owner_index(&self, index: usize) -> Option<usize> {
 		match self.cols.get(index) {
-			Some(RefCellData::Owned(data)) => Some(data),
-			Some(RefCellData::SameAsIndex(index)) => self.get_col_data_by_index(*index),
+			Some(RefCellData::Owned(_)) => Some(index),
+			Some(RefCellData::SameAsIndex(index)) => self.owner_index(*index),
 			None => None,
 		}
 	}
+
+	/// Borrows the data of a column mutably and records that the column was written on the rows
+	/// of the segment.
+	fn borrow_col_data(&self, column_id: ColumnId) -> Result<Ref<&'a mut [P]>, Error> {
+		let col = self
+			.get_col_data(column_id)
+			.ok_or_else(|| Error::MissingColumn(column_id))?;
+		col.try_borrow().map_err(Error::WitnessBorrow)
+	}
+
+	fn borrow_col_data_mut(&self, column_id: ColumnId) -> Result<RefMut<&'a mut [P]>, Error> {
+		let index = self
+			.owner_index(column_id.table_index.0)
+			.ok_or_else(|| Error::MissingColumn(column_id))?;
+		let RefCellData::Owned(data) = &self.cols[index] else {
+			unreachable!("owner_index returns the index of an owned column");
+		};
+		let col_ref = data.try_borrow_mut().map_err(Error::WitnessBorrowMut)?;
+		let start = self.index << self.log_size;
+		self.written
+			.mark(index, start..start + (1 << self.log_size));
+		Ok(col_ref)
+	}
 }
 
 impl<'a, P> TableWitnessSegment<'a, P>
@@ -1146,10 +2808,7 @@ where
 		&self,
 		col_id: ColumnId,
 	) -> Result<Box<dyn WitnessColView<P::Scalar> + '_>, Error> {
-		let col = self
-			.get_col_data(col_id)
-			.ok_or_else(|| Error::MissingColumn(col_id))?;
-		let col_ref = col.try_borrow().map_err(Error::WitnessBorrow)?;
+		let col_ref = self.borrow_col_data(col_id)?;
 		let tower_level = self.table[col_id].shape.tower_height;
 		let ret: Box<dyn WitnessColView<_>> = match tower_level {
 			0 => Box::new(WitnessColViewImpl(Ref::map(col_ref, |packed| {
@@ -1180,10 +2839,7 @@ where
 		&self,
 		col_id: ColumnId,
 	) -> Result<Box<dyn WitnessColViewMut<P::Scalar> + '_>, Error> {
-		let col = self
-			.get_col_data(col_id)
-			.ok_or_else(|| Error::MissingColumn(col_id))?;
-		let col_ref = col.try_borrow_mut().map_err(Error::WitnessBorrowMut)?;
+		let col_ref = self.borrow_col_data_mut(col_id)?;
 		let tower_level = self.table[col_id].shape.tower_height;
 		let ret: Box<dyn WitnessColViewMut<_>> = match tower_level {
 			0 => Box::new(WitnessColViewImpl(RefMut::map(col_ref, |packed| {
@@ -1256,6 +2912,64 @@ where
 	}
 }
 
+/// Receives notifications about the progress of the sequential table fills.
+///
+/// This is meant for profiling: the end notifications report how long the table or segment took
+/// to fill. All methods default to doing nothing. An observer is registered with
+/// [`WitnessIndex::set_fill_observer`] or [`TableWitnessIndex::set_fill_observer`] and is invoked
+/// by [`TableWitnessIndex::fill_sequential`] and the methods built on it, but not by the parallel
+/// fills.
+///
+/// Every segment of the table is reported, including the segments past the last event, which are
+/// filled by copying the last filled segment.
+pub trait FillObserver: Debug + Send + Sync {
+	/// Called before the table starts filling.
+	fn on_table_fill_start(&self, _table_id: TableId) {}
+
+	/// Called after the table has been filled, with the time it took.
+	fn on_table_fill_end(&self, _table_id: TableId, _duration: Duration) {}
+
+	/// Called before the segment with the given index starts filling.
+	fn on_segment_fill_start(&self, _table_id: TableId, _segment_index: usize) {}
+
+	/// Called after the segment with the given index has been filled, with the time it took.
+	fn on_segment_fill_end(&self, _table_id: TableId, _segment_index: usize, _duration: Duration) {}
+}
+
+/// An observer of the columns of a witness as soon as they are filled.
+///
+/// This allows overlapping the commitment of the columns with the generation of the rest of the
+/// witness. The observer is set with [`WitnessIndex::set_commit_observer`]. Once a table is filled
+/// with [`WitnessIndex::fill_table_sequential`],
+/// [`WitnessIndex::fill_table_sequential_from_iter`] or [`WitnessIndex::fill_table_parallel`],
+/// all its columns are complete, and the observer is called once for each of them, in the order
+/// of the columns of the table. [`WitnessIndex::fill_table_phase`] reports the columns of the
+/// filled phase only, and [`WitnessIndex::append_events`] reports the columns of the table again,
+/// since their values change. The columns that share the data of another column, such as packed
+/// columns, are not reported, and neither are the fills of a [`TableWitnessIndex`] itself.
+pub trait CommitObserver<P: PackedField>: Send + Sync {
+	/// Called with the packed data of a column, over the full capacity of the table, once the
+	/// column is filled.
+	fn on_column_filled(&self, table_id: TableId, column_id: ColumnId, data: &[P]);
+}
+
+/// Runs `fill` on a segment, notifying the observer before and after.
+fn observe_segment<R>(
+	observer: Option<&dyn FillObserver>,
+	table_id: TableId,
+	segment_index: usize,
+	fill: impl FnOnce() -> R,
+) -> R {
+	let Some(observer) = observer else {
+		return fill();
+	};
+	observer.on_segment_fill_start(table_id, segment_index);
+	let start = Instant::now();
+	let result = fill();
+	observer.on_segment_fill_end(table_id, segment_index, start.elapsed());
+	result
+}
+
 /// A struct that can populate segments of a table witness using row descriptors.
 pub trait TableFiller<P = PackedType<OptimalUnderlier, B128>>
 where
@@ -1280,23 +2994,97 @@ where
 	) -> anyhow::Result<()>;
 }
 
+/// A struct that populates segments of a table witness in several phases.
+///
+/// The columns of each phase are filled after those of the earlier phases, with challenges that
+/// may depend on them, see [`WitnessIndex::fill_table_phase`].
+pub trait PhasedTableFiller<P = PackedType<OptimalUnderlier, B128>>
+where
+	P: PackedField,
+	P::Scalar: TowerField,
+{
+	/// A struct that specifies the row contents.
+	type Event: Clone;
+
+	/// Returns the table ID.
+	fn id(&self) -> TableId;
+
+	/// Fill the columns of the given phase with data derived from the given rows and the
+	/// challenges of the phase.
+	///
+	/// The columns of the earlier phases are already filled.
+	///
+	/// ## Preconditions
+	///
+	/// * the number of elements in `rows` must equal `witness.size()`
+	fn fill_phase(
+		&self,
+		phase: usize,
+		challenges: &[P::Scalar],
+		rows: &[Self::Event],
+		witness: &mut TableWitnessSegment<P>,
+	) -> anyhow::Result<()>;
+}
+
+/// The [`TableFiller`] of one phase of a [`PhasedTableFiller`].
+struct PhaseFiller<'a, T, F> {
+	filler: &'a T,
+	phase: usize,
+	challenges: &'a [F],
+}
+
+impl<P, T> TableFiller<P> for PhaseFiller<'_, T, P::Scalar>
+where
+	P: PackedField,
+	P::Scalar: TowerField,
+	T: PhasedTableFiller<P>,
+{
+	type Event = T::Event;
+
+	fn id(&self) -> TableId {
+		self.filler.id()
+	}
+
+	fn fill(
+		&self,
+		rows: &[Self::Event],
+		witness: &mut TableWitnessSegment<P>,
+	) -> anyhow::Result<()> {
+		self.filler
+			.fill_phase(self.phase, self.challenges, rows, witness)
+	}
+}
+
 #[cfg(test)]
 mod tests {
-	use std::{array, iter::repeat_with};
+	use std::{
+		array,
+		iter::{self, repeat_with},
+		sync::Mutex,
+	};
 
 	use assert_matches::assert_matches;
 	use binius_compute::cpu::alloc::CpuComputeAllocator;
-	use binius_core::oracle::{OracleId, SymbolicMultilinearOracleSet};
+	use binius_core::{
+		constraint_system::channel::{Boundary, FlushDirection},
+		oracle::{OracleId, SymbolicMultilinearOracleSet, SymbolicMultilinearPolyVariant},
+	};
 	use binius_field::{
 		arch::{OptimalUnderlier128b, OptimalUnderlier256b},
 		packed::{len_packed_slice, set_packed_slice},
 	};
+	use binius_utils::checked_arithmetics::log2_strict_usize;
 	use rand::{Rng, SeedableRng, rngs::StdRng};
 
 	use super::*;
-	use crate::builder::{
-		ConstraintSystem, TableBuilder,
-		types::{B1, B8, B16, B32},
+	use crate::{
+		builder::{
+			ConstraintSystem, FlushOpts, StructuredDynSize, TableBuilder,
+			test_utils::{ClosureFiller, validate_system_witness},
+			types::{B1, B8, B16, B32},
+			upcast_col,
+		},
+		gadgets::structured::fill_incrementing_b32,
 	};
 
 	#[test]
@@ -1374,6 +3162,37 @@ mod tests {
 		assert_eq!(len_packed_slice(&seg1.get_mut(col3).unwrap()), 1 << 5);
 	}
 
+	#[test]
+	fn test_padded_size() {
+		let mut inner_table = Table::<B128>::new(0, "table".to_string());
+		let mut table = TableBuilder::new(&mut inner_table);
+		table.require_power_of_two_size();
+		table.add_committed::<B32, 1>("col");
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let index = TableWitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(
+			&allocator,
+			&inner_table,
+			(1 << 5) - 3,
+		)
+		.unwrap();
+		assert_eq!(index.logical_size(), (1 << 5) - 3);
+		assert_eq!(index.padded_size(), 1 << 5);
+
+		// The blinding rows count towards the padded size.
+		let mut table = TableBuilder::new(&mut inner_table);
+		table.add_blinding_rows(4);
+		let index = TableWitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(
+			&allocator,
+			&inner_table,
+			(1 << 5) - 3,
+		)
+		.unwrap();
+		assert_eq!(index.logical_size(), (1 << 5) - 3);
+		assert_eq!(index.padded_size(), 1 << 6);
+	}
+
 	#[test]
 	fn test_eval_expr() {
 		let table_id = 0;
@@ -1698,38 +3517,1291 @@ mod tests {
 		})
 	}
 
-	fn find_oracle_id_with_name(
-		oracles: &SymbolicMultilinearOracleSet<B128>,
-		name: &str,
-	) -> Option<OracleId> {
-		oracles
-			.iter()
-			.find(|(_, oracle)| oracle.name.as_deref() == Some(name))
-			.map(|(id, _)| id)
-	}
-
 	#[test]
-	fn test_constant_filling() {
+	fn test_fill_zero() {
 		let mut cs = ConstraintSystem::new();
-
 		let mut test_table = cs.add_table("test");
-		let mut rng = StdRng::seed_from_u64(0);
-		let unpack_value = B16::random(&mut rng);
-		let pack_const_arr: [B32; 4] = array::from_fn(|_| B32::random(&mut rng));
-
-		let _ = test_table.add_constant("unpacked_col", [unpack_value]);
-		let _ = test_table.add_constant("packed_col", pack_const_arr);
+		let zero_col = test_table.add_committed::<B32, 4>("zero");
+		let reference_col = test_table.add_constant("reference", [B32::new(0); 4]);
+		test_table.assert_zero("zero = reference", zero_col - reference_col);
 		let table_id = test_table.id();
 
 		let mut allocator = CpuComputeAllocator::new(1 << 12);
 		let allocator = allocator.into_bump_allocator();
 
-		let table_size = 123;
-		let ccs = cs.compile().unwrap();
-		let mut index = WitnessIndex::<PackedType<OptimalUnderlier, B128>>::new(&cs, &allocator);
-
+		let table_size = 37;
+		let mut index =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
 		{
-			let _ = index.init_table(table_id, table_size).unwrap();
+			let table_index = index.init_table(table_id, table_size).unwrap();
+			let segment = table_index.full_segment();
+			let mut rng = StdRng::seed_from_u64(0);
+			for elem in &mut *segment.get_mut(zero_col).unwrap() {
+				*elem = PackedField::random(&mut rng);
+			}
+
+			segment.fill_zero(zero_col).unwrap();
+			assert!(
+				segment
+					.get_scalars(zero_col)
+					.unwrap()
+					.iter()
+					.all(|&val| val == B32::new(0))
+			);
+		}
+		index.fill_constant_cols().unwrap();
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, index, vec![]);
+	}
+
+	#[test]
+	fn test_closure_filler_struct_events() {
+		#[derive(Debug, Clone)]
+		struct PairEvent {
+			lhs: u32,
+			rhs: u16,
+		}
+
+		let mut cs = ConstraintSystem::new();
+		let mut test_table = cs.add_table("test");
+		let lhs_col = test_table.add_committed::<B32, 1>("lhs");
+		let rhs_col = test_table.add_committed::<B16, 1>("rhs");
+		let table_id = test_table.id();
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let events = repeat_with(|| PairEvent {
+			lhs: rng.random(),
+			rhs: rng.random(),
+		})
+		.take(13)
+		.collect::<Vec<_>>();
+
+		let mut index =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		index
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |events: &[PairEvent], segment| {
+					let mut lhs = segment.get_scalars_mut(lhs_col)?;
+					let mut rhs = segment.get_scalars_mut(rhs_col)?;
+					for (i, event) in events.iter().enumerate() {
+						lhs[i] = B32::new(event.lhs);
+						rhs[i] = B16::new(event.rhs);
+					}
+					Ok(())
+				}),
+				&events,
+			)
+			.unwrap();
+
+		let segment = index.get_table(table_id).unwrap().full_segment();
+		let lhs = segment.get_scalars(lhs_col).unwrap();
+		let rhs = segment.get_scalars(rhs_col).unwrap();
+		for (i, event) in events.iter().enumerate() {
+			assert_eq!(lhs[i], B32::new(event.lhs));
+			assert_eq!(rhs[i], B16::new(event.rhs));
+		}
+	}
+
+	#[test]
+	fn test_fill_with_growable_allocator() {
+		let mut cs = ConstraintSystem::new();
+		let mut test_table = cs.add_table("test");
+		let x_col = test_table.add_committed::<B32, 1>("x");
+		let y_col = test_table.add_committed::<B32, 1>("y");
+		test_table.assert_zero("y = x * x", x_col * x_col - y_col);
+		let table_id = test_table.id();
+
+		// The initial capacity is far smaller than the table's columns.
+		let mut allocator = CpuComputeAllocator::new_growable(4, 1 << 20);
+		let allocator = allocator.into_bump_allocator();
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let events = repeat_with(|| B32::random(&mut rng))
+			.take(1 << 10)
+			.collect::<Vec<_>>();
+
+		let mut index =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		index
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |events: &[B32], segment| {
+					let mut x = segment.get_scalars_mut(x_col)?;
+					let mut y = segment.get_scalars_mut(y_col)?;
+					for (i, &event) in events.iter().enumerate() {
+						x[i] = event;
+						y[i] = event * event;
+					}
+					Ok(())
+				}),
+				&events,
+			)
+			.unwrap();
+
+		{
+			let segment = index.get_table(table_id).unwrap().full_segment();
+			let x = segment.get_scalars(x_col).unwrap();
+			let y = segment.get_scalars(y_col).unwrap();
+			for (i, &event) in events.iter().enumerate() {
+				assert_eq!(x[i], event);
+				assert_eq!(y[i], event * event);
+			}
+		}
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, index, vec![]);
+	}
+
+	#[test]
+	fn test_fill_table_chunked() {
+		let log_size = 8;
+		let mut cs = ConstraintSystem::new();
+		let mut test_table = cs.add_table("test");
+		test_table.require_power_of_two_size();
+		let index_col = test_table.add_structured::<B32>(
+			"index",
+			StructuredDynSize::Incrementing {
+				max_size_log: log_size,
+			},
+		);
+		let x_col = test_table.add_committed::<B32, 1>("x");
+		let y_col = test_table.add_committed::<B32, 1>("y");
+		test_table.assert_zero("y = x * index", x_col * index_col - y_col);
+		let table_id = test_table.id();
+		drop(test_table);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let events = repeat_with(|| B32::random(&mut rng))
+			// The last chunk is not full.
+			.take(200)
+			.collect::<Vec<_>>();
+		let filler = ClosureFiller::new(table_id, |events: &[B32], segment| {
+			fill_incrementing_b32(segment, index_col)?;
+			let index = segment.get_scalars(index_col)?;
+			let mut x = segment.get_scalars_mut(x_col)?;
+			let mut y = segment.get_scalars_mut(y_col)?;
+			for (i, &event) in events.iter().enumerate() {
+				x[i] = event;
+				y[i] = event * index[i];
+			}
+			Ok(())
+		});
+
+		let mut allocator = CpuComputeAllocator::new(1 << 14);
+		let allocator = allocator.into_bump_allocator();
+		let log_chunk_size = 5;
+		// The full fill with segments of the size of the chunks fills the events the same way.
+		let mut full_index =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		full_index
+			.init_table(table_id, events.len())
+			.unwrap()
+			.fill_sequential_with_segment_size(&filler, &events, log_chunk_size)
+			.unwrap();
+
+		// The chunked fill holds one chunk of 32 rows of the three B32 columns at a time, which is
+		// 24 packed elements, rather than the 192 of the whole table.
+		let mut chunk_allocator = CpuComputeAllocator::new(1 << 14);
+		let chunk_allocator = chunk_allocator.into_bump_allocator();
+		let chunked_index =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &chunk_allocator);
+		let capacity_before = chunk_allocator.capacity();
+		let mut chunk_rows = Vec::new();
+		let mut streamed = Vec::new();
+		chunked_index
+			.fill_table_chunked(&filler, &events, log_chunk_size, |segment, n_rows| {
+				let start = segment.index() << segment.log_size();
+				chunk_rows.push(start..start + n_rows);
+				let index = segment.get_scalars(index_col)?;
+				let x = segment.get_scalars(x_col)?;
+				let y = segment.get_scalars(y_col)?;
+				streamed.extend(
+					izip!(&*index, &*x, &*y)
+						.take(n_rows)
+						.map(|(&i, &x, &y)| (i, x, y)),
+				);
+				Ok(())
+			})
+			.unwrap();
+		assert_eq!(capacity_before - chunk_allocator.capacity(), 3 << (log_chunk_size + 5 - 7));
+
+		// The 200 events are six full chunks and a last chunk of 8 events.
+		assert_eq!(
+			chunk_rows,
+			(0..events.len())
+				.step_by(1 << log_chunk_size)
+				.map(|start| start..(start + (1 << log_chunk_size)).min(events.len()))
+				.collect::<Vec<_>>()
+		);
+		let segment = full_index.get_table(table_id).unwrap().full_segment();
+		let index = segment.get_scalars(index_col).unwrap();
+		let x = segment.get_scalars(x_col).unwrap();
+		let y = segment.get_scalars(y_col).unwrap();
+		let expected = izip!(&*index, &*x, &*y)
+			.take(events.len())
+			.map(|(&i, &x, &y)| (i, x, y))
+			.collect::<Vec<_>>();
+		assert_eq!(streamed, expected);
+	}
+
+	#[test]
+	fn test_fill_table_chunked_with_blinding_rows() {
+		let mut cs = ConstraintSystem::new();
+		let mut test_table = cs.add_table("test");
+		test_table.require_power_of_two_size();
+		test_table.add_blinding_rows(4);
+		let x_col = test_table.add_committed::<B32, 1>("x");
+		let table_id = test_table.id();
+		drop(test_table);
+
+		let filler = ClosureFiller::new(table_id, |events: &[B32], segment| {
+			segment.get_scalars_mut(x_col)?.copy_from_slice(events);
+			Ok(())
+		});
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let index = WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		assert_matches!(
+			index.fill_table_chunked(&filler, &[B32::new(1); 20], 3, |_, _| Ok(())),
+			Err(Error::ChunkedFillWithBlindingRows { table_id: id }) if id == table_id
+		);
+	}
+
+	#[test]
+	fn test_fill_auto_segment_size() {
+		let log_size = 12;
+		let mut cs = ConstraintSystem::new();
+		let mut test_table = cs.add_table("test");
+		test_table.require_power_of_two_size();
+		let index_col = test_table.add_structured::<B32>(
+			"index",
+			StructuredDynSize::Incrementing {
+				max_size_log: log_size,
+			},
+		);
+		let bit_col = test_table.add_committed::<B1, 1>("bit");
+		let x_col = test_table.add_committed::<B32, 1>("x");
+		let y_col = test_table.add_committed::<B128, 1>("y");
+		test_table.assert_zero("x = index", x_col - index_col);
+		let table_id = test_table.id();
+		drop(test_table);
+
+		let filler = ClosureFiller::new(table_id, |events: &[u32], segment| {
+			fill_incrementing_b32(segment, index_col)?;
+			let mut bit = segment.get_mut(bit_col)?;
+			let mut x = segment.get_scalars_mut(x_col)?;
+			let mut y = segment.get_scalars_mut(y_col)?;
+			for (i, &event) in events.iter().enumerate() {
+				set_packed_slice(&mut bit, i, B1::from(event.count_ones() % 2 == 1));
+				x[i] = B32::new(event);
+				y[i] = B128::new(event as u128) * B128::new(0x1234);
+			}
+			Ok(())
+		});
+		let events = (0..1 << log_size).collect::<Vec<_>>();
+
+		let mut allocator = CpuComputeAllocator::new(1 << 16);
+		let allocator = allocator.into_bump_allocator();
+
+		let mut auto_index =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		let table_witness = auto_index.init_table(table_id, events.len()).unwrap();
+		// A row of the owned columns is 1 + 32 + 32 + 128 bits, so 2^9 rows fit in 16 KiB.
+		assert_eq!(
+			table_witness.log_segment_size(SegmentSize::Auto {
+				cache_bytes: 1 << 14
+			}),
+			9
+		);
+		// The whole table fits in the default cache.
+		assert_eq!(table_witness.log_segment_size(SegmentSize::auto()), log_size);
+		// A tiny cache still gives segments of whole packed elements of the B1 column.
+		let log_tiny = table_witness.log_segment_size(SegmentSize::Auto { cache_bytes: 64 });
+		let b1_width = <PackedSubfield<PackedType<OptimalUnderlier128b, B128>, B1>>::WIDTH;
+		assert_eq!((1 << log_tiny) % b1_width, 0);
+		assert_eq!(log_tiny, table_witness.min_log_segment_size());
+		table_witness
+			.fill_sequential_with(
+				&filler,
+				&events,
+				SegmentSize::Auto {
+					cache_bytes: 1 << 14,
+				},
+			)
+			.unwrap();
+
+		let mut fixed_index =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		fixed_index
+			.init_table(table_id, events.len())
+			.unwrap()
+			.fill_sequential_with(&filler, &events, SegmentSize::Fixed { log_size: 4 })
+			.unwrap();
+
+		{
+			let auto = auto_index.get_table(table_id).unwrap().full_segment();
+			let fixed = fixed_index.get_table(table_id).unwrap().full_segment();
+			for col in [index_col, x_col] {
+				assert_eq!(*auto.get_scalars(col).unwrap(), *fixed.get_scalars(col).unwrap());
+			}
+			assert_eq!(*auto.get_scalars(y_col).unwrap(), *fixed.get_scalars(y_col).unwrap());
+			assert_eq!(*auto.get(bit_col).unwrap(), *fixed.get(bit_col).unwrap());
+		}
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, auto_index, vec![]);
+	}
+
+	#[test]
+	fn test_fill_sequential_with_segments() {
+		let log_size = 8;
+		let mut cs = ConstraintSystem::new();
+		let mut test_table = cs.add_table("test");
+		test_table.require_power_of_two_size();
+		let index_col = test_table.add_structured::<B32>(
+			"index",
+			StructuredDynSize::Incrementing {
+				max_size_log: log_size,
+			},
+		);
+		let x_col = test_table.add_committed::<B32, 1>("x");
+		let y_col = test_table.add_committed::<B32, 1>("y");
+		test_table.assert_zero("y = x * index", x_col * index_col - y_col);
+		let table_id = test_table.id();
+		drop(test_table);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let events = repeat_with(|| B32::random(&mut rng))
+			.take(1 << log_size)
+			.collect::<Vec<_>>();
+		let filler = ClosureFiller::new(table_id, |events: &[B32], segment| {
+			fill_incrementing_b32(segment, index_col)?;
+			let index = segment.get_scalars(index_col)?;
+			let mut x = segment.get_scalars_mut(x_col)?;
+			let mut y = segment.get_scalars_mut(y_col)?;
+			for (i, &event) in events.iter().enumerate() {
+				x[i] = event;
+				y[i] = event * index[i];
+			}
+			Ok(())
+		});
+
+		let mut allocator = CpuComputeAllocator::new(1 << 14);
+		let allocator = allocator.into_bump_allocator();
+
+		let mut uniform_index =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		uniform_index
+			.init_table(table_id, events.len())
+			.unwrap()
+			.fill_sequential_with_segment_size(&filler, &events, 4)
+			.unwrap();
+
+		let mut irregular_index =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		let table_witness = irregular_index.init_table(table_id, events.len()).unwrap();
+		// Misaligned and non-contiguous segments are rejected.
+		assert_matches!(
+			table_witness.fill_sequential_with_segments(&filler, &events, &[0..32, 32..96, 96..256]),
+			Err(Error::InvalidSegment { segment, .. }) if segment == (32..96)
+		);
+		assert_matches!(
+			table_witness.fill_sequential_with_segments(&filler, &events, &[0..64, 64..128]),
+			Err(Error::IncompleteSegments {
+				covered: 128,
+				capacity: 256
+			})
+		);
+		table_witness
+			.fill_sequential_with_segments(
+				&filler,
+				&events,
+				&[
+					0..16,
+					16..32,
+					32..64,
+					64..128,
+					128..144,
+					144..160,
+					160..192,
+					192..256,
+				],
+			)
+			.unwrap();
+
+		{
+			let uniform = uniform_index.get_table(table_id).unwrap().full_segment();
+			let irregular = irregular_index.get_table(table_id).unwrap().full_segment();
+			for col in [index_col, x_col, y_col] {
+				assert_eq!(
+					*uniform.get_scalars(col).unwrap(),
+					*irregular.get_scalars(col).unwrap()
+				);
+			}
+		}
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, irregular_index, vec![]);
+	}
+
+	#[test]
+	fn test_append_events() {
+		let mut cs = ConstraintSystem::new();
+		let mut test_table = cs.add_table("test");
+		test_table.require_power_of_two_size();
+		let index_col = test_table
+			.add_structured::<B32>("index", StructuredDynSize::Incrementing { max_size_log: 8 });
+		let x_col = test_table.add_committed::<B32, 1>("x");
+		test_table.assert_zero("x = index", x_col - index_col);
+		let table_id = test_table.id();
+		drop(test_table);
+
+		let filler = ClosureFiller::new(table_id, |events: &[u32], segment| {
+			fill_incrementing_b32(segment, index_col)?;
+			let mut x = segment.get_scalars_mut(x_col)?;
+			for (x, &event) in iter::zip(&mut *x, events) {
+				*x = B32::new(event);
+			}
+			Ok(())
+		});
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		witness
+			.fill_table_sequential(&filler, &(0..20).collect::<Vec<_>>())
+			.unwrap();
+
+		// The new events fit in the capacity of the table.
+		witness
+			.append_events(&filler, &(20..32).collect::<Vec<_>>())
+			.unwrap();
+		{
+			let table_witness = witness.get_table(table_id).unwrap();
+			assert_eq!(table_witness.size(), 32);
+			assert_eq!(table_witness.capacity(), 32);
+			let segment = table_witness.full_segment();
+			let x = segment.get_scalars(x_col).unwrap();
+			assert!((0..32).all(|i| x[i] == B32::new(i as u32)));
+		}
+
+		// The new events need a reallocation, and then start within a segment of filled rows.
+		witness
+			.append_events(&filler, &(32..50).collect::<Vec<_>>())
+			.unwrap();
+		assert_eq!(witness.get_table(table_id).unwrap().capacity(), 64);
+		witness
+			.append_events(&filler, &(50..64).collect::<Vec<_>>())
+			.unwrap();
+		{
+			let segment = witness.get_table(table_id).unwrap().full_segment();
+			let x = segment.get_scalars(x_col).unwrap();
+			assert!((0..64).all(|i| x[i] == B32::new(i as u32)));
+		}
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+	}
+
+	#[test]
+	fn test_seeded_rng_fill_is_deterministic() {
+		const LOG_SEGMENT_SIZE: usize = 5;
+
+		let mut cs = ConstraintSystem::new();
+		let mut test_table = cs.add_table("test");
+		let table_id = test_table.id();
+		let noise_col = test_table.add_committed::<B32, 1>("noise");
+		drop(test_table);
+
+		let filler = ClosureFiller::new(table_id, |_events: &[()], segment| {
+			let mut rng = segment.seeded_rng(7);
+			for noise in &mut *segment.get_scalars_mut(noise_col)? {
+				*noise = B32::random(&mut rng);
+			}
+			Ok(())
+		});
+		let events = vec![(); 1 << 8];
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut noise = Vec::new();
+		for parallel in [false, true] {
+			let mut witness =
+				WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+			let table_witness = witness.init_table(table_id, events.len()).unwrap();
+			if parallel {
+				table_witness
+					.fill_parallel_with_segment_size(&filler, &events, LOG_SEGMENT_SIZE)
+					.unwrap();
+			} else {
+				table_witness
+					.fill_sequential_with_segment_size(&filler, &events, LOG_SEGMENT_SIZE)
+					.unwrap();
+			}
+			noise.push(
+				table_witness
+					.full_segment()
+					.get_scalars(noise_col)
+					.unwrap()
+					.to_vec(),
+			);
+		}
+
+		assert_eq!(noise[0], noise[1]);
+		let (first, second) = noise[0].split_at(1 << LOG_SEGMENT_SIZE);
+		assert_ne!(first, &second[..1 << LOG_SEGMENT_SIZE]);
+	}
+
+	#[test]
+	fn test_undersized_allocator() {
+		let mut cs = ConstraintSystem::new();
+		let mut test_table = cs.add_table("test");
+		let table_id = test_table.id();
+		let col = test_table.add_committed::<B32, 1>("col");
+		drop(test_table);
+
+		let filler = ClosureFiller::new(table_id, |events: &[u32], segment| {
+			let mut col = segment.get_scalars_mut(col)?;
+			for (dst, &event) in iter::zip(&mut *col, events) {
+				*dst = B32::new(event);
+			}
+			Ok(())
+		});
+		let events = (0..1 << 10).collect::<Vec<_>>();
+
+		// The column needs 2^8 packed elements of 128 bits.
+		let mut allocator = CpuComputeAllocator::new(1 << 4);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		assert_matches!(
+			witness.fill_table_sequential(&filler, &events),
+			Err(Error::Alloc { table_id: id, source: AllocError::OutOfMemory }) if id == table_id
+		);
+		assert_matches!(witness.init_table(table_id, events.len()), Err(Error::Alloc { .. }));
+
+		// Retry with a larger capacity.
+		let mut allocator = CpuComputeAllocator::new(1 << 8);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		witness.fill_table_sequential(&filler, &events).unwrap();
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+	}
+
+	#[test]
+	fn test_table_allocators() {
+		let mut cs = ConstraintSystem::new();
+		let mut large_table = cs.add_table("large");
+		let large_id = large_table.id();
+		let large_col = large_table.add_committed::<B32, 1>("col");
+		drop(large_table);
+		let mut small_table = cs.add_table("small");
+		let small_id = small_table.id();
+		let small_col = small_table.add_committed::<B32, 1>("col");
+		drop(small_table);
+
+		let fill = |col: Col<B32>, events: &[u32], segment: &mut TableWitnessSegment<_>| {
+			let mut col = segment.get_scalars_mut(col)?;
+			for (dst, &event) in iter::zip(&mut *col, events) {
+				*dst = B32::new(event);
+			}
+			Ok(())
+		};
+		let large_events = (0..1 << 10).collect::<Vec<_>>();
+		let small_events = (0..1 << 4).collect::<Vec<_>>();
+
+		// The shared allocator is too small for either table.
+		let mut shared = CpuComputeAllocator::new(1 << 2);
+		let shared = shared.into_bump_allocator();
+		let mut large = CpuComputeAllocator::new(1 << 8);
+		let large = large.into_bump_allocator();
+		let mut small = CpuComputeAllocator::new(1 << 4);
+		let small = small.into_bump_allocator();
+
+		let mut witness = WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &shared);
+		witness.set_table_allocator(large_id, &large).unwrap();
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(large_id, |events: &[u32], segment| {
+					fill(large_col, events, segment)
+				}),
+				&large_events,
+			)
+			.unwrap();
+		assert_eq!(large.capacity(), 0);
+		assert_eq!(small.capacity(), 1 << 4);
+
+		witness
+			.init_table_with_allocator(small_id, small_events.len(), Some(&small))
+			.unwrap()
+			.fill_sequential(
+				&ClosureFiller::new(small_id, |events: &[u32], segment| {
+					fill(small_col, events, segment)
+				}),
+				&small_events,
+			)
+			.unwrap();
+		assert_eq!(large.capacity(), 0);
+		assert_eq!(small.capacity(), (1 << 4) - (1 << 2));
+		assert_eq!(shared.capacity(), 1 << 2);
+
+		assert_matches!(
+			witness.set_table_allocator(2, &small),
+			Err(Error::MissingTable { table_id: 2 })
+		);
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+	}
+
+	#[test]
+	fn test_to_grid_string() {
+		let mut cs = ConstraintSystem::new();
+		let mut test_table = cs.add_table("incrementing");
+		test_table.require_power_of_two_size();
+		let index_col = test_table
+			.add_structured::<B32>("index", StructuredDynSize::Incrementing { max_size_log: 8 });
+		let reference_col = test_table.add_committed::<B8, 2>("reference");
+		let table_id = test_table.id();
+		drop(test_table);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |events: &[u8], segment| {
+					fill_incrementing_b32(segment, index_col)?;
+					let mut reference = segment.get_scalars_mut(reference_col)?;
+					for (row, &event) in reference.chunks_mut(2).zip(events) {
+						row[0] = B8::new(event);
+						row[1] = B8::new(0xf0 | event);
+					}
+					Ok(())
+				}),
+				&(0..1 << 4).collect::<Vec<_>>(),
+			)
+			.unwrap();
+
+		let grid = witness
+			.get_table(table_id)
+			.unwrap()
+			.to_grid_string(3)
+			.unwrap();
+		assert_eq!(
+			grid,
+			"row | index      | reference\n\
+			 0   | 0x00000000 | 0x00 0xf0\n\
+			 1   | 0x00000001 | 0x01 0xf1\n\
+			 2   | 0x00000002 | 0x02 0xf2\n"
+		);
+	}
+
+	#[test]
+	fn test_to_columnar() {
+		let mut cs = ConstraintSystem::new();
+		let mut test_table = cs.add_table("incrementing");
+		test_table.require_power_of_two_size();
+		let index_col = test_table
+			.add_structured::<B32>("index", StructuredDynSize::Incrementing { max_size_log: 8 });
+		let parity_col = test_table.add_committed::<B1, 1>("parity");
+		let reference_col = test_table.add_committed::<B8, 2>("reference");
+		let table_id = test_table.id();
+		drop(test_table);
+		let other_table_id = cs.add_table("unfilled").id();
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |events: &[u8], segment| {
+					fill_incrementing_b32(segment, index_col)?;
+					let mut parity = segment.get_mut(parity_col)?;
+					let mut reference = segment.get_scalars_mut(reference_col)?;
+					for (i, (row, &event)) in reference.chunks_mut(2).zip(events).enumerate() {
+						set_packed_slice(&mut parity, i, B1::from(event % 2 == 1));
+						row[0] = B8::new(event);
+						row[1] = B8::new(!event);
+					}
+					Ok(())
+				}),
+				&(0..1 << 5).collect::<Vec<_>>(),
+			)
+			.unwrap();
+
+		let batch = witness.to_columnar(table_id).unwrap();
+		assert_eq!(batch.n_rows, 1 << 5);
+		assert_eq!(batch.n_events, 1 << 5);
+		assert_eq!(batch.columns.len(), 3);
+		assert_eq!(batch.column("index").unwrap().values, ColumnValues::B32((0..1 << 5).collect()));
+		assert_eq!(
+			batch.column("parity").unwrap().values,
+			ColumnValues::B1((0..1 << 5).map(|i| i % 2 == 1).collect())
+		);
+		let reference = batch.column("reference").unwrap();
+		assert_eq!(reference.id, reference_col.id());
+		assert_eq!(reference.values_per_row, 2);
+		assert_eq!(
+			reference.values,
+			ColumnValues::B8((0..1 << 5).flat_map(|i: u8| [i, !i]).collect())
+		);
+
+		let unfilled = witness.to_columnar(other_table_id).unwrap();
+		assert_eq!(unfilled.n_rows, 0);
+		assert!(unfilled.columns.is_empty());
+		assert_matches!(witness.to_columnar(7), Err(Error::MissingTable { table_id: 7 }));
+	}
+
+	#[test]
+	fn test_fill_sequential_from_iter() {
+		let log_size = 10;
+		let mut cs = ConstraintSystem::new();
+		let mut test_table = cs.add_table("incrementing");
+		test_table.require_power_of_two_size();
+		let index_col = test_table.add_structured::<B32>(
+			"index",
+			StructuredDynSize::Incrementing {
+				max_size_log: log_size,
+			},
+		);
+		let event_col = test_table.add_committed::<B32, 1>("event");
+		test_table.assert_zero("event = index", event_col - index_col);
+		let table_id = test_table.id();
+		drop(test_table);
+
+		let filler = ClosureFiller::new(table_id, |events: &[u32], segment| {
+			fill_incrementing_b32(segment, index_col)?;
+			let mut event_col = segment.get_scalars_mut(event_col)?;
+			for (dst, &event) in iter::zip(&mut *event_col, events) {
+				*dst = B32::new(event);
+			}
+			Ok(())
+		});
+
+		let mut allocator = CpuComputeAllocator::new(1 << 14);
+		let allocator = allocator.into_bump_allocator();
+
+		let mut collected_index =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		collected_index
+			.fill_table_sequential(&filler, &(0..1 << log_size).collect::<Vec<_>>())
+			.unwrap();
+
+		let mut iter_index =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		iter_index
+			.fill_table_sequential_from_iter(&filler, 0..1 << log_size)
+			.unwrap();
+
+		for col in [index_col, event_col] {
+			let expected = collected_index
+				.get_table(table_id)
+				.unwrap()
+				.full_segment()
+				.get_scalars(col)
+				.unwrap()
+				.to_vec();
+			let actual = iter_index
+				.get_table(table_id)
+				.unwrap()
+				.full_segment()
+				.get_scalars(col)
+				.unwrap()
+				.to_vec();
+			assert_eq!(actual, expected);
+		}
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, iter_index, vec![]);
+	}
+
+	#[derive(Debug, Default)]
+	struct RecordingObserver {
+		events: Mutex<Vec<(&'static str, TableId, Option<usize>)>>,
+	}
+
+	impl FillObserver for RecordingObserver {
+		fn on_table_fill_start(&self, table_id: TableId) {
+			self.events
+				.lock()
+				.unwrap()
+				.push(("table_start", table_id, None));
+		}
+
+		fn on_table_fill_end(&self, table_id: TableId, _duration: Duration) {
+			self.events
+				.lock()
+				.unwrap()
+				.push(("table_end", table_id, None));
+		}
+
+		fn on_segment_fill_start(&self, table_id: TableId, segment_index: usize) {
+			self.events
+				.lock()
+				.unwrap()
+				.push(("segment_start", table_id, Some(segment_index)));
+		}
+
+		fn on_segment_fill_end(
+			&self,
+			table_id: TableId,
+			segment_index: usize,
+			_duration: Duration,
+		) {
+			self.events
+				.lock()
+				.unwrap()
+				.push(("segment_end", table_id, Some(segment_index)));
+		}
+	}
+
+	type Packed128b = PackedType<OptimalUnderlier128b, B128>;
+
+	#[derive(Default)]
+	struct RecordingCommitObserver {
+		columns: Mutex<Vec<(TableId, ColumnId, Vec<Packed128b>)>>,
+	}
+
+	impl CommitObserver<Packed128b> for RecordingCommitObserver {
+		fn on_column_filled(&self, table_id: TableId, column_id: ColumnId, data: &[Packed128b]) {
+			self.columns
+				.lock()
+				.unwrap()
+				.push((table_id, column_id, data.to_vec()));
+		}
+	}
+
+	#[test]
+	fn test_commit_observer() {
+		let log_size = 6;
+		let mut cs = ConstraintSystem::new();
+		let mut test_table = cs.add_table("incrementing");
+		test_table.require_power_of_two_size();
+		let incrementing = test_table.add_structured::<B32>(
+			"incrementing",
+			StructuredDynSize::Incrementing {
+				max_size_log: log_size,
+			},
+		);
+		let committed = test_table.add_committed::<B32, 1>("committed");
+		let table_id = test_table.id();
+		drop(test_table);
+		let mut other_table = cs.add_table("other");
+		let other = other_table.add_committed::<B32, 1>("other");
+		let other_id = other_table.id();
+		drop(other_table);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let observer = Arc::new(RecordingCommitObserver::default());
+		let mut index =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		index.set_commit_observer(observer.clone());
+		index
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |events: &[u32], segment| {
+					fill_incrementing_b32(segment, incrementing)?;
+					let mut committed = segment.get_scalars_mut(committed)?;
+					for (value, &event) in committed.iter_mut().zip(events) {
+						*value = B32::new(3 * event);
+					}
+					Ok(())
+				}),
+				&(0..1 << log_size).collect::<Vec<_>>(),
+			)
+			.unwrap();
+		assert_eq!(observer.columns.lock().unwrap().len(), 2);
+		index
+			.fill_table_parallel(
+				&ClosureFiller::new(other_id, |_events: &[()], segment| {
+					segment.get_scalars_mut(other)?.fill(B32::new(7));
+					Ok(())
+				}),
+				&[(); 4],
+			)
+			.unwrap();
+
+		let columns = observer.columns.lock().unwrap();
+		let ids = columns
+			.iter()
+			.map(|&(table_id, column_id, _)| (table_id, column_id))
+			.collect::<Vec<_>>();
+		assert_eq!(
+			ids,
+			[
+				(table_id, incrementing.id()),
+				(table_id, committed.id()),
+				(other_id, other.id())
+			]
+		);
+		// The observed data is the filled column.
+		let segment = index.get_table(table_id).unwrap().full_segment();
+		for (col, (_, _, data)) in [incrementing, committed].into_iter().zip(&*columns) {
+			assert_eq!(*segment.get(col).unwrap(), *PackedExtension::<B32>::cast_bases(data));
+		}
+	}
+
+	#[test]
+	fn test_fill_observer() {
+		let log_size = 8;
+		let mut cs = ConstraintSystem::new();
+		let mut test_table = cs.add_table("incrementing");
+		test_table.require_power_of_two_size();
+		let incrementing = test_table.add_structured::<B32>(
+			"incrementing",
+			StructuredDynSize::Incrementing {
+				max_size_log: log_size,
+			},
+		);
+		let table_id = test_table.id();
+		drop(test_table);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let observer = Arc::new(RecordingObserver::default());
+		let mut index =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		index.set_fill_observer(observer.clone());
+		index
+			.init_table(table_id, 1 << log_size)
+			.unwrap()
+			.fill_sequential_with_segment_size(
+				&ClosureFiller::new(table_id, |_events: &[()], segment| {
+					fill_incrementing_b32(segment, incrementing)?;
+					Ok(())
+				}),
+				&[(); 1 << 8],
+				5,
+			)
+			.unwrap();
+
+		let events = observer.events.lock().unwrap();
+		let mut expected = vec![("table_start", table_id, None)];
+		for i in 0..1 << (log_size - 5) {
+			expected.push(("segment_start", table_id, Some(i)));
+			expected.push(("segment_end", table_id, Some(i)));
+		}
+		expected.push(("table_end", table_id, None));
+		assert_eq!(*events, expected);
+	}
+
+	#[test]
+	fn test_zero_constraint_failure_values() {
+		let mut cs = ConstraintSystem::new();
+		let mut test_table = cs.add_table("test");
+		test_table.require_power_of_two_size();
+		let reference_col = test_table.add_committed::<B32, 1>("reference");
+		let structured_col = test_table.add_structured::<B32>(
+			"incrementing",
+			StructuredDynSize::Incrementing { max_size_log: 5 },
+		);
+		test_table.assert_zero("reference = structured", reference_col - structured_col);
+		let table_id = test_table.id();
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut index =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		index
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |events: &[u32], segment| {
+					let mut reference = segment.get_scalars_mut(reference_col)?;
+					for (i, &event) in events.iter().enumerate() {
+						// Corrupt a single row.
+						reference[i] = B32::new(if event == 13 { 42 } else { event });
+					}
+					drop(reference);
+					crate::gadgets::structured::fill_incrementing_b32(segment, structured_col)?;
+					Ok(())
+				}),
+				&(0..1 << 5).collect::<Vec<_>>(),
+			)
+			.unwrap();
+
+		let failures = index.zero_constraint_failures().unwrap();
+		assert_eq!(failures.len(), 1);
+		let failure = &failures[0];
+		assert_eq!(failure.row, 13);
+		assert_eq!(
+			failure.values,
+			vec![
+				("reference".to_string(), B128::from(B32::new(42))),
+				("incrementing".to_string(), B128::from(B32::new(13))),
+			]
+		);
+
+		let diagnostic = failure.to_string();
+		assert!(diagnostic.contains("reference = structured"));
+		assert!(diagnostic.contains(&B128::from(B32::new(42)).to_string()));
+		assert!(diagnostic.contains(&B128::from(B32::new(13)).to_string()));
+	}
+
+	#[test]
+	fn test_zero_constraint_failures_packed_matches_scalar() {
+		const LOG_SIZE: usize = 12;
+		let mut cs = ConstraintSystem::new();
+		let mut test_table = cs.add_table("test");
+		let x_col = test_table.add_committed::<B32, 1>("x");
+		let y_col = test_table.add_committed::<B32, 1>("y");
+		let z_col = test_table.add_committed::<B8, 4>("z");
+		test_table.assert_zero("y = x * x", y_col - x_col * x_col);
+		test_table.assert_zero("z * z = z", z_col * z_col - z_col);
+		let table_id = test_table.id();
+
+		for corrupt in [false, true] {
+			let mut allocator = CpuComputeAllocator::new(1 << 16);
+			let allocator = allocator.into_bump_allocator();
+			let mut index =
+				WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+			index
+				.fill_table_sequential(
+					&ClosureFiller::new(table_id, |events: &[u32], segment| {
+						let mut x = segment.get_scalars_mut(x_col)?;
+						let mut y = segment.get_scalars_mut(y_col)?;
+						let mut z = segment.get_scalars_mut(z_col)?;
+						for (i, &event) in events.iter().enumerate() {
+							x[i] = B32::new(event);
+							y[i] = x[i] * x[i];
+							z[4 * i..4 * (i + 1)].fill(B8::new((event & 1) as u8));
+							if corrupt && event == 3001 {
+								y[i] += B32::new(1);
+							}
+							if corrupt && event == 1234 {
+								z[4 * i + 2] = B8::new(2);
+							}
+						}
+						Ok(())
+					}),
+					&(0..1 << LOG_SIZE).collect::<Vec<_>>(),
+				)
+				.unwrap();
+
+			let packed = index.zero_constraint_failures().unwrap();
+			let scalar = index.zero_constraint_failures_scalar().unwrap();
+			assert_eq!(packed.len(), scalar.len());
+			for (packed, scalar) in iter::zip(&packed, &scalar) {
+				assert_eq!(packed.constraint_name, scalar.constraint_name);
+				assert_eq!((packed.row, packed.value_index), (scalar.row, scalar.value_index));
+				assert_eq!(packed.to_string(), scalar.to_string());
+			}
+
+			if corrupt {
+				let mut rows = packed
+					.iter()
+					.map(|failure| (failure.constraint_name.as_str(), failure.row))
+					.collect::<Vec<_>>();
+				rows.sort();
+				assert_eq!(rows, vec![("y = x * x", 3001), ("z * z = z", 1234)]);
+				assert_eq!(
+					packed
+						.iter()
+						.find(|failure| failure.row == 1234)
+						.unwrap()
+						.value_index,
+					2
+				);
+			} else {
+				assert!(packed.is_empty());
+			}
+		}
+	}
+
+	#[test]
+	fn test_get_structured_values() {
+		let mut cs = ConstraintSystem::new();
+		let mut test_table = cs.add_table("test");
+		test_table.require_power_of_two_size();
+		let structured_col = test_table.add_structured::<B32>(
+			"incrementing",
+			StructuredDynSize::Incrementing { max_size_log: 8 },
+		);
+		let derived_col = test_table.add_committed::<B32, 1>("derived");
+		let scale = B32::new(0x1234);
+		test_table
+			.assert_zero("derived = incrementing * scale", derived_col - structured_col * scale);
+		let table_id = test_table.id();
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut index =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		let table_index = index.init_table(table_id, 1 << 6).unwrap();
+		table_index
+			.fill_sequential_with_segment_size(
+				&ClosureFiller::new(table_id, |_events: &[()], segment| {
+					{
+						let mut derived = segment.get_scalars_mut(derived_col)?;
+						for (derived_i, value) in
+							iter::zip(&mut *derived, segment.get_structured_values(structured_col)?)
+						{
+							*derived_i = value * scale;
+						}
+					}
+					crate::gadgets::structured::fill_incrementing_b32(segment, structured_col)?;
+					Ok(())
+				}),
+				&[(); 1 << 6],
+				4,
+			)
+			.unwrap();
+
+		{
+			let segment = table_index.full_segment();
+			let values = segment
+				.get_structured_values(structured_col)
+				.unwrap()
+				.collect::<Vec<_>>();
+			assert_eq!(values, (0..1 << 6).map(B32::new).collect::<Vec<_>>());
+			assert_matches!(
+				segment.get_structured_values(derived_col).err(),
+				Some(Error::NotStructuredColumn(_))
+			);
+		}
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, index, vec![]);
+	}
+
+	#[test]
+	fn test_fill_blinding_rows() {
+		let n_events = 28;
+		let n_blinding_rows = 4;
+
+		let mut cs = ConstraintSystem::new();
+		let channel = cs.add_channel("values");
+		let mut test_table = cs.add_table("test");
+		test_table.require_power_of_two_size();
+		test_table.add_blinding_rows(n_blinding_rows);
+		let is_event = test_table.add_committed::<B1, 1>("is_event");
+		let x_col = test_table.add_committed::<B32, 1>("x");
+		let y_col = test_table.add_committed::<B8, 4>("y");
+		let incrementing = StructuredDynSize::Incrementing { max_size_log: 5 };
+		let structured_col = test_table.add_structured::<B32>("incrementing", incrementing);
+		// The constraint and the flush are gated by a selector that is zero on the blinding rows.
+		test_table.assert_zero(
+			"x = incrementing on events",
+			upcast_col(is_event) * (x_col - structured_col),
+		);
+		test_table.push_with_opts(
+			channel,
+			[x_col],
+			FlushOpts {
+				selectors: vec![is_event],
+				..FlushOpts::default()
+			},
+		);
+		let table_id = test_table.id();
+		drop(test_table);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut index =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		{
+			let table_index = index.init_table(table_id, n_events).unwrap();
+			table_index
+				.fill_sequential(
+					&ClosureFiller::new(table_id, |events: &[u32], segment| {
+						{
+							let mut is_event = segment.get_mut(is_event)?;
+							let mut x = segment.get_scalars_mut(x_col)?;
+							let mut y = segment.get_scalars_mut(y_col)?;
+							for (i, &event) in events.iter().enumerate() {
+								set_packed_slice(&mut is_event, i, B1::from(1));
+								x[i] = B32::new(event);
+								y[4 * i..4 * (i + 1)].fill(B8::new(event as u8));
+							}
+						}
+						fill_incrementing_b32(segment, structured_col)?;
+						Ok(())
+					}),
+					&(0..n_events as u32).collect::<Vec<_>>(),
+				)
+				.unwrap();
+			table_index
+				.fill_blinding_rows(&mut StdRng::seed_from_u64(0))
+				.unwrap();
+
+			let segment = table_index.full_segment();
+			let is_event = segment.get(is_event).unwrap();
+			let x = segment.get_scalars(x_col).unwrap();
+			let y = segment.get_scalars(y_col).unwrap();
+			for row in 0..n_events {
+				assert_eq!(x[row], B32::new(row as u32));
+			}
+			for row in n_events..n_events + n_blinding_rows {
+				assert_eq!(get_packed_slice(&is_event, row), B1::from(0));
+				assert_ne!(x[row], B32::new(0));
+				assert!(y[4 * row..4 * (row + 1)].iter().all(|&y| y != B8::new(0)));
+			}
+		}
+
+		// The blinding rows count towards the table size, which the structured column must fit.
+		let table_sizes = index.table_sizes();
+		assert_eq!(table_sizes, vec![n_events + n_blinding_rows]);
+		incrementing
+			.check_nvars(log2_strict_usize(table_sizes[table_id]))
+			.unwrap();
+
+		// The zero constraints hold on the logical region.
+		assert!(index.zero_constraint_failures().unwrap().is_empty());
+
+		// The compiled constraint system holds on all the rows, and only the events are flushed.
+		let boundaries = (0..n_events as u32)
+			.map(|event| Boundary {
+				values: vec![B32::new(event).into()],
+				channel_id: channel,
+				direction: FlushDirection::Pull,
+				multiplicity: 1,
+			})
+			.collect();
+		validate_system_witness::<OptimalUnderlier128b>(&cs, index, boundaries);
+	}
+
+	#[test]
+	fn test_blinding_rows_reject_unselected_flush() {
+		let mut cs = ConstraintSystem::<B128>::new();
+		let channel = cs.add_channel("values");
+		let mut test_table = cs.add_table("test");
+		test_table.add_blinding_rows(4);
+		let x_col = test_table.add_committed::<B32, 1>("x");
+		test_table.push(channel, [x_col]);
+		drop(test_table);
+
+		assert_matches!(cs.compile(), Err(Error::UnselectedFlushWithBlindingRows { table_id: 0 }));
+	}
+
+	fn find_oracle_id_with_name(
+		oracles: &SymbolicMultilinearOracleSet<B128>,
+		name: &str,
+	) -> Option<OracleId> {
+		oracles
+			.iter()
+			.find(|(_, oracle)| oracle.name.as_deref() == Some(name))
+			.map(|(id, _)| id)
+	}
+
+	#[test]
+	fn test_constant_filling() {
+		let mut cs = ConstraintSystem::new();
+
+		let mut test_table = cs.add_table("test");
+		let mut rng = StdRng::seed_from_u64(0);
+		let unpack_value = B16::random(&mut rng);
+		let pack_const_arr: [B32; 4] = array::from_fn(|_| B32::random(&mut rng));
+
+		let _ = test_table.add_constant("unpacked_col", [unpack_value]);
+		let _ = test_table.add_constant("packed_col", pack_const_arr);
+		let table_id = test_table.id();
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+
+		let table_size = 123;
+		let ccs = cs.compile().unwrap();
+		let mut index = WitnessIndex::<PackedType<OptimalUnderlier, B128>>::new(&cs, &allocator);
+
+		{
+			let _ = index.init_table(table_id, table_size).unwrap();
 			index.fill_constant_cols().unwrap();
 		}
 
@@ -1751,4 +4823,311 @@ mod tests {
 			assert_eq!(got, pack_const_arr[index % 4].into());
 		}
 	}
+
+	#[test]
+	fn test_structured_as_committed() {
+		const LOG_SIZE: usize = 5;
+		let mut cs = ConstraintSystem::new();
+		cs.structured_as_committed = true;
+		let mut table = cs.add_table("incrementing");
+		table.require_power_of_two_size();
+		let table_id = table.id();
+		let incrementing = table.add_structured::<B32>(
+			"incrementing",
+			StructuredDynSize::Incrementing {
+				max_size_log: LOG_SIZE,
+			},
+		);
+		let committed = table.add_committed::<B32, 1>("committed");
+		table.assert_zero("committed = incrementing", committed - incrementing);
+		drop(table);
+
+		let ccs = cs.compile().unwrap();
+		let oracle_id = find_oracle_id_with_name(&ccs.oracles, "incrementing").unwrap();
+		assert_matches!(ccs.oracles[oracle_id].variant, SymbolicMultilinearPolyVariant::Committed);
+		assert!(
+			cs.to_verifier().unwrap().tables[table_id]
+				.structured_columns
+				.is_empty()
+		);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut index =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		index
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |_events: &[()], segment| {
+					let values = segment
+						.get_structured_values(incrementing)?
+						.collect::<Vec<_>>();
+					segment.get_scalars_mut(committed)?.copy_from_slice(&values);
+					Ok(())
+				}),
+				&[(); 1 << LOG_SIZE],
+			)
+			.unwrap();
+		index.fill_structured_cols().unwrap();
+
+		let expected = (0..1 << LOG_SIZE).map(B32::new).collect::<Vec<_>>();
+		assert_eq!(
+			*index
+				.get_table(table_id)
+				.unwrap()
+				.full_segment()
+				.get_scalars(incrementing)
+				.unwrap(),
+			*expected
+		);
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, index, vec![]);
+	}
+
+	#[test]
+	#[cfg(debug_assertions)]
+	fn test_unwritten_committed_column() {
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("incrementing");
+		table.require_power_of_two_size();
+		let table_id = table.id();
+		let incrementing = table.add_structured::<B32>(
+			"incrementing",
+			StructuredDynSize::Incrementing { max_size_log: 8 },
+		);
+		let reference = table.add_committed::<B32, 1>("reference");
+		let forgotten = table.add_committed::<B32, 1>("forgotten");
+		table.assert_zero("reference = incrementing", reference - incrementing);
+		table.assert_zero("forgotten = 0", forgotten.into());
+		drop(table);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut index =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		index
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |_events: &[()], segment| {
+					fill_incrementing_b32(segment, reference)?;
+					Ok(())
+				}),
+				&[(); 1 << 6],
+			)
+			.unwrap();
+
+		assert_matches!(
+			index.check_committed_columns_written(),
+			Err(Error::UnwrittenColumn { column, row: 0, .. }) if column == "forgotten"
+		);
+
+		index
+			.get_table(table_id)
+			.unwrap()
+			.full_segment()
+			.fill_zero(forgotten)
+			.unwrap();
+		index.check_committed_columns_written().unwrap();
+	}
+
+	#[test]
+	fn test_update_column() {
+		const LOG_SIZE: usize = 6;
+
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("incrementing");
+		table.require_power_of_two_size();
+		let table_id = table.id();
+		let incrementing = table.add_structured::<B32>(
+			"incrementing",
+			StructuredDynSize::Incrementing { max_size_log: 8 },
+		);
+		let reference = table.add_committed::<B32, 1>("reference");
+		table.assert_zero("reference = incrementing", reference - incrementing);
+		drop(table);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut index =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		index
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |_events: &[()], segment| {
+					fill_incrementing_b32(segment, incrementing)?;
+					Ok(())
+				}),
+				&[(); 1 << LOG_SIZE],
+			)
+			.unwrap();
+		assert!(!index.zero_constraint_failures().unwrap().is_empty());
+
+		let table_index = index.get_table(table_id).unwrap();
+		let values = (0..1 << LOG_SIZE).map(B32::new).collect::<Vec<_>>();
+		assert_matches!(
+			table_index.update_column(reference, &values[1..]),
+			Err(Error::IncorrectColumnLength { expected, actual, .. })
+				if expected == 1 << LOG_SIZE && actual == expected - 1
+		);
+		table_index.update_column(reference, &values).unwrap();
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, index, vec![]);
+	}
+	#[test]
+	fn test_fill_table_phases() {
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("phased");
+		let a = table.add_committed::<B32, 1>("a");
+		let shifted = table.add_committed_phase::<B32, 1>("shifted", 1);
+		let inverse = table.add_committed_phase::<B32, 1>("inverse", 1);
+		table.assert_zero("shifted * inverse = 1", shifted * inverse - B32::new(1));
+		let table_id = table.id();
+		drop(table);
+		assert_eq!(cs.tables[table_id].n_phases(), 2);
+
+		/// Fills `a` in phase 0, and `a + gamma` and its inverse in phase 1.
+		struct Filler {
+			table_id: TableId,
+			a: Col<B32>,
+			shifted: Col<B32>,
+			inverse: Col<B32>,
+		}
+
+		impl PhasedTableFiller for Filler {
+			type Event = u32;
+
+			fn id(&self) -> TableId {
+				self.table_id
+			}
+
+			fn fill_phase(
+				&self,
+				phase: usize,
+				challenges: &[B128],
+				rows: &[u32],
+				witness: &mut TableWitnessSegment,
+			) -> anyhow::Result<()> {
+				if phase == 0 {
+					let mut a = witness.get_scalars_mut(self.a)?;
+					for (a, &row) in a.iter_mut().zip(rows) {
+						*a = B32::new(row);
+					}
+				} else {
+					let gamma = B32::try_from(challenges[0])
+						.map_err(|_| anyhow::anyhow!("the challenge must be in B32"))?;
+					let a = witness.get_scalars(self.a)?;
+					let mut shifted = witness.get_scalars_mut(self.shifted)?;
+					let mut inverse = witness.get_scalars_mut(self.inverse)?;
+					for ((&a, shifted), inverse) in a.iter().zip(&mut *shifted).zip(&mut *inverse) {
+						*shifted = a + gamma;
+						*inverse = shifted.invert_or_zero();
+					}
+				}
+				Ok(())
+			}
+		}
+
+		let filler = Filler {
+			table_id,
+			a,
+			shifted,
+			inverse,
+		};
+		let rows = (0..50).collect::<Vec<u32>>();
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		assert_matches!(
+			witness.fill_table_phase(&filler, 1, &[], &rows),
+			Err(Error::PhaseOutOfOrder {
+				phase: 1,
+				expected: 0,
+				..
+			})
+		);
+		witness.fill_table_phase(&filler, 0, &[], &rows).unwrap();
+
+		// The challenge is injected after phase 0 and takes a value that no row of `a` has.
+		let gamma = B32::new(0x8000_0000);
+		witness
+			.fill_table_phase(&filler, 1, &[gamma.into()], &rows)
+			.unwrap();
+		assert_matches!(
+			witness.fill_table_phase(&filler, 1, &[gamma.into()], &rows),
+			Err(Error::PhaseOutOfOrder {
+				expected: 2,
+				n_phases: 2,
+				..
+			})
+		);
+		{
+			let segment = witness.get_table(table_id).unwrap().full_segment();
+			let shifted = segment.get_scalars(shifted).unwrap();
+			for (&row, &shifted) in rows.iter().zip(&*shifted) {
+				assert_eq!(shifted, B32::new(row) + gamma);
+			}
+		}
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+	}
+	#[test]
+	fn test_retarget() {
+		/// Builds a system with one table, and returns its incrementing and committed columns.
+		fn build_cs<FX: TowerField>(name: &str) -> (ConstraintSystem, Col<B32>, Col<FX>)
+		where
+			B32: ExtensionField<FX>,
+			B128: ExtensionField<FX>,
+		{
+			let mut cs = ConstraintSystem::new();
+			let mut table = cs.add_table(name);
+			table.require_power_of_two_size();
+			let index_col = table.add_structured::<B32>(
+				"index",
+				StructuredDynSize::Incrementing { max_size_log: 8 },
+			);
+			let x_col = table.add_committed::<FX, 1>("x");
+			table.assert_zero("x = index", crate::builder::upcast_col(x_col) - index_col);
+			drop(table);
+			(cs, index_col, x_col)
+		}
+
+		let (cs, index_col, x_col) = build_cs::<B32>("first");
+		let table_id = x_col.table_id;
+		let filler = ClosureFiller::new(table_id, |events: &[u32], segment| {
+			fill_incrementing_b32(segment, index_col)?;
+			let mut x = segment.get_scalars_mut(x_col)?;
+			for (x, &event) in iter::zip(&mut *x, events) {
+				*x = B32::new(event);
+			}
+			Ok(())
+		});
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let fill_witness = || {
+			let mut witness =
+				WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+			witness
+				.fill_table_sequential(&filler, &(0..32).collect::<Vec<_>>())
+				.unwrap();
+			witness
+		};
+
+		// A system with other names and the same layout reuses the filled columns.
+		let (other_cs, ..) = build_cs::<B32>("second");
+		let witness = fill_witness().retarget(&other_cs).unwrap();
+		assert_eq!(witness.table_sizes(), [32]);
+		validate_system_witness::<OptimalUnderlier128b>(&other_cs, witness, vec![]);
+
+		assert_matches!(
+			fill_witness().retarget(&build_cs::<B16>("narrow").0).err(),
+			Some(Error::IncompatibleTable { table_id: 0, .. })
+		);
+		assert_matches!(
+			fill_witness().retarget(&ConstraintSystem::new()).err(),
+			Some(Error::IncorrectNumberOfTables {
+				expected: 1,
+				actual: 0
+			})
+		);
+	}
 }
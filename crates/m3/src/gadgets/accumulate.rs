@@ -0,0 +1,262 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_core::oracle::ShiftVariant;
+use binius_field::{
+	ExtensionField, PackedExtension, PackedFieldIndexable, PackedSubfield, TowerField,
+};
+
+use crate::builder::{B128, Col, Error, TableBuilder, TableWitnessSegment};
+
+/// A conditional accumulation (masked sum) of a column over the binary field.
+///
+/// The accumulation runs over all values of the column in row-major order: the output is
+/// constrained with the recurrence `acc[i] = acc[i - 1] + mask[i] * value[i]`, where `acc[-1]` is
+/// zero, and the mask is constrained to be boolean. This is the inner product of the mask and the
+/// values, with every partial sum exposed. The previous value `acc[i - 1]` is a shifted column of
+/// the accumulator across the rows of the table, see [`TableBuilder::add_table_shifted`].
+///
+/// ## Requirements
+///
+/// * The table must have a fixed size, see [`TableBuilder::require_fixed_size`], because the shift
+///   spans the whole column.
+/// * The segments of the table are not independent: the first value of a segment depends on the
+///   last accumulator of the previous one. [`Self::populate`] takes that accumulator and returns
+///   the last accumulator of the segment, so the segments must be filled sequentially in order,
+///   threading the returned value into the next segment. Parallel fills are not supported.
+#[derive(Debug)]
+pub struct MaskedSum<FSub: TowerField, const V: usize> {
+	pub mask: Col<FSub, V>,
+	pub value: Col<FSub, V>,
+	pub acc: Col<FSub, V>,
+	/// The accumulator shifted by one value across the rows.
+	acc_prev: Col<FSub, V>,
+}
+
+impl<FSub: TowerField, const V: usize> MaskedSum<FSub, V>
+where
+	B128: ExtensionField<FSub>,
+{
+	/// Creates the masked sum of `value` with a committed accumulator column.
+	///
+	/// ## Pre-conditions
+	///
+	/// * The table size must have been fixed with [`TableBuilder::require_fixed_size`].
+	pub fn new(table: &mut TableBuilder, mask: Col<FSub, V>, value: Col<FSub, V>) -> Self {
+		let acc = table.add_committed("masked_sum");
+		let acc_prev =
+			table.add_table_shifted("masked_sum_prev", acc, 1, ShiftVariant::LogicalLeft);
+		table.assert_boolean("masked_sum mask is boolean", mask.into());
+		table.assert_zero("masked_sum recurrence", acc - acc_prev - mask * value);
+		Self {
+			mask,
+			value,
+			acc,
+			acc_prev,
+		}
+	}
+
+	/// Populates the accumulator and the auxiliary shifted column of a segment.
+	///
+	/// `prev` is the last accumulator of the previous segment, or zero for the first segment.
+	/// Returns the last accumulator of this segment, to pass to the next one. The mask and value
+	/// columns must already be populated.
+	pub fn populate<P>(&self, index: &mut TableWitnessSegment<P>, prev: FSub) -> Result<FSub, Error>
+	where
+		P: PackedExtension<FSub, Scalar = B128>,
+		PackedSubfield<P, FSub>: PackedFieldIndexable,
+	{
+		let last = fill_masked_sum(index, self.mask, self.value, self.acc, prev)?;
+
+		let acc = index.get_scalars(self.acc)?;
+		let mut acc_prev = index.get_scalars_mut(self.acc_prev)?;
+		acc_prev[0] = prev;
+		acc_prev[1..].copy_from_slice(&acc[..acc.len() - 1]);
+		Ok(last)
+	}
+}
+
+/// Fills `acc_col` with the masked sum of `value_col` over the values of a segment.
+///
+/// The value of `acc_col` is `acc[i] = acc[i - 1] + mask[i] * value[i]`, starting from `prev`, the
+/// last accumulator of the previous segment, or zero for the first segment. Returns the last
+/// accumulator of the segment. See [`MaskedSum`] for the constrained version and the segmentation
+/// requirements.
+pub fn fill_masked_sum<P, FSub, const V: usize>(
+	index: &mut TableWitnessSegment<P>,
+	mask_col: Col<FSub, V>,
+	value_col: Col<FSub, V>,
+	acc_col: Col<FSub, V>,
+	prev: FSub,
+) -> Result<FSub, Error>
+where
+	P: PackedExtension<FSub, Scalar = B128>,
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+	PackedSubfield<P, FSub>: PackedFieldIndexable,
+{
+	let mask = index.get_scalars(mask_col)?;
+	let value = index.get_scalars(value_col)?;
+	let mut acc = index.get_scalars_mut(acc_col)?;
+	let mut sum = prev;
+	for ((&m, &v), acc) in mask.iter().zip(value.iter()).zip(acc.iter_mut()) {
+		sum += m * v;
+		*acc = sum;
+	}
+	Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_compute::{alloc::HostBumpAllocator, cpu::alloc::CpuComputeAllocator};
+	use binius_field::{Field, arch::OptimalUnderlier128b, as_packed_field::PackedType};
+	use rand::{Rng, SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::builder::{
+		B32, ConstraintSystem, WitnessIndex,
+		test_utils::{assert_rejected_across_rows, fill_table_with_carry, validate_system_witness},
+	};
+
+	const LOG_N_ROWS: usize = 3;
+	const LOG_V: usize = 5;
+
+	type Event = Vec<(B32, B32)>;
+
+	fn random_events(rng: &mut StdRng) -> Vec<Event> {
+		repeat_with(|| {
+			repeat_with(|| {
+				let mask = if rng.random() { B32::ONE } else { B32::ZERO };
+				(mask, B32::random(&mut *rng))
+			})
+			.take(1 << LOG_V)
+			.collect()
+		})
+		.take(1 << LOG_N_ROWS)
+		.collect()
+	}
+
+	fn new_masked_sum(cs: &mut ConstraintSystem) -> MaskedSum<B32, { 1 << LOG_V }> {
+		let mut table = cs.add_table("masked_sum");
+		table.require_fixed_size(LOG_N_ROWS);
+		let mask = table.add_committed::<B32, { 1 << LOG_V }>("mask");
+		let value = table.add_committed::<B32, { 1 << LOG_V }>("value");
+		MaskedSum::new(&mut table, mask, value)
+	}
+
+	/// Fills the table in segments of two rows, carrying the accumulator across the segments.
+	fn build_witness<'a>(
+		cs: &'a ConstraintSystem,
+		allocator: &'a HostBumpAllocator<'a, PackedType<OptimalUnderlier128b, B128>>,
+		masked_sum: &MaskedSum<B32, { 1 << LOG_V }>,
+		events: &[Event],
+	) -> WitnessIndex<'a, 'a, PackedType<OptimalUnderlier128b, B128>> {
+		let mut witness = WitnessIndex::new(cs, allocator);
+		fill_table_with_carry(
+			&mut witness,
+			masked_sum.acc.table_id,
+			events,
+			1,
+			B32::ZERO,
+			|events: &[Event], index, carry| {
+				{
+					let mut mask = index.get_scalars_mut(masked_sum.mask)?;
+					let mut value = index.get_scalars_mut(masked_sum.value)?;
+					for (i, &(m, v)) in events.iter().flatten().enumerate() {
+						mask[i] = m;
+						value[i] = v;
+					}
+				}
+				Ok(masked_sum.populate(index, carry)?)
+			},
+		)
+		.unwrap();
+		witness
+	}
+
+	#[test]
+	fn test_masked_sum() {
+		let mut cs = ConstraintSystem::new();
+		let masked_sum = new_masked_sum(&mut cs);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let events = random_events(&mut rng);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 14);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness = build_witness(&cs, &allocator, &masked_sum, &events);
+
+		{
+			let table_witness = witness.get_table(masked_sum.acc.table_id).unwrap();
+			let segment = table_witness.full_segment();
+			let acc = segment.get_scalars(masked_sum.acc).unwrap();
+			let mut expected = B32::ZERO;
+			for (&acc, &(m, v)) in acc.iter().zip(events.iter().flatten()) {
+				if m == B32::ONE {
+					expected += v;
+				}
+				assert_eq!(acc, expected);
+			}
+		}
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+	}
+
+	#[test]
+	fn test_masked_sum_rejects_restarted_sum() {
+		let mut cs = ConstraintSystem::new();
+		let masked_sum = new_masked_sum(&mut cs);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let events = random_events(&mut rng);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 14);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness = build_witness(&cs, &allocator, &masked_sum, &events);
+
+		// Restart the accumulation in the middle of the table, keeping the shifted column
+		// consistent with the accumulator, as a fill that drops the carry between segments would.
+		{
+			let segment = witness
+				.get_table(masked_sum.acc.table_id)
+				.unwrap()
+				.full_segment();
+			let start = (1 << LOG_N_ROWS) * (1 << LOG_V) / 2;
+			let mask = segment.get_scalars(masked_sum.mask).unwrap();
+			let value = segment.get_scalars(masked_sum.value).unwrap();
+			let mut acc = segment.get_scalars_mut(masked_sum.acc).unwrap();
+			let mut acc_prev = segment.get_scalars_mut(masked_sum.acc_prev).unwrap();
+			assert_ne!(acc[start - 1], B32::ZERO);
+			let mut sum = B32::ZERO;
+			for i in start..acc.len() {
+				acc_prev[i] = sum;
+				sum += mask[i] * value[i];
+				acc[i] = sum;
+			}
+		}
+
+		assert_rejected_across_rows(&cs, witness);
+	}
+
+	#[test]
+	fn test_masked_sum_non_boolean_mask() {
+		let mut cs = ConstraintSystem::new();
+		let masked_sum = new_masked_sum(&mut cs);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut events = random_events(&mut rng);
+		events[3][7].0 = B32::new(2);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 14);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness = build_witness(&cs, &allocator, &masked_sum, &events);
+
+		// The accumulator follows the recurrence for any mask, so only the boolean check fails.
+		let failures = witness.zero_constraint_failures().unwrap();
+		assert_eq!(failures.len(), 1);
+		assert_eq!(failures[0].constraint_name, "masked_sum mask is boolean");
+		assert_eq!(failures[0].row, 3);
+		assert_eq!(failures[0].value_index, 7);
+	}
+}
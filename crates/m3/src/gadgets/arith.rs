@@ -0,0 +1,545 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::array;
+
+use binius_core::oracle::ShiftVariant;
+use binius_field::{
+	ExtensionField, Field, PackedExtension, PackedField, PackedSubfield, TowerField,
+	packed::{get_packed_slice, set_packed_slice},
+};
+use binius_utils::checked_arithmetics::log2_strict_usize;
+use itertools::izip;
+
+use crate::builder::{B1, B128, Col, TableBuilder, TableWitnessSegment};
+
+/// The carry columns of a multi-limb integer addition.
+///
+/// Each limb is a vertically-packed bit column with `LIMB_BITS` bits per row. The limbs are in
+/// little-endian order, so limb `0` holds the least significant bits of the integer. For every limb
+/// `i` the gadget constrains the integer relation
+///
+/// ```text
+/// a[i] + b[i] + carry_in[i] == sum[i] + carry_out[i] * 2^LIMB_BITS
+/// ```
+///
+/// where `carry_in[0]` is zero and `carry_in[i]` is `carry_out[i - 1]` for the other limbs. The
+/// relation is enforced bitwise with the same ripple-carry constraints as
+/// [`U32Add`](crate::gadgets::add::U32Add): a committed column holds the carry out of every bit,
+/// and the carry into each bit is the carry out of the bit below it, crossing limb boundaries.
+///
+/// The carry out of the most significant limb is exposed as [`Self::carry_out`].
+#[derive(Debug)]
+pub struct LimbCarries<const LIMB_BITS: usize, const N_LIMBS: usize> {
+	/// The carry out of every bit of every limb.
+	cout: [Col<B1, LIMB_BITS>; N_LIMBS],
+	/// `cout` shifted up by one bit within each limb.
+	cout_shl: [Col<B1, LIMB_BITS>; N_LIMBS],
+	/// The carry out of the previous limb, placed at bit 0. `None` for the first limb.
+	limb_carry_in: [Option<Col<B1, LIMB_BITS>>; N_LIMBS],
+	/// The carry into every bit of every limb.
+	cin: [Col<B1, LIMB_BITS>; N_LIMBS],
+	/// The carry out of the most significant limb.
+	pub carry_out: Col<B1>,
+}
+
+/// Asserts that the little-endian limbs `sum_limbs` are the integer sum of `a_limbs` and
+/// `b_limbs`, with the overflow carried out of the top limb.
+///
+/// The sum columns are provided by the caller and typically are committed. The returned
+/// [`LimbCarries`] holds the auxiliary carry columns, which are populated together with the sum by
+/// [`fill_add_with_carry`].
+pub fn assert_add_with_carry<const LIMB_BITS: usize, const N_LIMBS: usize>(
+	table: &mut TableBuilder,
+	a_limbs: [Col<B1, LIMB_BITS>; N_LIMBS],
+	b_limbs: [Col<B1, LIMB_BITS>; N_LIMBS],
+	sum_limbs: [Col<B1, LIMB_BITS>; N_LIMBS],
+) -> LimbCarries<LIMB_BITS, N_LIMBS> {
+	assert!(N_LIMBS > 0, "at least one limb is required");
+	assert!(LIMB_BITS < 64, "limbs must be narrower than 64 bits");
+	let log_limb_bits = log2_strict_usize(LIMB_BITS);
+
+	let cout: [_; N_LIMBS] = array::from_fn(|i| table.add_committed(format!("cout[{i}]")));
+	let cout_shl: [_; N_LIMBS] = array::from_fn(|i| {
+		table.add_shifted(
+			format!("cout_shl[{i}]"),
+			cout[i],
+			log_limb_bits,
+			1,
+			ShiftVariant::LogicalLeft,
+		)
+	});
+	let limb_carry_in: [_; N_LIMBS] = array::from_fn(|i| {
+		(i != 0).then(|| {
+			table.add_shifted(
+				format!("limb_carry_in[{i}]"),
+				cout[i - 1],
+				log_limb_bits,
+				LIMB_BITS - 1,
+				ShiftVariant::LogicalRight,
+			)
+		})
+	});
+	let cin: [_; N_LIMBS] = array::from_fn(|i| match limb_carry_in[i] {
+		Some(limb_carry_in) => table.add_computed(format!("cin[{i}]"), cout_shl[i] + limb_carry_in),
+		None => cout_shl[i],
+	});
+
+	for i in 0..N_LIMBS {
+		let (a, b, sum, cin, cout) = (a_limbs[i], b_limbs[i], sum_limbs[i], cin[i], cout[i]);
+		table.assert_zero(format!("carry_out[{i}]"), (a + cin) * (b + cin) + cin - cout);
+		table.assert_zero(format!("sum[{i}]"), a + b + cin - sum);
+	}
+
+	let carry_out = table.add_selected("carry_out", cout[N_LIMBS - 1], LIMB_BITS - 1);
+
+	LimbCarries {
+		cout,
+		cout_shl,
+		limb_carry_in,
+		cin,
+		carry_out,
+	}
+}
+
+/// Populates the sum limbs and carry columns of a multi-limb addition.
+///
+/// The input limbs `a_limbs` and `b_limbs` must already be populated. The columns must be the same
+/// ones that were passed to [`assert_add_with_carry`] when creating `carry_col`.
+pub fn fill_add_with_carry<P, const LIMB_BITS: usize, const N_LIMBS: usize>(
+	index: &mut TableWitnessSegment<P>,
+	a_limbs: [Col<B1, LIMB_BITS>; N_LIMBS],
+	b_limbs: [Col<B1, LIMB_BITS>; N_LIMBS],
+	sum_limbs: [Col<B1, LIMB_BITS>; N_LIMBS],
+	carry_col: &LimbCarries<LIMB_BITS, N_LIMBS>,
+) -> anyhow::Result<()>
+where
+	P: PackedField<Scalar = B128> + PackedExtension<B1>,
+{
+	let a_limbs = array_util::try_map(a_limbs, |limb| index.get(limb))?;
+	let b_limbs = array_util::try_map(b_limbs, |limb| index.get(limb))?;
+	let mut sum_limbs = array_util::try_map(sum_limbs, |limb| index.get_mut(limb))?;
+	let mut cout = array_util::try_map(carry_col.cout, |limb| index.get_mut(limb))?;
+	let mut cout_shl = array_util::try_map(carry_col.cout_shl, |limb| index.get_mut(limb))?;
+	let mut limb_carry_in = carry_col
+		.limb_carry_in
+		.into_iter()
+		.map(|limb| limb.map(|limb| index.get_mut(limb)).transpose())
+		.collect::<Result<Vec<_>, _>>()?;
+	// The first limb's carry in is the shifted carry out, which is populated above.
+	let mut cin = carry_col
+		.cin
+		.into_iter()
+		.skip(1)
+		.map(|limb| index.get_mut(limb))
+		.collect::<Result<Vec<_>, _>>()?;
+	let mut carry_out = index.get_mut(carry_col.carry_out)?;
+
+	let mask = (1u64 << LIMB_BITS) - 1;
+	for row in 0..index.size() {
+		let mut carry = 0u64;
+		for i in 0..N_LIMBS {
+			let a = read_limb::<P, LIMB_BITS>(&a_limbs[i], row);
+			let b = read_limb::<P, LIMB_BITS>(&b_limbs[i], row);
+			let full_sum = a + b + carry;
+			let sum = full_sum & mask;
+			let next_carry = full_sum >> LIMB_BITS;
+
+			// The carry into each bit is recovered from the sum, and the carry out of each bit is
+			// the carry into the next one.
+			let bit_cin = a ^ b ^ sum;
+			let bit_cout = next_carry << (LIMB_BITS - 1) | bit_cin >> 1;
+
+			write_limb::<P, LIMB_BITS>(&mut sum_limbs[i], row, sum);
+			write_limb::<P, LIMB_BITS>(&mut cout[i], row, bit_cout);
+			write_limb::<P, LIMB_BITS>(&mut cout_shl[i], row, (bit_cout << 1) & mask);
+			if let Some(limb_carry_in) = &mut limb_carry_in[i] {
+				write_limb::<P, LIMB_BITS>(limb_carry_in, row, carry);
+				write_limb::<P, LIMB_BITS>(&mut cin[i - 1], row, bit_cin);
+			}
+
+			carry = next_carry;
+		}
+		set_packed_slice(&mut carry_out, row, if carry == 1 { B1::ONE } else { B1::ZERO });
+	}
+
+	Ok(())
+}
+
+/// Allocates a committed column constrained to the field product of `a` and `b`.
+///
+/// The product column is named `product`, as is the degree 2 constraint `a * b - product = 0`;
+/// use a namespace to multiply more than once in a table. The product is populated by
+/// [`fill_mul`].
+pub fn mul<FSub, const V: usize>(
+	table: &mut TableBuilder,
+	a: Col<FSub, V>,
+	b: Col<FSub, V>,
+) -> Col<FSub, V>
+where
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+{
+	let product = table.add_committed::<FSub, V>("product");
+	table.assert_zero("product", a * b - product);
+	product
+}
+
+/// Populates the product column of [`mul`] with the field product of `a` and `b` on every row.
+///
+/// The columns `a` and `b` must already be populated.
+pub fn fill_mul<P, FSub, const V: usize>(
+	index: &mut TableWitnessSegment<P>,
+	a: Col<FSub, V>,
+	b: Col<FSub, V>,
+	product: Col<FSub, V>,
+) -> anyhow::Result<()>
+where
+	P: PackedField<Scalar = B128> + PackedExtension<FSub>,
+	FSub: TowerField,
+{
+	let a = index.get(a)?;
+	let b = index.get(b)?;
+	let mut product = index.get_mut(product)?;
+	for (product, &a, &b) in izip!(&mut *product, &*a, &*b) {
+		*product = a * b;
+	}
+	Ok(())
+}
+
+/// The columns of a zero-aware field inversion, created by [`inverse`].
+#[derive(Debug, Clone, Copy)]
+pub struct Inverse<FSub: TowerField, const V: usize = 1> {
+	/// The multiplicative inverse of the input, or zero where the input is zero.
+	pub inverse: Col<FSub, V>,
+	/// One where the input is zero and zero elsewhere.
+	pub is_zero: Col<FSub, V>,
+}
+
+/// Allocates committed columns for the inverse of `col`, with a flag for the rows where `col` is
+/// zero.
+///
+/// The gadget enforces the constraints
+///
+/// ```text
+/// col * inverse = 1 - is_zero
+/// col * is_zero = 0
+/// ```
+///
+/// named `inverse` and `is_zero`. Where `col` is nonzero the second constraint forces `is_zero`
+/// to zero, and then the first forces `inverse` to be the inverse of `col`. Where `col` is zero
+/// the first constraint forces `is_zero` to one, and `inverse` is not constrained; [`fill_inverse`]
+/// sets it to zero. Use a namespace to invert more than once in a table.
+pub fn inverse<FSub, const V: usize>(
+	table: &mut TableBuilder,
+	col: Col<FSub, V>,
+) -> Inverse<FSub, V>
+where
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+{
+	let inverse = table.add_committed::<FSub, V>("inverse");
+	let is_zero = table.add_committed::<FSub, V>("is_zero");
+	table.assert_zero("inverse", col * inverse + is_zero - FSub::ONE);
+	table.assert_zero("is_zero", col * is_zero);
+	Inverse { inverse, is_zero }
+}
+
+/// Populates the columns of [`inverse`] from `col`, which must already be populated.
+pub fn fill_inverse<P, FSub, const V: usize>(
+	index: &mut TableWitnessSegment<P>,
+	col: Col<FSub, V>,
+	inverse: &Inverse<FSub, V>,
+) -> anyhow::Result<()>
+where
+	P: PackedField<Scalar = B128> + PackedExtension<FSub>,
+	FSub: TowerField,
+{
+	let col = index.get(col)?;
+	let mut inv = index.get_mut(inverse.inverse)?;
+	let mut is_zero = index.get_mut(inverse.is_zero)?;
+	for (inv, is_zero, &col) in izip!(&mut *inv, &mut *is_zero, &*col) {
+		*inv = col.invert_or_zero();
+		*is_zero = PackedSubfield::<P, FSub>::one() - col * *inv;
+	}
+	Ok(())
+}
+
+/// Asserts that two multi-limb numbers are equal, limb by limb.
+///
+/// The limbs are in the same order in both slices, e.g. little-endian as for
+/// [`assert_add_with_carry`]. There is one equality constraint per limb, named `limbs_eq[i]`.
+///
+/// ## Panics
+///
+/// Panics if the numbers have different numbers of limbs.
+pub fn assert_limbs_eq<FSub, const V: usize>(
+	table: &mut TableBuilder,
+	a_limbs: &[Col<FSub, V>],
+	b_limbs: &[Col<FSub, V>],
+) where
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+{
+	assert_eq!(a_limbs.len(), b_limbs.len(), "the numbers must have the same number of limbs");
+	for (i, (&a, &b)) in a_limbs.iter().zip(b_limbs).enumerate() {
+		table.assert_eq(format!("limbs_eq[{i}]"), a, b);
+	}
+}
+
+fn read_limb<P, const LIMB_BITS: usize>(limb: &[PackedSubfield<P, B1>], row: usize) -> u64
+where
+	P: PackedExtension<B1>,
+{
+	(0..LIMB_BITS).fold(0, |acc, bit| {
+		let value = get_packed_slice(limb, row * LIMB_BITS + bit);
+		acc | ((value == B1::ONE) as u64) << bit
+	})
+}
+
+fn write_limb<P, const LIMB_BITS: usize>(limb: &mut [PackedSubfield<P, B1>], row: usize, value: u64)
+where
+	P: PackedExtension<B1>,
+{
+	for bit in 0..LIMB_BITS {
+		let value = if (value >> bit) & 1 == 1 {
+			B1::ONE
+		} else {
+			B1::ZERO
+		};
+		set_packed_slice(limb, row * LIMB_BITS + bit, value);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_compute::cpu::alloc::CpuComputeAllocator;
+	use binius_field::{arch::OptimalUnderlier128b, as_packed_field::PackedType};
+	use rand::{Rng as _, SeedableRng, prelude::StdRng};
+
+	use super::*;
+	use crate::builder::{
+		B16, B32, ConstraintSystem, WitnessIndex,
+		test_utils::{ClosureFiller, validate_system_witness},
+	};
+
+	#[test]
+	fn test_mul_b32() {
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("mul");
+		let a = table.add_committed::<B32, 1>("a");
+		let b = table.add_committed::<B32, 1>("b");
+		let product = mul(&mut table, a, b);
+		let table_id = table.id();
+		drop(table);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let events = (0..1 << 6)
+			.map(|_| (B32::new(rng.random()), B32::new(rng.random())))
+			.collect::<Vec<_>>();
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |events: &[(B32, B32)], index| {
+					{
+						let mut a_col = index.get_scalars_mut(a)?;
+						let mut b_col = index.get_scalars_mut(b)?;
+						for (i, &(a_val, b_val)) in events.iter().enumerate() {
+							a_col[i] = a_val;
+							b_col[i] = b_val;
+						}
+					}
+					fill_mul(index, a, b, product)
+				}),
+				&events,
+			)
+			.unwrap();
+
+		{
+			let table_witness = witness.get_table(table_id).unwrap();
+			let segment = table_witness.full_segment();
+			let products = segment.get_scalars(product).unwrap();
+			for (i, &(a_val, b_val)) in events.iter().enumerate() {
+				assert_eq!(products[i], a_val * b_val);
+			}
+			// A wrong product violates the constraint.
+			drop(products);
+			segment.get_scalars_mut(product).unwrap()[5] += B32::ONE;
+		}
+		let failures = witness.zero_constraint_failures().unwrap();
+		assert_eq!(failures.len(), 1);
+		assert_eq!(failures[0].row, 5);
+
+		let table_witness = witness.get_table(table_id).unwrap();
+		let segment = table_witness.full_segment();
+		segment.get_scalars_mut(product).unwrap()[5] += B32::ONE;
+		drop(segment);
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+	}
+
+	#[test]
+	fn test_inverse_b32() {
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("inverse");
+		let input = table.add_committed::<B32, 1>("input");
+		let inv = inverse(&mut table, input);
+		let table_id = table.id();
+		drop(table);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let events = (0..1 << 6)
+			.map(|i| {
+				if i % 5 == 0 {
+					B32::ZERO
+				} else {
+					B32::new(rng.random())
+				}
+			})
+			.collect::<Vec<_>>();
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |events: &[B32], index| {
+					index.get_scalars_mut(input)?.copy_from_slice(events);
+					fill_inverse(index, input, &inv)
+				}),
+				&events,
+			)
+			.unwrap();
+
+		{
+			let table_witness = witness.get_table(table_id).unwrap();
+			let segment = table_witness.full_segment();
+			let inverses = segment.get_scalars(inv.inverse).unwrap();
+			let is_zero = segment.get_scalars(inv.is_zero).unwrap();
+			for (i, &value) in events.iter().enumerate() {
+				if value == B32::ZERO {
+					assert_eq!(inverses[i], B32::ZERO);
+					assert_eq!(is_zero[i], B32::ONE);
+				} else {
+					assert_eq!(inverses[i] * value, B32::ONE);
+					assert_eq!(is_zero[i], B32::ZERO);
+				}
+			}
+			// A wrong inverse violates the constraint.
+			drop(inverses);
+			segment.get_scalars_mut(inv.inverse).unwrap()[3] += B32::ONE;
+		}
+		let failures = witness.zero_constraint_failures().unwrap();
+		assert_eq!(failures.len(), 1);
+		assert_eq!(failures[0].constraint_name, "inverse");
+		assert_eq!(failures[0].row, 3);
+
+		let table_witness = witness.get_table(table_id).unwrap();
+		let segment = table_witness.full_segment();
+		segment.get_scalars_mut(inv.inverse).unwrap()[3] += B32::ONE;
+		drop(segment);
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+	}
+
+	#[test]
+	fn test_assert_limbs_eq_u128_with_b16_limbs() {
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("limbs_eq");
+		let a_limbs = table.add_committed_multiple::<B16, 1, 8>("a");
+		let b_limbs = table.add_committed_multiple::<B16, 1, 8>("b");
+		assert_limbs_eq(&mut table, &a_limbs, &b_limbs);
+		let table_id = table.id();
+		drop(table);
+
+		let filler = ClosureFiller::new(table_id, |events: &[(u128, u128)], index| {
+			for limb in 0..8 {
+				let mut a = index.get_mut_as::<u16, _, 1>(a_limbs[limb])?;
+				let mut b = index.get_mut_as::<u16, _, 1>(b_limbs[limb])?;
+				for (i, &(a_val, b_val)) in events.iter().enumerate() {
+					a[i] = (a_val >> (16 * limb)) as u16;
+					b[i] = (b_val >> (16 * limb)) as u16;
+				}
+			}
+			Ok(())
+		});
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut events = (0..1 << 6)
+			.map(|_| {
+				let value = rng.random::<u128>();
+				(value, value)
+			})
+			.collect::<Vec<_>>();
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		witness.fill_table_sequential(&filler, &events).unwrap();
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+
+		// Flip one bit of one limb in one row.
+		events[3].1 ^= 1 << 100;
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		witness.fill_table_sequential(&filler, &events).unwrap();
+		let failures = witness.zero_constraint_failures().unwrap();
+		assert_eq!(failures.len(), 1);
+		assert_eq!(failures[0].constraint_name, "limbs_eq[6]");
+		assert_eq!(failures[0].row, 3);
+	}
+
+	#[test]
+	fn test_add_u64_with_u8_limbs() {
+		const N_ITER: usize = 1 << 10;
+
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("add_with_carry");
+		let a_limbs = table.add_committed_multiple::<B1, 8, 8>("a");
+		let b_limbs = table.add_committed_multiple::<B1, 8, 8>("b");
+		let sum_limbs = table.add_committed_multiple::<B1, 8, 8>("sum");
+		let carries = assert_add_with_carry(&mut table, a_limbs, b_limbs, sum_limbs);
+		let table_id = table.id();
+		drop(table);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let events = (0..N_ITER)
+			.map(|_| (rng.random::<u64>(), rng.random::<u64>()))
+			.collect::<Vec<_>>();
+
+		let mut allocator = CpuComputeAllocator::new(1 << 16);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		let table_witness = witness.init_table(table_id, events.len()).unwrap();
+		let mut segment = table_witness.full_segment();
+
+		for limb in 0..8 {
+			let mut a = segment.get_mut_as::<u8, _, 8>(a_limbs[limb]).unwrap();
+			let mut b = segment.get_mut_as::<u8, _, 8>(b_limbs[limb]).unwrap();
+			for (i, &(a_val, b_val)) in events.iter().enumerate() {
+				a[i] = (a_val >> (8 * limb)) as u8;
+				b[i] = (b_val >> (8 * limb)) as u8;
+			}
+		}
+
+		fill_add_with_carry(&mut segment, a_limbs, b_limbs, sum_limbs, &carries).unwrap();
+
+		{
+			let sum = sum_limbs.map(|limb| segment.get_as::<u8, _, 8>(limb).unwrap());
+			let carry_out = segment.get(carries.carry_out).unwrap();
+			for (i, &(a_val, b_val)) in events.iter().enumerate() {
+				let (expected_sum, expected_carry) = a_val.overflowing_add(b_val);
+				let sum_val =
+					(0..8).fold(0u64, |acc, limb| acc | (sum[limb][i] as u64) << (8 * limb));
+				assert_eq!(sum_val, expected_sum);
+				assert_eq!(get_packed_slice(&carry_out, i), B1::from(expected_carry));
+			}
+		}
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+	}
+}
@@ -0,0 +1,113 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Expressions over the bit decomposition of a word.
+//!
+//! A word of `n` bits is decomposed into `n` B1 columns, in little-endian order, so that each bit
+//! is zero or one by the type of its column and needs no booleanity constraint. The value of the
+//! word as a field element is $\sum_i b_i \beta_i$, where $\beta_i$ are the basis elements of the
+//! field as an $\mathbb{F}_2$ vector space. A permutation of the bits permutes the basis weights,
+//! so it is a linear expression over the bit columns that needs no extra columns.
+
+use binius_field::{ExtensionField, TowerField};
+
+use crate::builder::{B1, Col, Expr, upcast_col};
+
+/// Returns the value of the word with bits `bits`, rotated left by `amount` bits.
+///
+/// The word has `bits.len()` bits, in little-endian order, and `amount` is taken modulo the word
+/// size. The result is the linear expression in which bit `i` has the weight of bit
+/// `(i + amount) % bits.len()`, i.e. the field element of `FSub` with the bits of
+/// `x.rotate_left(amount)` for the word `x`.
+///
+/// The bit columns are B1 columns, which can only hold zero or one. With the bits in a wider
+/// field, a bit column holding another value would be weighted like a bit, and the expression
+/// would not be a rotation.
+///
+/// ## Panics
+///
+/// * If `bits` is empty or has more bits than `FSub`.
+pub fn rotate_left<FSub, const V: usize>(bits: &[Col<B1, V>], amount: usize) -> Expr<FSub, V>
+where
+	FSub: TowerField,
+{
+	let word_bits = bits.len();
+	assert!(word_bits > 0, "the word must have at least one bit");
+	assert!(word_bits <= FSub::N_BITS, "the word must fit in the field of the result");
+	bits.iter()
+		.enumerate()
+		.map(|(i, &bit)| {
+			upcast_col(bit) * <FSub as ExtensionField<B1>>::basis((i + amount) % word_bits)
+		})
+		.reduce(|acc, term| acc + term)
+		.expect("bits is non-empty")
+}
+
+/// Returns the value of the word with bits `bits`, rotated right by `amount` bits.
+///
+/// This is [`rotate_left`] by `bits.len() - amount % bits.len()` bits.
+///
+/// ## Panics
+///
+/// * If `bits` is empty or has more bits than `FSub`.
+pub fn rotate_right<FSub, const V: usize>(bits: &[Col<B1, V>], amount: usize) -> Expr<FSub, V>
+where
+	FSub: TowerField,
+{
+	assert!(!bits.is_empty(), "the word must have at least one bit");
+	rotate_left(bits, bits.len() - amount % bits.len())
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_compute::cpu::alloc::CpuComputeAllocator;
+	use binius_field::{
+		arch::OptimalUnderlier128b, as_packed_field::PackedType, packed::set_packed_slice,
+	};
+
+	use super::*;
+	use crate::builder::{
+		B8, B128, ConstraintSystem, WitnessIndex,
+		test_utils::{ClosureFiller, validate_system_witness},
+	};
+
+	#[test]
+	fn test_rotate_u8() {
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("rotate");
+		let bits = table.add_committed_multiple::<B1, 1, 8>("bits");
+		let rotl = table.add_committed::<B8, 1>("rotl");
+		let rotr = table.add_committed::<B8, 1>("rotr");
+		table.assert_zero("rotl", rotate_left(&bits, 3) - rotl);
+		// The amount is taken modulo the word size.
+		table.assert_zero("rotr", rotate_right(&bits, 8 + 3) - rotr);
+		let table_id = table.id();
+		drop(table);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |events: &[u8], index| {
+					for (i, &bit_col) in bits.iter().enumerate() {
+						let mut bit = index.get_mut(bit_col)?;
+						for (row, &x) in events.iter().enumerate() {
+							set_packed_slice(&mut bit, row, B1::from((x >> i) & 1 == 1));
+						}
+					}
+					let mut rotl = index.get_mut_as::<u8, _, 1>(rotl)?;
+					let mut rotr = index.get_mut_as::<u8, _, 1>(rotr)?;
+					for (row, &x) in events.iter().enumerate() {
+						rotl[row] = x.rotate_left(3);
+						rotr[row] = x.rotate_right(3);
+					}
+					Ok(())
+				}),
+				&(0..=u8::MAX).collect::<Vec<_>>(),
+			)
+			.unwrap();
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+	}
+}
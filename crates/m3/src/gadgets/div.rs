@@ -284,8 +284,9 @@ impl DivSS32 {
 			SignConverter::new(&mut inner_neg_abs_q_table, sign_extend_q, q_is_positive);
 		let neg_abs_q_bits = neg_abs_q_value.converted_bits;
 
+		let mut inner_sub_table = table.with_namespace("abs_difference");
 		let sub = WideAdd::<u64, 64>::new(
-			table,
+			&mut inner_sub_table,
 			abs_r_bits,
 			neg_abs_q_bits,
 			U32AddFlags {
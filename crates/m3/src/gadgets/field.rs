@@ -0,0 +1,210 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{
+	ExtensionField, PackedExtension, PackedField, TowerField,
+	packed::{get_packed_slice, set_packed_slice},
+};
+
+use crate::builder::{B128, Col, Expr, TableBuilder, TableWitnessSegment, upcast_col};
+
+/// Returns the trace of `x` over the subfield `To`.
+///
+/// The trace is the sum of the conjugates $x^{q^i}$ of `x` for $i < d$, where $q$ is the order of
+/// `To` and $d$ the degree of `From` over `To`. It is a `To`-linear map from `From` onto `To`.
+pub fn trace<From, To>(x: From) -> To
+where
+	From: ExtensionField<To>,
+	To: TowerField,
+{
+	let mut conjugate = x;
+	let mut sum = From::ZERO;
+	for _ in 0..<From as ExtensionField<To>>::DEGREE {
+		sum += conjugate;
+		for _ in 0..To::N_BITS {
+			conjugate = conjugate.square();
+		}
+	}
+	// The trace is in the subfield, so it is its own first coordinate.
+	sum.get_base(0)
+}
+
+/// Fills `dst_col` with the traces over `To` of the values of `src_col`.
+pub fn fill_trace<P, From, To, const V: usize>(
+	index: &mut TableWitnessSegment<P>,
+	src_col: Col<From, V>,
+	dst_col: Col<To, V>,
+) -> anyhow::Result<()>
+where
+	P: PackedField<Scalar = B128> + PackedExtension<From> + PackedExtension<To>,
+	From: TowerField + ExtensionField<To>,
+	To: TowerField,
+{
+	let src = index.get(src_col)?;
+	let mut dst = index.get_mut(dst_col)?;
+	for i in 0..index.size() * V {
+		set_packed_slice(&mut dst, i, trace::<From, To>(get_packed_slice(&src, i)));
+	}
+	Ok(())
+}
+
+/// A gadget computing the trace over `To` of a column of `From` elements.
+///
+/// The trace is not a low-degree polynomial of its input, but it is linear over `To`: with the
+/// coordinates $a_j$ of the input over the basis $\beta_j$ of `From` over `To`, the trace is
+/// $\sum_j a_j \mathrm{Tr}(\beta_j)$. The gadget commits the coordinates, and both the input and
+/// the trace are computed columns of them, so there are no constraints.
+#[derive(Debug)]
+pub struct Trace<From: TowerField, To: TowerField, const V: usize = 1> {
+	/// The coordinates of the input over `To`.
+	pub coords: Vec<Col<To, V>>,
+	/// The input, as the combination of its coordinates.
+	pub input: Col<From, V>,
+	/// The trace of the input.
+	pub output: Col<To, V>,
+}
+
+impl<From, To, const V: usize> Trace<From, To, V>
+where
+	From: TowerField + ExtensionField<To>,
+	To: TowerField,
+	B128: ExtensionField<From> + ExtensionField<To>,
+{
+	pub fn new(table: &mut TableBuilder) -> Self {
+		let coords = (0..<From as ExtensionField<To>>::DEGREE)
+			.map(|j| table.add_committed(format!("coords[{j}]")))
+			.collect::<Vec<_>>();
+		let input = coords
+			.iter()
+			.enumerate()
+			.map(|(j, &coord)| {
+				upcast_col::<From, To, V>(coord) * <From as ExtensionField<To>>::basis(j)
+			})
+			.reduce(|a, b| a + b)
+			.expect("an extension has at least one basis element");
+		let input = table.add_computed("input", input);
+		let output = coords
+			.iter()
+			.enumerate()
+			.map(|(j, &coord)| {
+				Expr::from(coord) * trace::<From, To>(<From as ExtensionField<To>>::basis(j))
+			})
+			.reduce(|a, b| a + b)
+			.expect("an extension has at least one basis element");
+		let output = table.add_computed("trace", output);
+		Self {
+			coords,
+			input,
+			output,
+		}
+	}
+
+	/// Fills the coordinates and the trace from the values of [`Self::input`], which must have
+	/// been written before.
+	pub fn populate<P>(&self, index: &mut TableWitnessSegment<P>) -> anyhow::Result<()>
+	where
+		P: PackedField<Scalar = B128> + PackedExtension<From> + PackedExtension<To>,
+	{
+		{
+			let input = index.get(self.input)?;
+			let mut coords = self
+				.coords
+				.iter()
+				.map(|&coord| index.get_mut(coord))
+				.collect::<Result<Vec<_>, _>>()?;
+			for i in 0..index.size() * V {
+				let value = get_packed_slice(&input, i);
+				for (coord, base) in coords
+					.iter_mut()
+					.zip(ExtensionField::<To>::iter_bases(&value))
+				{
+					set_packed_slice(coord, i, base);
+				}
+			}
+		}
+		fill_trace(index, self.input, self.output)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_compute::cpu::alloc::CpuComputeAllocator;
+	use binius_field::{Field, arch::OptimalUnderlier128b, as_packed_field::PackedType};
+	use rand::{SeedableRng, prelude::StdRng};
+
+	use super::*;
+	use crate::builder::{
+		B1, B8, B16, ConstraintSystem, WitnessIndex,
+		test_utils::{ClosureFiller, validate_system_witness},
+	};
+
+	/// Computes the trace of a B16 element over B1 as the sum of its 16 conjugates.
+	fn reference_trace(x: B16) -> B1 {
+		let conjugates = (0..16).scan(x, |conjugate, _| {
+			let value = *conjugate;
+			*conjugate = conjugate.square();
+			Some(value)
+		});
+		let sum = conjugates.sum::<B16>();
+		assert!(sum == B16::ZERO || sum == B16::ONE, "the trace must be in the subfield");
+		if sum == B16::ONE { B1::ONE } else { B1::ZERO }
+	}
+
+	#[test]
+	fn test_trace_linear() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..16 {
+			let x: B16 = Field::random(&mut rng);
+			let y: B16 = Field::random(&mut rng);
+			assert_eq!(trace::<B16, B1>(x), reference_trace(x));
+			assert_eq!(trace::<B16, B1>(x + y), trace::<B16, B1>(x) + trace::<B16, B1>(y));
+			// The trace over B8 is B8-linear.
+			let a: B8 = Field::random(&mut rng);
+			assert_eq!(trace::<B16, B8>(x * a), trace::<B16, B8>(x) * a);
+		}
+	}
+
+	#[test]
+	fn test_trace_b16_over_b1() {
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("trace");
+		let trace_gadget = Trace::<B16, B1>::new(&mut table);
+		let table_id = table.id();
+		drop(table);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let inputs = repeat_with(|| <B16 as Field>::random(&mut rng))
+			.take(1 << 8)
+			.collect::<Vec<_>>();
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |inputs: &[B16], index| {
+					{
+						let mut input = index.get_mut(trace_gadget.input)?;
+						for (i, &value) in inputs.iter().enumerate() {
+							set_packed_slice(&mut input, i, value);
+						}
+					}
+					trace_gadget.populate(index)?;
+					Ok(())
+				}),
+				&inputs,
+			)
+			.unwrap();
+		{
+			let segment = witness.get_table(table_id).unwrap().full_segment();
+			let output = segment.get(trace_gadget.output).unwrap();
+			for (i, &input) in inputs.iter().enumerate() {
+				assert_eq!(get_packed_slice(&output, i), reference_trace(input));
+			}
+		}
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+	}
+}
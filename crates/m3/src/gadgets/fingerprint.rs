@@ -0,0 +1,210 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{
+	ExtensionField, PackedExtension, PackedFieldIndexable, PackedSubfield, TowerField,
+};
+
+use crate::builder::{B128, Col, Error, Expr, TableBuilder, TableWitnessSegment, upcast_col};
+
+/// Returns the random linear combination $\sum_j c_j v_j$ of the value columns.
+///
+/// The sum is over a binary field, so it is the XOR of the products. The combination is
+/// $\mathbb{F}_2$-linear in the values and, with the coefficients fixed, an expression of degree
+/// one.
+///
+/// ## Panics
+///
+/// * If there are no value columns, or not one coefficient per value column.
+pub fn xor_fold_expr<FSub, const V: usize>(
+	value_cols: &[Col<FSub, V>],
+	random_coeffs: &[B128],
+) -> Expr<B128, V>
+where
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+{
+	assert_eq!(value_cols.len(), random_coeffs.len(), "expected one coefficient per value column");
+	value_cols
+		.iter()
+		.zip(random_coeffs)
+		.map(|(&col, &coeff)| upcast_col::<B128, FSub, V>(col) * coeff)
+		.reduce(|a, b| a + b)
+		.expect("expected at least one value column")
+}
+
+/// Fills `acc_col` with the random linear combination of the values of `value_cols`.
+///
+/// Every value of `acc_col` is $\sum_j c_j v_j$, where $v_j$ is the value of `value_cols[j]` and
+/// $c_j$ is `random_coeffs[j]`. With random coefficients, this folds the values of a row into a
+/// single field element, which is the fingerprint of the row in a multiset hash, such as the
+/// one of a channel. See [`XorFold`] for the constrained version.
+///
+/// ## Panics
+///
+/// * If there is not one coefficient per value column.
+pub fn fill_xor_fold<P, FSub, const V: usize>(
+	index: &mut TableWitnessSegment<P>,
+	value_cols: &[Col<FSub, V>],
+	acc_col: Col<B128, V>,
+	random_coeffs: &[B128],
+) -> Result<(), Error>
+where
+	P: PackedExtension<FSub, Scalar = B128> + PackedExtension<B128>,
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+	PackedSubfield<P, FSub>: PackedFieldIndexable,
+	PackedSubfield<P, B128>: PackedFieldIndexable,
+{
+	assert_eq!(value_cols.len(), random_coeffs.len(), "expected one coefficient per value column");
+	let values = value_cols
+		.iter()
+		.map(|&col| index.get_scalars(col))
+		.collect::<Result<Vec<_>, _>>()?;
+	let mut acc = index.get_scalars_mut(acc_col)?;
+	for (i, acc) in acc.iter_mut().enumerate() {
+		*acc = values
+			.iter()
+			.zip(random_coeffs)
+			.map(|(values, &coeff)| coeff * values[i])
+			.sum();
+	}
+	Ok(())
+}
+
+/// A computed column folding several value columns with a random linear combination.
+///
+/// The fold is [`xor_fold_expr`] with coefficients fixed when the circuit is built, such as
+/// challenges derived from a transcript ahead of time, so it is a computed column without
+/// constraints.
+#[derive(Debug)]
+pub struct XorFold<FSub: TowerField, const V: usize = 1> {
+	pub values: Vec<Col<FSub, V>>,
+	pub random_coeffs: Vec<B128>,
+	/// The folded values.
+	pub acc: Col<B128, V>,
+}
+
+impl<FSub: TowerField, const V: usize> XorFold<FSub, V>
+where
+	B128: ExtensionField<FSub>,
+{
+	/// Creates the fold of `values` with one coefficient per value column.
+	pub fn new(
+		table: &mut TableBuilder,
+		values: Vec<Col<FSub, V>>,
+		random_coeffs: Vec<B128>,
+	) -> Self {
+		let acc = table.add_computed::<B128, V>("xor_fold", xor_fold_expr(&values, &random_coeffs));
+		Self {
+			values,
+			random_coeffs,
+			acc,
+		}
+	}
+
+	/// Populates the folded column.
+	///
+	/// The value columns must already be populated.
+	pub fn populate<P>(&self, index: &mut TableWitnessSegment<P>) -> Result<(), Error>
+	where
+		P: PackedExtension<FSub, Scalar = B128> + PackedExtension<B128>,
+		PackedSubfield<P, FSub>: PackedFieldIndexable,
+		PackedSubfield<P, B128>: PackedFieldIndexable,
+	{
+		fill_xor_fold(index, &self.values, self.acc, &self.random_coeffs)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_compute::cpu::alloc::CpuComputeAllocator;
+	use binius_field::{Field, arch::OptimalUnderlier128b, as_packed_field::PackedType};
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::builder::{
+		B32, ConstraintSystem, WitnessIndex,
+		test_utils::{ClosureFiller, validate_system_witness},
+	};
+
+	type Row = [B32; 3];
+
+	/// Folds the rows of `events` and returns the product of `gamma + acc` over the rows, which is
+	/// the multiset hash of the rows.
+	fn fingerprint(events: &[Row], random_coeffs: &[B128], gamma: B128) -> B128 {
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("fingerprint");
+		let values = (0..3)
+			.map(|j| table.add_committed::<B32, 1>(format!("value[{j}]")))
+			.collect::<Vec<_>>();
+		let fold = XorFold::new(&mut table, values, random_coeffs.to_vec());
+		let table_id = table.id();
+		drop(table);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |events: &[Row], index| {
+					for (j, &col) in fold.values.iter().enumerate() {
+						let mut values = index.get_scalars_mut(col)?;
+						for (value, event) in values.iter_mut().zip(events) {
+							*value = event[j];
+						}
+					}
+					fold.populate(index)?;
+					Ok(())
+				}),
+				events,
+			)
+			.unwrap();
+
+		let hash = {
+			let segment = witness.get_table(table_id).unwrap().full_segment();
+			let acc = segment.get_scalars(fold.acc).unwrap();
+			for (&acc, event) in acc.iter().zip(events) {
+				let expected = event
+					.iter()
+					.zip(random_coeffs)
+					.map(|(&value, &coeff)| coeff * value)
+					.sum::<B128>();
+				assert_eq!(acc, expected);
+			}
+			acc[..events.len()].iter().map(|&acc| gamma + acc).product()
+		};
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+		hash
+	}
+
+	#[test]
+	fn test_xor_fold_fingerprint() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let random_coeffs = repeat_with(|| B128::random(&mut rng))
+			.take(3)
+			.collect::<Vec<_>>();
+		let gamma = B128::random(&mut rng);
+		let events = repeat_with(|| [(); 3].map(|_| B32::random(&mut rng)))
+			.take(32)
+			.collect::<Vec<_>>();
+
+		let mut permuted = events.clone();
+		permuted.reverse();
+		permuted.swap(3, 17);
+		assert_eq!(
+			fingerprint(&permuted, &random_coeffs, gamma),
+			fingerprint(&events, &random_coeffs, gamma)
+		);
+
+		// Swapping two values within a row changes the multiset of rows.
+		let mut different = events.clone();
+		different[5].swap(0, 2);
+		assert_ne!(
+			fingerprint(&different, &random_coeffs, gamma),
+			fingerprint(&events, &random_coeffs, gamma)
+		);
+	}
+}
@@ -152,7 +152,7 @@ impl Keccakf {
 		for x in 0..5 {
 			for y in 0..5 {
 				table.assert_zero(
-					"link_out_to_next_in",
+					format!("link_out_to_next_in[{x},{y}]"),
 					(state_out_packed[(x, y)] - next_state_in[(x, y)])
 						* upcast_expr(Expr::from(link_sel)),
 				);
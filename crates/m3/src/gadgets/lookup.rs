@@ -1,11 +1,18 @@
 // Copyright 2025 Irreducible Inc.
 
+use std::collections::HashMap;
+
 use anyhow::{Result, ensure};
 use binius_core::constraint_system::channel::ChannelId;
-use binius_field::{ExtensionField, PackedExtension, PackedField, PackedSubfield, TowerField};
+use binius_field::{
+	ExtensionField, PackedExtension, PackedField, PackedFieldIndexable, PackedSubfield, TowerField,
+};
 use itertools::Itertools;
 
-use crate::builder::{B1, B128, Col, FlushOpts, TableBuilder, TableWitnessSegment};
+use crate::builder::{
+	B1, B128, Col, ConstraintSystem, FlushOpts, StructuredDynSize, TableBuilder, TableId,
+	TableWitnessSegment,
+};
 
 /// A lookup producer gadget is used to create a lookup table.
 ///
@@ -86,6 +93,175 @@ impl LookupProducer {
 	}
 }
 
+/// A lookup table whose values are the rows of a structured column.
+///
+/// The table has a single structured column and a [`LookupProducer`] over it, on a dedicated
+/// channel. Use [`assert_in_structured`] to assert that the values of a column are in the table.
+/// The table may have any power-of-two size up to `2^max_size_log` of the structured column, in
+/// which case the domain of the lookup is the first rows of the structured column.
+#[derive(Debug)]
+pub struct StructuredLookup<FSub: TowerField> {
+	pub table_id: TableId,
+	pub channel: ChannelId,
+	/// The structured column holding the values of the table.
+	pub values: Col<FSub>,
+	variant: StructuredDynSize,
+	producer: LookupProducer,
+}
+
+impl<FSub: TowerField> StructuredLookup<FSub>
+where
+	B128: ExtensionField<FSub>,
+{
+	/// Adds the lookup table and its channel to the constraint system.
+	///
+	/// The multiplicity of each value is committed with `n_multiplicity_bits` bits, see
+	/// [`LookupProducer::new`].
+	pub fn new(
+		cs: &mut ConstraintSystem,
+		name: impl ToString,
+		variant: StructuredDynSize,
+		n_multiplicity_bits: usize,
+	) -> Self {
+		let name = name.to_string();
+		let channel = cs.add_channel(format!("{name} lookup"));
+		let mut table = cs.add_table(name);
+		table.require_power_of_two_size();
+		let values = table.add_structured::<FSub>("values", variant);
+		let producer = LookupProducer::new(&mut table, channel, &[values], n_multiplicity_bits);
+		Self {
+			table_id: table.id(),
+			channel,
+			values,
+			variant,
+			producer,
+		}
+	}
+
+	/// Returns a recorder of the reads from the table with `2^log_size` rows.
+	///
+	/// The domain of the lookup is the values of the first `2^log_size` rows of the structured
+	/// column, which must not exceed its maximum size.
+	pub fn reads(&self, log_size: usize) -> Result<StructuredReads<FSub>> {
+		ensure!(
+			log_size <= self.variant.max_size_log(),
+			"log size {log_size} of the lookup table exceeds the maximum log size {} of {:?}",
+			self.variant.max_size_log(),
+			self.variant
+		);
+		let rows = self
+			.variant
+			.materialize::<FSub, FSub>(log_size)?
+			.into_iter()
+			.enumerate()
+			.map(|(row, value)| (value, row))
+			.collect();
+		Ok(StructuredReads {
+			log_size,
+			rows,
+			counts: vec![0; 1 << log_size],
+		})
+	}
+
+	/// Populates the structured column and the multiplicities of the lookup table.
+	///
+	/// `counts` gives the number of times the value of each row is looked up.
+	pub fn populate<P>(
+		&self,
+		index: &mut TableWitnessSegment<P>,
+		counts: impl Iterator<Item = u32> + Clone,
+	) -> Result<(), anyhow::Error>
+	where
+		P: PackedExtension<B1> + PackedExtension<FSub, Scalar = B128>,
+		PackedSubfield<P, FSub>: PackedFieldIndexable,
+	{
+		let values = index
+			.get_structured_values(self.values)?
+			.collect::<Vec<_>>();
+		index.get_scalars_mut(self.values)?.copy_from_slice(&values);
+		self.producer.populate(index, counts)
+	}
+}
+
+/// Asserts that every value of `value_col` is in the domain of a structured lookup table.
+///
+/// The values are pulled from the channel of the lookup table, so a value outside of the domain,
+/// e.g. an index beyond the size of an incrementing table, leaves the channel unbalanced and the
+/// witness fails validation. The lookup table is created with [`StructuredLookup::new`], because
+/// it is a table of its own and cannot be added through the builder of the looking-up table.
+pub fn assert_in_structured<FSub>(
+	table: &mut TableBuilder,
+	value_col: Col<FSub>,
+	lookup: &StructuredLookup<FSub>,
+) where
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+{
+	table.pull(lookup.channel, [value_col]);
+}
+
+/// The number of reads of every row of a structured lookup table, whose size is chosen when the
+/// witness is generated.
+///
+/// Reads are recorded once per event of the looking-up tables, when the events are generated, and
+/// the counts then populate the lookup table with [`StructuredLookup::populate`]. The lookup table
+/// must be filled with one event per count.
+#[derive(Debug)]
+pub struct StructuredReads<FSub: TowerField> {
+	log_size: usize,
+	/// The row of every value in the domain.
+	rows: HashMap<FSub, usize>,
+	counts: Vec<u32>,
+}
+
+impl<FSub: TowerField> StructuredReads<FSub> {
+	/// Returns the log size of the lookup table.
+	pub fn log_size(&self) -> usize {
+		self.log_size
+	}
+
+	/// Records a read of `value` from the lookup table.
+	///
+	/// Returns an error if the value is not in the domain of the lookup table, in which case the
+	/// read is not recorded.
+	pub fn record(&mut self, value: FSub) -> Result<()> {
+		let Some(&row) = self.rows.get(&value) else {
+			anyhow::bail!(
+				"value {value} is not in the domain of the lookup table of size 2^{}",
+				self.log_size
+			);
+		};
+		self.counts[row] += 1;
+		Ok(())
+	}
+
+	/// Returns the number of reads of every row of the lookup table.
+	pub fn counts(&self) -> &[u32] {
+		&self.counts
+	}
+}
+
+/// Asserts that every value of `value_col` is in the domain of a structured lookup table whose
+/// size is chosen when the witness is generated.
+///
+/// Unlike a table of fixed size, the size of the domain is not fixed by the constraint system:
+/// the domain is the rows of the lookup table as committed in the witness, up to the maximum size
+/// of its structured column. The constraints therefore only bound the values by the size of the
+/// committed lookup table, which the prover chooses; a verifier that needs a tighter bound must
+/// check the size of the lookup table among the table sizes of the proof. The size is chosen with
+/// [`StructuredLookup::reads`], whose recorder tracks the reads of every event of the looking-up
+/// table and then populates the lookup table.
+pub fn assert_in_dynamic_structured<FSub>(
+	table: &mut TableBuilder,
+	value_col: Col<FSub>,
+	lookup: &StructuredLookup<FSub>,
+) where
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+{
+	assert_in_structured(table, value_col, lookup);
+}
+
 #[cfg(test)]
 mod tests {
 	use std::{cmp::Reverse, iter, iter::repeat_with};
@@ -96,7 +272,7 @@ mod tests {
 
 	use super::*;
 	use crate::builder::{
-		ConstraintSystem, WitnessIndex,
+		B32, WitnessIndex,
 		test_utils::{ClosureFiller, validate_system_witness},
 	};
 
@@ -266,4 +442,164 @@ mod tests {
 		);
 		assert!(result.is_err());
 	}
+
+	#[test]
+	fn test_assert_in_structured() {
+		const MAX_SIZE_LOG: usize = 4;
+
+		for (values, in_domain) in [
+			(vec![0u32, 3, 3, 15, 7, 0, 9], true),
+			(vec![1, 2, 16], false),
+		] {
+			let mut cs = ConstraintSystem::new();
+			let lookup = StructuredLookup::<B32>::new(
+				&mut cs,
+				"incrementing",
+				StructuredDynSize::Incrementing {
+					max_size_log: MAX_SIZE_LOG,
+				},
+				8,
+			);
+			let mut looker = cs.add_table("looker");
+			let looker_id = looker.id();
+			let looker_vals = looker.add_committed::<B32, 1>("values");
+			assert_in_structured(&mut looker, looker_vals, &lookup);
+			drop(looker);
+
+			let mut counts = vec![0u32; 1 << MAX_SIZE_LOG];
+			for &value in &values {
+				if let Some(count) = counts.get_mut(value as usize) {
+					*count += 1;
+				}
+			}
+
+			let mut allocator = CpuComputeAllocator::new(1 << 12);
+			let allocator = allocator.into_bump_allocator();
+			let mut witness =
+				WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+			witness
+				.fill_table_sequential(
+					&ClosureFiller::new(lookup.table_id, |counts: &[u32], witness| {
+						lookup.populate(witness, counts.iter().copied())
+					}),
+					&counts,
+				)
+				.unwrap();
+			witness
+				.fill_table_sequential(
+					&ClosureFiller::new(looker_id, |values: &[u32], witness| {
+						let mut looker_vals = witness.get_scalars_mut(looker_vals)?;
+						for (dst, &value) in iter::zip(&mut *looker_vals, values) {
+							*dst = B32::new(value);
+						}
+						Ok(())
+					}),
+					&values,
+				)
+				.unwrap();
+
+			if in_domain {
+				validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+			} else {
+				let ccs = cs.compile().unwrap();
+				let table_sizes = witness.table_sizes();
+				let witness = witness.into_multilinear_extension_index();
+				let result = binius_core::constraint_system::validate::validate_witness(
+					&ccs,
+					&[],
+					&table_sizes,
+					&witness,
+				);
+				assert!(result.is_err());
+			}
+		}
+	}
+
+	#[test]
+	fn test_assert_in_dynamic_structured() {
+		const MAX_SIZE_LOG: usize = 8;
+		const RUNTIME_LOG_SIZE: usize = 5;
+
+		// The domain is the committed lookup table, so 32 is out of range of a table with 2^5 rows
+		// and in range of one with 2^6 rows.
+		for (values, log_size, in_domain) in [
+			(vec![0u32, 31, 31, 17, 5, 0, 9], RUNTIME_LOG_SIZE, true),
+			(vec![1, 2, 32], RUNTIME_LOG_SIZE, false),
+			(vec![1, 2, 32], RUNTIME_LOG_SIZE + 1, true),
+		] {
+			let mut cs = ConstraintSystem::new();
+			let lookup = StructuredLookup::<B32>::new(
+				&mut cs,
+				"incrementing",
+				StructuredDynSize::Incrementing {
+					max_size_log: MAX_SIZE_LOG,
+				},
+				8,
+			);
+			let mut looker = cs.add_table("looker");
+			let looker_id = looker.id();
+			let looker_vals = looker.add_committed::<B32, 1>("values");
+			assert_in_dynamic_structured(&mut looker, looker_vals, &lookup);
+			drop(looker);
+
+			// The values out of the domain are not read from the lookup table, so the channel is
+			// unbalanced if there are any.
+			let mut reads = lookup.reads(log_size).unwrap();
+			for &value in &values {
+				let _ = reads.record(B32::new(value));
+			}
+
+			let mut allocator = CpuComputeAllocator::new(1 << 12);
+			let allocator = allocator.into_bump_allocator();
+			let mut witness =
+				WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+			witness
+				.fill_table_sequential(
+					&ClosureFiller::new(looker_id, |values: &[u32], witness| {
+						let mut looker_vals = witness.get_scalars_mut(looker_vals)?;
+						for (dst, &value) in iter::zip(&mut *looker_vals, values) {
+							*dst = B32::new(value);
+						}
+						Ok(())
+					}),
+					&values,
+				)
+				.unwrap();
+			witness
+				.fill_table_sequential(
+					&ClosureFiller::new(lookup.table_id, |counts: &[u32], witness| {
+						lookup.populate(witness, counts.iter().copied())
+					}),
+					reads.counts(),
+				)
+				.unwrap();
+			assert_eq!(witness.table_sizes()[lookup.table_id], 1 << log_size);
+
+			if in_domain {
+				validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+			} else {
+				let ccs = cs.compile().unwrap();
+				let table_sizes = witness.table_sizes();
+				let witness = witness.into_multilinear_extension_index();
+				let result = binius_core::constraint_system::validate::validate_witness(
+					&ccs,
+					&[],
+					&table_sizes,
+					&witness,
+				);
+				assert!(result.is_err());
+			}
+		}
+
+		let mut cs = ConstraintSystem::new();
+		let lookup = StructuredLookup::<B32>::new(
+			&mut cs,
+			"incrementing",
+			StructuredDynSize::Incrementing {
+				max_size_log: RUNTIME_LOG_SIZE,
+			},
+			8,
+		);
+		assert!(lookup.reads(RUNTIME_LOG_SIZE + 1).is_err());
+	}
 }
@@ -558,6 +558,8 @@ where
 			.try_map_ext(|col| witness.get_mut_as(col))?;
 
 		let mut increment_merged: RefMut<'_, [u32]> = witness.get_mut_as(self.increment.merged)?;
+		// The depth fits in a byte, so incrementing it never carries out.
+		witness.fill_zero(self.increment.carry_out)?;
 
 		{
 			for (i, event) in rows.iter().enumerate() {
@@ -1,13 +1,23 @@
 // Copyright 2025 Irreducible Inc.
 
+pub mod accumulate;
 pub mod add;
+pub mod arith;
 pub mod barrel_shifter;
+pub mod bits;
 pub mod div;
+pub mod field;
+pub mod fingerprint;
 pub mod hash;
 pub mod indexed_lookup;
 pub mod lookup;
 pub mod merkle_tree;
 pub mod mul;
+pub mod mux;
+pub mod onehot;
+pub mod order;
+pub mod permutation;
+pub mod scan;
 pub mod structured;
 pub mod sub;
 mod util;
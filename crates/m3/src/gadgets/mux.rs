@@ -0,0 +1,157 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{
+	ExtensionField, PackedExtension, PackedFieldIndexable, PackedSubfield, TowerField,
+};
+
+use crate::builder::{B128, Col, Error, TableBuilder, TableWitnessSegment};
+
+/// Adds a column `out = sel ? a : b` and constrains it with `out = sel * a + (1 - sel) * b`.
+///
+/// The selector is asserted to be boolean with
+/// [`TableBuilder::assert_boolean`](crate::builder::TableBuilder::assert_boolean). The output is a
+/// committed column, filled with [`fill_select`]. The output column and the constraints are added
+/// in the namespace `name`, so a table may have several selects with different names.
+pub fn select<FSub, const V: usize>(
+	table: &mut TableBuilder,
+	name: impl ToString,
+	sel: Col<FSub, V>,
+	a: Col<FSub, V>,
+	b: Col<FSub, V>,
+) -> Col<FSub, V>
+where
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+{
+	let mut table = table.with_namespace(name);
+	table.assert_boolean("sel is boolean", sel.into());
+	let out = table.add_committed::<FSub, V>("out");
+	table.assert_zero("select", out - (sel * a + (sel - FSub::ONE) * b));
+	out
+}
+
+/// Fills the output column of [`select`].
+///
+/// The output is `sel * a + (1 - sel) * b`, which is `a` where the selector is one and `b` where it
+/// is zero. The input columns must already be populated.
+pub fn fill_select<P, FSub, const V: usize>(
+	index: &mut TableWitnessSegment<P>,
+	sel: Col<FSub, V>,
+	a: Col<FSub, V>,
+	b: Col<FSub, V>,
+	out: Col<FSub, V>,
+) -> Result<(), Error>
+where
+	P: PackedExtension<FSub, Scalar = B128>,
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+	PackedSubfield<P, FSub>: PackedFieldIndexable,
+{
+	let sel = index.get_scalars(sel)?;
+	let a = index.get_scalars(a)?;
+	let b = index.get_scalars(b)?;
+	let mut out = index.get_scalars_mut(out)?;
+	for (out, &sel, &a, &b) in itertools::izip!(&mut *out, &*sel, &*a, &*b) {
+		*out = sel * a + (sel - FSub::ONE) * b;
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_compute::cpu::alloc::CpuComputeAllocator;
+	use binius_field::{Field, arch::OptimalUnderlier128b, as_packed_field::PackedType};
+	use rand::{Rng, SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::builder::{
+		B32, ConstraintSystem, WitnessIndex,
+		test_utils::{ClosureFiller, validate_system_witness},
+	};
+
+	#[test]
+	fn test_select() {
+		const N_ROWS: usize = 64;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let events = repeat_with(|| {
+			(
+				B32::from(rng.random::<bool>() as u32),
+				<B32 as Field>::random(&mut rng),
+				<B32 as Field>::random(&mut rng),
+			)
+		})
+		.take(N_ROWS)
+		.collect::<Vec<_>>();
+		let mut non_boolean = events.clone();
+		non_boolean[5].0 = B32::new(3);
+
+		for (events, is_valid) in [(events, true), (non_boolean, false)] {
+			let mut cs = ConstraintSystem::new();
+			let mut table = cs.add_table("mux");
+			let table_id = table.id();
+			let sel = table.add_committed::<B32, 1>("sel");
+			let a = table.add_committed::<B32, 1>("a");
+			let b = table.add_committed::<B32, 1>("b");
+			let out = select(&mut table, "mux", sel, a, b);
+			drop(table);
+
+			let mut allocator = CpuComputeAllocator::new(1 << 12);
+			let allocator = allocator.into_bump_allocator();
+			let mut witness =
+				WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+			witness
+				.fill_table_sequential(
+					&ClosureFiller::new(table_id, |events: &[(B32, B32, B32)], index| {
+						{
+							let mut sel = index.get_scalars_mut(sel)?;
+							let mut a = index.get_scalars_mut(a)?;
+							let mut b = index.get_scalars_mut(b)?;
+							for (i, &(sel_i, a_i, b_i)) in events.iter().enumerate() {
+								sel[i] = sel_i;
+								a[i] = a_i;
+								b[i] = b_i;
+							}
+						}
+						fill_select(index, sel, a, b, out)?;
+						Ok(())
+					}),
+					&events,
+				)
+				.unwrap();
+
+			if is_valid {
+				let segment = witness.get_table(table_id).unwrap().full_segment();
+				let values = segment.get_scalars(out).unwrap();
+				for (&value, &(sel, a, b)) in values.iter().zip(&events) {
+					assert_eq!(value, if sel == B32::ONE { a } else { b });
+				}
+				drop(values);
+				validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+			} else {
+				let failures = witness.zero_constraint_failures().unwrap();
+				let [failure] = failures.as_slice() else {
+					panic!("expected a single failure, got {failures:?}");
+				};
+				assert_eq!(failure.constraint_name, "mux::sel is boolean");
+				assert_eq!(failure.row, 5);
+			}
+		}
+	}
+	#[test]
+	fn test_select_twice_in_table() {
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("mux");
+		let sel = table.add_committed::<B32, 1>("sel");
+		let a = table.add_committed::<B32, 1>("a");
+		let b = table.add_committed::<B32, 1>("b");
+		let c = table.add_committed::<B32, 1>("c");
+		let out = select(&mut table, "select_a_b", sel, a, b);
+		select(&mut table, "select_out_c", sel, out, c);
+		drop(table);
+
+		assert!(cs.compile().is_ok());
+	}
+}
@@ -0,0 +1,186 @@
+// Copyright 2025 Irreducible Inc.
+
+use anyhow::{Result, ensure};
+use binius_field::{
+	ExtensionField, PackedExtension, PackedFieldIndexable, PackedSubfield, TowerField,
+	packed::set_packed_slice,
+};
+
+use crate::builder::{B32, B128, Col, Expr, TableBuilder, TableWitnessSegment};
+
+/// Constrains the columns to be a one-hot encoding, where exactly one column is one on each row.
+///
+/// Every column is asserted to be boolean and the columns to sum to one. In characteristic 2 the
+/// sum of boolean values is the parity of the number of ones, so the products of every pair of
+/// columns are also asserted to be zero, which leaves a single one. This adds `n (n + 1) / 2 + 1`
+/// constraints of degree at most 2 for `n` columns. The columns are filled with [`fill_one_hot`].
+///
+/// ## Panics
+///
+/// * If there are no columns.
+pub fn assert_one_hot<FSub, const V: usize>(table: &mut TableBuilder, cols: &[Col<FSub, V>])
+where
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+{
+	assert!(!cols.is_empty(), "expected at least one one-hot column");
+	for (i, &col) in cols.iter().enumerate() {
+		table.assert_boolean(format!("one_hot[{i}] is boolean"), col.into());
+		for (j, &other) in cols.iter().enumerate().skip(i + 1) {
+			table.assert_zero(format!("one_hot[{i}] * one_hot[{j}] is zero"), col * other);
+		}
+	}
+	let sum = cols
+		.iter()
+		.map(|&col| Expr::from(col))
+		.reduce(|a, b| a + b)
+		.expect("cols is not empty");
+	table.assert_zero("one_hot sum is one", sum - FSub::ONE);
+}
+
+/// Fills the columns of [`assert_one_hot`] from the index of the active column on each row.
+///
+/// The column of index `active_index_col[i]` is one at value `i` and the others are zero. The index
+/// column must already be populated. The index of the padding rows is zero, so the first column is
+/// active on them.
+///
+/// ## Throws
+///
+/// * If an index is not the index of a column.
+pub fn fill_one_hot<P, FSub, const V: usize>(
+	index: &mut TableWitnessSegment<P>,
+	cols: &[Col<FSub, V>],
+	active_index_col: Col<B32, V>,
+) -> Result<()>
+where
+	P: PackedExtension<FSub, Scalar = B128> + PackedExtension<B32>,
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+	PackedSubfield<P, B32>: PackedFieldIndexable,
+{
+	let active_index = index.get_scalars(active_index_col)?;
+	for &active in &*active_index {
+		ensure!(
+			(active.val() as usize) < cols.len(),
+			"active index {active} is not the index of one of {} columns",
+			cols.len()
+		);
+	}
+	for (j, &col) in cols.iter().enumerate() {
+		let mut col = index.get_mut(col)?;
+		for (i, &active) in active_index.iter().enumerate() {
+			let value = if active.val() as usize == j {
+				FSub::ONE
+			} else {
+				FSub::ZERO
+			};
+			set_packed_slice(&mut col, i, value);
+		}
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_compute::cpu::alloc::CpuComputeAllocator;
+	use binius_field::{
+		Field, PackedField, arch::OptimalUnderlier128b, as_packed_field::PackedType,
+	};
+
+	use super::*;
+	use crate::builder::{
+		B1, ConstraintSystem, WitnessIndex,
+		test_utils::{ClosureFiller, validate_system_witness},
+	};
+
+	#[test]
+	fn test_one_hot() {
+		const N_ROWS: usize = 50;
+
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("state machine");
+		let table_id = table.id();
+		let active = table.add_committed::<B32, 1>("active");
+		let selectors = (0..3)
+			.map(|i| table.add_committed::<B1, 1>(format!("selector[{i}]")))
+			.collect::<Vec<_>>();
+		assert_one_hot(&mut table, &selectors);
+		drop(table);
+
+		let events = (0..N_ROWS as u32).map(|i| i % 3).collect::<Vec<_>>();
+		for (corruption, expected_failures) in [
+			(None, vec![]),
+			(
+				Some([true, true, false]),
+				vec!["one_hot[0] * one_hot[1] is zero", "one_hot sum is one"],
+			),
+			(Some([false, false, false]), vec!["one_hot sum is one"]),
+		] {
+			let mut allocator = CpuComputeAllocator::new(1 << 12);
+			let allocator = allocator.into_bump_allocator();
+			let mut witness =
+				WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+			witness
+				.fill_table_sequential(
+					&ClosureFiller::new(table_id, |events: &[u32], index| {
+						{
+							let mut active = index.get_scalars_mut(active)?;
+							for (dst, &event) in active.iter_mut().zip(events) {
+								*dst = B32::new(event);
+							}
+						}
+						fill_one_hot(index, &selectors, active)?;
+						if let Some(row) = corruption {
+							for (&col, set) in selectors.iter().zip(row) {
+								set_packed_slice(&mut index.get_mut(col)?, 7, B1::from(set));
+							}
+						}
+						Ok(())
+					}),
+					&events,
+				)
+				.unwrap();
+
+			if corruption.is_none() {
+				{
+					let segment = witness.get_table(table_id).unwrap().full_segment();
+					for (j, &col) in selectors.iter().enumerate() {
+						let values = segment.get(col).unwrap();
+						for (value, &event) in PackedField::iter_slice(&values).zip(&events) {
+							assert_eq!(value == B1::ONE, event as usize == j);
+						}
+					}
+				}
+				validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+			} else {
+				let failures = witness.zero_constraint_failures().unwrap();
+				assert_eq!(
+					failures
+						.iter()
+						.map(|failure| failure.constraint_name.as_str())
+						.collect::<Vec<_>>(),
+					expected_failures
+				);
+				assert!(failures.iter().all(|failure| failure.row == 7));
+			}
+		}
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		let result = witness.fill_table_sequential(
+			&ClosureFiller::new(table_id, |events: &[u32], index| {
+				{
+					let mut active = index.get_scalars_mut(active)?;
+					for (dst, &event) in active.iter_mut().zip(events) {
+						*dst = B32::new(event);
+					}
+				}
+				fill_one_hot(index, &selectors, active)
+			}),
+			&[0, 3],
+		);
+		assert!(result.is_err());
+	}
+}
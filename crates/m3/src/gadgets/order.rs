@@ -0,0 +1,574 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_core::oracle::ShiftVariant;
+use binius_field::{
+	ExtensionField, Field, PackedExtension, PackedField, PackedFieldIndexable, PackedSubfield,
+	TowerField, ext_basis, packed::set_packed_slice,
+};
+
+use crate::builder::{B1, B128, Col, Error, Expr, TableBuilder, TableWitnessSegment, upcast_col};
+
+/// A gadget asserting that the values of a column are non-decreasing as unsigned integers.
+///
+/// Binary field elements have no native order, so each value is interpreted as the unsigned
+/// integer whose bits are the coordinates of the value in the $\mathbb{F}_2$-basis of the field.
+/// The gadget commits the `n_bits` low bits of the column, which also asserts that the values are
+/// less than `2^n_bits`, and constrains that the subtraction `current - prev` of every two
+/// consecutive values has no final borrow. This is a range check of the difference to
+/// `[0, 2^n_bits)`.
+///
+/// The values are ordered over the whole column in row-major order, so consecutive values may be
+/// in different rows. The bits of the previous value are shifted columns of the bits across the
+/// rows of the table, see [`TableBuilder::add_table_shifted`], and the first value has the
+/// previous value zero, which it is never less than.
+///
+/// ## Requirements
+///
+/// * The table must have a fixed size, see [`TableBuilder::require_fixed_size`], because the shift
+///   spans the whole column.
+/// * The first value of a segment is compared with the last value of the previous one.
+///   [`Self::populate`] takes that value and returns the last value of the segment, so the segments
+///   must be filled sequentially in order, threading the returned value into the next segment.
+///   Parallel fills are not supported.
+#[derive(Debug)]
+pub struct Monotonic<FSub: TowerField, const V: usize> {
+	pub col: Col<FSub, V>,
+	/// The bits of the column values, least significant first.
+	pub bits: Vec<Col<B1, V>>,
+	/// The bits of the previous value in the column.
+	prev_bits: Vec<Col<B1, V>>,
+	/// The borrow out of each bit of the subtraction `current - prev`.
+	borrows: Vec<Col<B1, V>>,
+}
+
+/// Asserts that the values of `col` are non-decreasing as `n_bits`-bit unsigned integers.
+///
+/// The columns and the constraints of the gadget are added in the namespace `name`. See
+/// [`Monotonic`] for the details.
+///
+/// ## Preconditions
+///
+/// * `n_bits` must be positive and at most the bit width of `FSub`.
+/// * The table size must have been fixed with [`TableBuilder::require_fixed_size`].
+pub fn assert_monotonic<FSub, const V: usize>(
+	table: &mut TableBuilder,
+	name: impl ToString,
+	col: Col<FSub, V>,
+	n_bits: usize,
+) -> Monotonic<FSub, V>
+where
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+{
+	assert!(n_bits > 0 && n_bits <= FSub::N_BITS, "n_bits must be in the range [1, FSub::N_BITS]");
+
+	let mut table = table.with_namespace(name);
+
+	let bits = (0..n_bits)
+		.map(|i| table.add_committed::<B1, V>(format!("bits[{i}]")))
+		.collect::<Vec<_>>();
+	table.assert_zero("bit decomposition", pack_bits::<FSub, V>(&bits) - col);
+
+	let prev_bits = add_prev_bits(&mut table, "prev_bits", &bits);
+	let borrows = assert_borrow_chain(&mut table, "borrows", &bits, &prev_bits);
+	table.assert_zero::<B1, V>("non-decreasing", borrows[n_bits - 1].into());
+
+	Monotonic {
+		col,
+		bits,
+		prev_bits,
+		borrows,
+	}
+}
+
+/// Adds the columns of `bits` shifted by one value across the rows, which are the bits of the
+/// previous value in the column, and zero for the first value.
+fn add_prev_bits<const V: usize>(
+	table: &mut TableBuilder,
+	name: &str,
+	bits: &[Col<B1, V>],
+) -> Vec<Col<B1, V>> {
+	bits.iter()
+		.enumerate()
+		.map(|(i, &bit)| {
+			table.add_table_shifted(format!("{name}[{i}]"), bit, 1, ShiftVariant::LogicalLeft)
+		})
+		.collect()
+}
+
+/// Returns the packing of `bits`, least significant first, into an element of `FSub`.
+fn pack_bits<FSub, const V: usize>(bits: &[Col<B1, V>]) -> Expr<FSub, V>
+where
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+{
+	bits.iter()
+		.enumerate()
+		.map(|(i, &bit)| upcast_col(bit) * ext_basis::<FSub, B1>(i))
+		.reduce(|a, b| a + b)
+		.expect("there is at least one bit")
+}
+
+/// Adds the borrow columns of the subtraction `x - y` of the unsigned integers with bits `x_bits`
+/// and `y_bits`, and constrains them with the same equation as in
+/// [`U32Sub`](super::sub::U32Sub).
+///
+/// The borrow out of bit `i` is the column `{name}[i]`, so the last column is the final borrow,
+/// which is one if and only if `x < y`.
+fn assert_borrow_chain<const V: usize>(
+	table: &mut TableBuilder,
+	name: &str,
+	x_bits: &[Col<B1, V>],
+	y_bits: &[Col<B1, V>],
+) -> Vec<Col<B1, V>> {
+	let borrows = (0..x_bits.len())
+		.map(|i| table.add_committed::<B1, V>(format!("{name}[{i}]")))
+		.collect::<Vec<_>>();
+	for (i, (&x, &y)) in x_bits.iter().zip(y_bits).enumerate() {
+		let bout = borrows[i];
+		let constraint: Expr<B1, V> = if i == 0 {
+			(x - B1::ONE) * y - bout
+		} else {
+			let bin = borrows[i - 1];
+			(bin + (x - B1::ONE)) * (bin + y) + bin - bout
+		};
+		table.assert_zero::<B1, V>(format!("{name}[{i}]"), constraint);
+	}
+	borrows
+}
+
+impl<FSub: TowerField, const V: usize> Monotonic<FSub, V> {
+	/// Populates the bit decomposition and the borrow columns of a segment.
+	///
+	/// `prev` is the last value of the previous segment, or zero for the first segment. Returns
+	/// the last value of this segment, to pass to the next one. The input column must already be
+	/// populated.
+	pub fn populate<P>(&self, index: &mut TableWitnessSegment<P>, prev: FSub) -> Result<FSub, Error>
+	where
+		P: PackedField<Scalar = B128> + PackedExtension<B1> + PackedExtension<FSub>,
+		PackedSubfield<P, FSub>: PackedFieldIndexable,
+		B128: ExtensionField<FSub>,
+	{
+		let values = index.get_scalars(self.col)?;
+		let get_bits = |cols: &[Col<B1, V>]| {
+			cols.iter()
+				.map(|&col| index.get_mut(col))
+				.collect::<Result<Vec<_>, _>>()
+		};
+		let mut bits = get_bits(&self.bits)?;
+		let mut prev_bits = get_bits(&self.prev_bits)?;
+		let mut borrows = get_bits(&self.borrows)?;
+
+		let bit = |value: FSub, i: usize| ExtensionField::<B1>::get_base(&value, i) == B1::ONE;
+		for (k, &value) in values.iter().enumerate() {
+			let prev = if k == 0 { prev } else { values[k - 1] };
+			let mut borrow = false;
+			for i in 0..self.bits.len() {
+				let (x, y) = (bit(value, i), bit(prev, i));
+				borrow = (!x && y) || (x == y && borrow);
+				set_packed_slice(&mut bits[i], k, B1::from(x));
+				set_packed_slice(&mut prev_bits[i], k, B1::from(y));
+				set_packed_slice(&mut borrows[i], k, B1::from(borrow));
+			}
+		}
+		Ok(values[values.len() - 1])
+	}
+}
+
+/// Returns the unsigned integer whose bits are the coordinates of `value` in the
+/// $\mathbb{F}_2$-basis of the field.
+fn to_unsigned<FSub: TowerField>(value: FSub) -> u128 {
+	ExtensionField::<B1>::iter_bases(&value)
+		.enumerate()
+		.fold(0, |acc, (i, bit)| acc | (u128::from(bit == B1::ONE) << i))
+}
+
+/// Fills `max_col` with the running maximum of `input_col` over the values of a segment.
+///
+/// The values are compared as unsigned integers, see [`Monotonic`], and the value of `max_col` is
+/// `m[i] = max(m[i - 1], a[i])`, starting from `prev`, the last maximum of the previous segment, or
+/// zero for the first segment. Returns the last maximum of the segment. See [`RunningMax`] for the
+/// constrained version and the segmentation requirements.
+pub fn fill_running_max<P, FSub, const V: usize>(
+	index: &mut TableWitnessSegment<P>,
+	input_col: Col<FSub, V>,
+	max_col: Col<FSub, V>,
+	prev: FSub,
+) -> Result<FSub, Error>
+where
+	P: PackedExtension<FSub, Scalar = B128>,
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+	PackedSubfield<P, FSub>: PackedFieldIndexable,
+{
+	let input = index.get_scalars(input_col)?;
+	let mut max = index.get_scalars_mut(max_col)?;
+	let mut running = prev;
+	for (&value, max) in input.iter().zip(max.iter_mut()) {
+		if to_unsigned(value) > to_unsigned(running) {
+			running = value;
+		}
+		*max = running;
+	}
+	Ok(running)
+}
+
+/// A gadget computing the running maximum of a column as unsigned integers.
+///
+/// The output is `m[i] = max(m[i - 1], a[i])` over all values of the column in row-major order,
+/// where `m[-1]` is zero. The maximum is not a polynomial of its inputs, so the gadget commits it
+/// and constrains that `m[i]` is one of `m[i - 1]` and `a[i]`, and that it is at least both of
+/// them. The comparisons are range checks of the differences, as in [`Monotonic`]: the `n_bits`
+/// low bits of the input and of the maximum are committed, which also asserts that the values are
+/// less than `2^n_bits`, and the subtractions `m[i] - m[i - 1]` and `m[i] - a[i]` must have no
+/// final borrow. As in [`Monotonic`], the bits of `m[i - 1]` are shifted columns of the bits of
+/// the maximum across the rows of the table.
+///
+/// ## Requirements
+///
+/// * The table must have a fixed size, see [`TableBuilder::require_fixed_size`], because the shift
+///   spans the whole column.
+/// * The first maximum of a segment depends on the last maximum of the previous one.
+///   [`Self::populate`] takes that maximum and returns the last maximum of the segment, so the
+///   segments must be filled sequentially in order, threading the returned value into the next
+///   segment. Parallel fills are not supported.
+#[derive(Debug)]
+pub struct RunningMax<FSub: TowerField, const V: usize> {
+	pub input: Col<FSub, V>,
+	pub max: Col<FSub, V>,
+	/// The bits of the input, least significant first.
+	pub input_bits: Vec<Col<B1, V>>,
+	/// The bits of the maximum, least significant first.
+	pub max_bits: Vec<Col<B1, V>>,
+	/// The bits of the previous maximum in the column.
+	prev_max_bits: Vec<Col<B1, V>>,
+	/// The borrow out of each bit of the subtraction `m[j] - m[j - 1]`.
+	prev_borrows: Vec<Col<B1, V>>,
+	/// The borrow out of each bit of the subtraction `m[j] - a[j]`.
+	input_borrows: Vec<Col<B1, V>>,
+}
+
+impl<FSub: TowerField, const V: usize> RunningMax<FSub, V>
+where
+	B128: ExtensionField<FSub>,
+{
+	/// Creates the running maximum of the `n_bits`-bit unsigned integers of `input`, with a
+	/// committed output column.
+	///
+	/// The columns and the constraints of the gadget are added in the namespace `name`.
+	///
+	/// ## Preconditions
+	///
+	/// * `n_bits` must be positive and at most the bit width of `FSub`.
+	/// * The table size must have been fixed with [`TableBuilder::require_fixed_size`].
+	pub fn new(
+		table: &mut TableBuilder,
+		name: impl ToString,
+		input: Col<FSub, V>,
+		n_bits: usize,
+	) -> Self {
+		assert!(
+			n_bits > 0 && n_bits <= FSub::N_BITS,
+			"n_bits must be in the range [1, FSub::N_BITS]"
+		);
+
+		let mut table = table.with_namespace(name);
+		let max = table.add_committed::<FSub, V>("max");
+		let input_bits = (0..n_bits)
+			.map(|i| table.add_committed::<B1, V>(format!("input_bits[{i}]")))
+			.collect::<Vec<_>>();
+		let max_bits = (0..n_bits)
+			.map(|i| table.add_committed::<B1, V>(format!("max_bits[{i}]")))
+			.collect::<Vec<_>>();
+		let prev_max_bits = add_prev_bits(&mut table, "prev_max_bits", &max_bits);
+		table.assert_zero("input bit decomposition", pack_bits::<FSub, V>(&input_bits) - input);
+		table.assert_zero("max bit decomposition", pack_bits::<FSub, V>(&max_bits) - max);
+
+		let prev_borrows =
+			assert_borrow_chain(&mut table, "prev_borrows", &max_bits, &prev_max_bits);
+		table.assert_zero::<B1, V>("max >= prev", prev_borrows[n_bits - 1].into());
+		let input_borrows =
+			assert_borrow_chain(&mut table, "input_borrows", &max_bits, &input_bits);
+		table.assert_zero::<B1, V>("max >= input", input_borrows[n_bits - 1].into());
+		table.assert_zero(
+			"max is prev or input",
+			(pack_bits::<FSub, V>(&prev_max_bits) - max) * (max - input),
+		);
+
+		Self {
+			input,
+			max,
+			input_bits,
+			max_bits,
+			prev_max_bits,
+			prev_borrows,
+			input_borrows,
+		}
+	}
+
+	/// Populates the maximum and the auxiliary columns of a segment.
+	///
+	/// `prev` is the last maximum of the previous segment, or zero for the first segment. Returns
+	/// the last maximum of this segment, to pass to the next one. The input column must already be
+	/// populated.
+	pub fn populate<P>(&self, index: &mut TableWitnessSegment<P>, prev: FSub) -> Result<FSub, Error>
+	where
+		P: PackedField<Scalar = B128> + PackedExtension<B1> + PackedExtension<FSub>,
+		PackedSubfield<P, FSub>: PackedFieldIndexable,
+	{
+		let last = fill_running_max(index, self.input, self.max, prev)?;
+		self.populate_bits(index, prev)?;
+		Ok(last)
+	}
+
+	/// Populates the bit decompositions and the borrow columns from the input and the maximum,
+	/// where `prev` is the last maximum of the previous segment.
+	fn populate_bits<P>(&self, index: &mut TableWitnessSegment<P>, prev: FSub) -> Result<(), Error>
+	where
+		P: PackedField<Scalar = B128> + PackedExtension<B1> + PackedExtension<FSub>,
+		PackedSubfield<P, FSub>: PackedFieldIndexable,
+	{
+		let input = index.get_scalars(self.input)?;
+		let max = index.get_scalars(self.max)?;
+		let get_bits = |cols: &[Col<B1, V>]| {
+			cols.iter()
+				.map(|&col| index.get_mut(col))
+				.collect::<Result<Vec<_>, _>>()
+		};
+		let mut input_bits = get_bits(&self.input_bits)?;
+		let mut max_bits = get_bits(&self.max_bits)?;
+		let mut prev_max_bits = get_bits(&self.prev_max_bits)?;
+		let mut prev_borrows = get_bits(&self.prev_borrows)?;
+		let mut input_borrows = get_bits(&self.input_borrows)?;
+
+		let bit = |value: FSub, i: usize| ExtensionField::<B1>::get_base(&value, i) == B1::ONE;
+		for (k, (&a, &m)) in input.iter().zip(&*max).enumerate() {
+			let prev = if k == 0 { prev } else { max[k - 1] };
+			let (mut prev_borrow, mut input_borrow) = (false, false);
+			for i in 0..self.max_bits.len() {
+				let (x, y, z) = (bit(m, i), bit(prev, i), bit(a, i));
+				prev_borrow = (!x && y) || (x == y && prev_borrow);
+				input_borrow = (!x && z) || (x == z && input_borrow);
+				set_packed_slice(&mut input_bits[i], k, B1::from(z));
+				set_packed_slice(&mut max_bits[i], k, B1::from(x));
+				set_packed_slice(&mut prev_max_bits[i], k, B1::from(y));
+				set_packed_slice(&mut prev_borrows[i], k, B1::from(prev_borrow));
+				set_packed_slice(&mut input_borrows[i], k, B1::from(input_borrow));
+			}
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::array;
+
+	use binius_compute::{alloc::HostBumpAllocator, cpu::alloc::CpuComputeAllocator};
+	use binius_field::{arch::OptimalUnderlier128b, as_packed_field::PackedType};
+	use binius_utils::checked_arithmetics::log2_strict_usize;
+	use rand::{Rng, SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::builder::{
+		B32, ConstraintSystem, WitnessIndex,
+		test_utils::{assert_rejected_across_rows, fill_table_with_carry, validate_system_witness},
+	};
+
+	const V: usize = 16;
+	const N_ROWS: usize = 4;
+
+	#[test]
+	fn test_monotonic() {
+		let sorted = (0..N_ROWS)
+			.map(|row| array::from_fn(|j| B32::new((row * V + j) as u32)))
+			.collect::<Vec<[B32; V]>>();
+		let mut swapped = sorted.clone();
+		swapped[2].swap(5, 6);
+		// The last value of row 1 is greater than the first value of row 2.
+		let mut swapped_across_rows = sorted.clone();
+		(swapped_across_rows[1][V - 1], swapped_across_rows[2][0]) =
+			(swapped_across_rows[2][0], swapped_across_rows[1][V - 1]);
+
+		for (events, failing_row) in [
+			(sorted, None),
+			(swapped, Some(2)),
+			(swapped_across_rows, Some(2)),
+		] {
+			let mut cs = ConstraintSystem::new();
+			let mut table = cs.add_table("monotonic");
+			table.require_fixed_size(log2_strict_usize(N_ROWS));
+			let table_id = table.id();
+			let col = table.add_committed::<B32, V>("col");
+			let monotonic = assert_monotonic(&mut table, "monotonic", col, 8);
+			drop(table);
+
+			let mut allocator = CpuComputeAllocator::new(1 << 14);
+			let allocator = allocator.into_bump_allocator();
+			let mut witness =
+				WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+			fill_table_with_carry(
+				&mut witness,
+				table_id,
+				&events,
+				log2_strict_usize(N_ROWS),
+				B32::ZERO,
+				|events: &[[B32; V]], index, prev| {
+					{
+						let mut col = index.get_scalars_mut(col)?;
+						for (row, event) in col.chunks_mut(V).zip(events) {
+							row.copy_from_slice(event);
+						}
+					}
+					Ok(monotonic.populate(index, prev)?)
+				},
+			)
+			.unwrap();
+
+			let failures = witness.zero_constraint_failures().unwrap();
+			match failing_row {
+				None => {
+					assert!(failures.is_empty());
+					validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+				}
+				Some(row) => {
+					let [failure] = failures.as_slice() else {
+						panic!("expected a single failure, got {failures:?}");
+					};
+					assert_eq!(failure.constraint_name, "monotonic::non-decreasing");
+					assert_eq!(failure.row, row);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_order_gadgets_twice_in_table() {
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("order");
+		table.require_fixed_size(log2_strict_usize(N_ROWS));
+		let a = table.add_committed::<B32, V>("a");
+		let b = table.add_committed::<B32, V>("b");
+		assert_monotonic(&mut table, "monotonic_a", a, 8);
+		assert_monotonic(&mut table, "monotonic_b", b, 8);
+		RunningMax::new(&mut table, "running_max_a", a, 8);
+		RunningMax::new(&mut table, "running_max_b", b, 8);
+		drop(table);
+
+		assert!(cs.compile().is_ok());
+	}
+
+	type Packed = PackedType<OptimalUnderlier128b, B128>;
+
+	/// The running maximum table has two segments of the minimum size for its B1 columns.
+	const LOG_RUNNING_MAX_ROWS: usize = 4;
+	const LOG_RUNNING_MAX_SEGMENT: usize = 3;
+
+	/// Fills the input of the running maximum from the events, and then the rest with `fill_max`,
+	/// which is passed the value returned for the previous segment.
+	fn build_running_max_witness<'a>(
+		cs: &'a ConstraintSystem,
+		allocator: &'a HostBumpAllocator<'a, Packed>,
+		running_max: &RunningMax<B32, V>,
+		events: &[[B32; V]],
+		fill_max: impl Fn(&mut TableWitnessSegment<Packed>, B32) -> Result<B32, Error> + Sync,
+	) -> WitnessIndex<'a, 'a, Packed> {
+		let mut witness = WitnessIndex::new(cs, allocator);
+		fill_table_with_carry(
+			&mut witness,
+			running_max.max.table_id,
+			events,
+			LOG_RUNNING_MAX_SEGMENT,
+			B32::ZERO,
+			|events: &[[B32; V]], index, carry| {
+				{
+					let mut input = index.get_scalars_mut(running_max.input)?;
+					for (row, event) in input.chunks_mut(V).zip(events) {
+						row.copy_from_slice(event);
+					}
+				}
+				Ok(fill_max(index, carry)?)
+			},
+		)
+		.unwrap();
+		witness
+	}
+
+	fn new_running_max(cs: &mut ConstraintSystem) -> RunningMax<B32, V> {
+		let mut table = cs.add_table("running_max");
+		table.require_fixed_size(LOG_RUNNING_MAX_ROWS);
+		let input = table.add_committed::<B32, V>("input");
+		RunningMax::new(&mut table, "running_max", input, 16)
+	}
+
+	fn random_running_max_events() -> Vec<[B32; V]> {
+		let mut rng = StdRng::seed_from_u64(0);
+		(0..1 << LOG_RUNNING_MAX_ROWS)
+			.map(|_| array::from_fn(|_| B32::new(rng.random::<u16>() as u32)))
+			.collect()
+	}
+
+	#[test]
+	fn test_running_max() {
+		let mut cs = ConstraintSystem::new();
+		let running_max = new_running_max(&mut cs);
+		let events = random_running_max_events();
+
+		let mut allocator = CpuComputeAllocator::new(1 << 14);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			build_running_max_witness(&cs, &allocator, &running_max, &events, |index, prev| {
+				running_max.populate(index, prev)
+			});
+		{
+			let segment = witness
+				.get_table(running_max.max.table_id)
+				.unwrap()
+				.full_segment();
+			let max = segment.get_scalars(running_max.max).unwrap();
+			let mut expected = 0;
+			for (&max, &value) in max.iter().zip(events.iter().flatten()) {
+				expected = expected.max(value.val());
+				assert_eq!(max, B32::new(expected));
+			}
+		}
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+
+		// A maximum that is the input itself is not at least the previous maximum where the
+		// input decreases.
+		let mut witness =
+			build_running_max_witness(&cs, &allocator, &running_max, &events, |index, prev| {
+				let input = index.get_scalars(running_max.input)?.to_vec();
+				index
+					.get_scalars_mut(running_max.max)?
+					.copy_from_slice(&input);
+				running_max.populate_bits(index, prev)?;
+				Ok(input[input.len() - 1])
+			});
+		let failures = witness.zero_constraint_failures().unwrap();
+		assert!(!failures.is_empty());
+		assert!(
+			failures
+				.iter()
+				.all(|failure| failure.constraint_name == "running_max::max >= prev")
+		);
+	}
+
+	#[test]
+	fn test_running_max_rejects_restarted_max() {
+		let mut cs = ConstraintSystem::new();
+		let running_max = new_running_max(&mut cs);
+		let events = random_running_max_events();
+
+		// Dropping the carry restarts the maximum at the second segment. Every row is consistent
+		// with the bits of the previous maximum, which are zero at the restart.
+		let mut allocator = CpuComputeAllocator::new(1 << 14);
+		let allocator = allocator.into_bump_allocator();
+		let witness =
+			build_running_max_witness(&cs, &allocator, &running_max, &events, |index, _prev| {
+				running_max.populate(index, B32::ZERO)
+			});
+
+		assert_rejected_across_rows(&cs, witness);
+	}
+}
@@ -0,0 +1,177 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::iter;
+
+use anyhow::ensure;
+use binius_core::constraint_system::channel::ChannelId;
+use binius_field::{
+	ExtensionField, PackedExtension, PackedFieldIndexable, PackedSubfield, TowerField,
+};
+
+use crate::builder::{B128, Col, ConstraintSystem, TableBuilder, TableId, TableWitnessSegment};
+
+/// A multiset-equality argument asserting that one column is a permutation of another.
+///
+/// The columns may live in different tables. The argument is enforced with a dedicated channel:
+/// every row of the first table pushes its value and every row of the second table pulls its
+/// value. The channel balances if and only if the two columns contain the same multiset of values,
+/// which also implies that the two tables have the same height.
+#[derive(Debug)]
+pub struct ColumnPermutation<FSub: TowerField> {
+	/// The channel the permutation argument is flushed to.
+	pub channel: ChannelId,
+	col_b: Col<FSub>,
+}
+
+/// Asserts that `col_b` in table `table_b` is a permutation of `col_a` in table `table_a`.
+///
+/// See [`ColumnPermutation`] for details on the argument.
+///
+/// ## Preconditions
+///
+/// * `col_a` must belong to `table_a` and `col_b` must belong to `table_b`.
+/// * Neither table may be mutably borrowed by a [`TableBuilder`] while this is called.
+pub fn assert_permutation<FSub>(
+	cs: &mut ConstraintSystem,
+	table_a: TableId,
+	col_a: Col<FSub>,
+	table_b: TableId,
+	col_b: Col<FSub>,
+) -> ColumnPermutation<FSub>
+where
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+{
+	assert_eq!(col_a.table_id, table_a, "col_a must belong to table_a");
+	assert_eq!(col_b.table_id, table_b, "col_b must belong to table_b");
+
+	let name_a = &cs.tables[table_a][col_a.id()].name;
+	let name_b = &cs.tables[table_b][col_b.id()].name;
+	let channel = cs.add_channel(format!("permutation({name_a}, {name_b})"));
+
+	TableBuilder::new(&mut cs.tables[table_a]).push(channel, [col_a]);
+	TableBuilder::new(&mut cs.tables[table_b]).pull(channel, [col_b]);
+
+	ColumnPermutation { channel, col_b }
+}
+
+impl<FSub: TowerField> ColumnPermutation<FSub> {
+	/// Populates the permuted column with `values_a` reordered by `permutation`.
+	///
+	/// The permutation is given as the sequence of source indices for the rows of the segment,
+	/// i.e. the `i`-th row of the segment is assigned `values_a[permutation[i]]`. Typically the
+	/// source indices are the events of the permuted table.
+	pub fn populate<P>(
+		&self,
+		witness: &mut TableWitnessSegment<P>,
+		values_a: &[FSub],
+		permutation: impl IntoIterator<Item = usize>,
+	) -> anyhow::Result<()>
+	where
+		P: PackedExtension<FSub>,
+		P::Scalar: TowerField + ExtensionField<FSub>,
+		PackedSubfield<P, FSub>: PackedFieldIndexable,
+	{
+		let mut col_b = witness.get_scalars_mut(self.col_b)?;
+		for (dst, index) in iter::zip(&mut *col_b, permutation) {
+			ensure!(
+				index < values_a.len(),
+				"permutation index {index} is out of range for {} values",
+				values_a.len()
+			);
+			*dst = values_a[index];
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_compute::cpu::alloc::CpuComputeAllocator;
+	use binius_field::{arch::OptimalUnderlier128b, as_packed_field::PackedType};
+	use rand::{SeedableRng, rngs::StdRng, seq::SliceRandom};
+
+	use super::*;
+	use crate::{
+		builder::{B32, StructuredDynSize, WitnessIndex, test_utils::ClosureFiller},
+		gadgets::structured::fill_incrementing_b32,
+	};
+
+	fn validate_permutation(
+		permutation: Vec<usize>,
+	) -> Result<(), binius_core::constraint_system::error::Error> {
+		let log_size = 6;
+
+		let mut cs = ConstraintSystem::new();
+		let mut table_a = cs.add_table("a");
+		table_a.require_power_of_two_size();
+		let table_a_id = table_a.id();
+		let col_a = table_a.add_structured::<B32>(
+			"incrementing",
+			StructuredDynSize::Incrementing {
+				max_size_log: log_size,
+			},
+		);
+		drop(table_a);
+
+		let mut table_b = cs.add_table("b");
+		let table_b_id = table_b.id();
+		let col_b = table_b.add_committed::<B32, 1>("permuted");
+		drop(table_b);
+
+		let permutation_arg = assert_permutation(&mut cs, table_a_id, col_a, table_b_id, col_b);
+
+		let values_a = (0..1 << log_size).map(B32::new).collect::<Vec<_>>();
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(table_a_id, |_events, index| {
+					fill_incrementing_b32(index, col_a)?;
+					Ok(())
+				}),
+				&vec![(); 1 << log_size],
+			)
+			.unwrap();
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(table_b_id, |events, index| {
+					permutation_arg.populate(index, &values_a, events.iter().copied())
+				}),
+				&permutation,
+			)
+			.unwrap();
+
+		let ccs = cs.compile().unwrap();
+		let table_sizes = witness.table_sizes();
+		let witness = witness.into_multilinear_extension_index();
+
+		binius_core::constraint_system::validate::validate_witness(
+			&ccs,
+			&[],
+			&table_sizes,
+			&witness,
+		)
+	}
+
+	#[test]
+	fn test_permutation_of_incrementing() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut permutation = (0..1 << 6).collect::<Vec<_>>();
+		permutation.shuffle(&mut rng);
+		validate_permutation(permutation).unwrap();
+	}
+
+	#[test]
+	fn test_non_permutation_fails() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut permutation = (0..1 << 6).collect::<Vec<_>>();
+		permutation.shuffle(&mut rng);
+		// Duplicate one value in place of another, so that the multisets differ.
+		permutation[0] = permutation[1];
+		assert!(validate_permutation(permutation).is_err());
+	}
+}
@@ -0,0 +1,222 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_core::oracle::ShiftVariant;
+use binius_field::{
+	ExtensionField, PackedExtension, PackedFieldIndexable, PackedSubfield, TowerField,
+};
+
+use crate::builder::{B128, Col, Error, TableBuilder, TableWitnessSegment};
+
+/// A prefix-sum (scan) of a column over the binary field.
+///
+/// The scan runs over all values of the column in row-major order: the output is
+/// `p[i] = a[0] + ... + a[i]`, which is constrained with the recurrence `p[i] = p[i - 1] + a[i]`,
+/// where `p[-1]` is zero. The previous value `p[i - 1]` is a shifted column of the output across
+/// the rows of the table, see [`TableBuilder::add_table_shifted`].
+///
+/// ## Requirements
+///
+/// * The table must have a fixed size, see [`TableBuilder::require_fixed_size`], because the shift
+///   spans the whole column.
+/// * The segments of the table are not independent: the first value of a segment depends on the
+///   last output of the previous one. [`Self::populate`] takes that output and returns the last
+///   output of the segment, so the segments must be filled sequentially in order, threading the
+///   returned value into the next segment. Parallel fills are not supported.
+#[derive(Debug)]
+pub struct PrefixSum<FSub: TowerField, const V: usize> {
+	pub input: Col<FSub, V>,
+	pub output: Col<FSub, V>,
+	/// The output shifted by one value across the rows.
+	output_prev: Col<FSub, V>,
+}
+
+impl<FSub: TowerField, const V: usize> PrefixSum<FSub, V>
+where
+	B128: ExtensionField<FSub>,
+{
+	/// Creates the prefix-sum of `input` with a committed output column.
+	///
+	/// ## Pre-conditions
+	///
+	/// * The table size must have been fixed with [`TableBuilder::require_fixed_size`].
+	pub fn new(table: &mut TableBuilder, input: Col<FSub, V>) -> Self {
+		let output = table.add_committed("prefix_sum");
+		let output_prev =
+			table.add_table_shifted("prefix_sum_prev", output, 1, ShiftVariant::LogicalLeft);
+		table.assert_zero("prefix_sum recurrence", output - output_prev - input);
+		Self {
+			input,
+			output,
+			output_prev,
+		}
+	}
+
+	/// Populates the output and the auxiliary shifted column of a segment.
+	///
+	/// `prev` is the last output of the previous segment, or zero for the first segment. Returns
+	/// the last output of this segment, to pass to the next one. The input column must already be
+	/// populated.
+	pub fn populate<P>(&self, index: &mut TableWitnessSegment<P>, prev: FSub) -> Result<FSub, Error>
+	where
+		P: PackedExtension<FSub, Scalar = B128>,
+		PackedSubfield<P, FSub>: PackedFieldIndexable,
+	{
+		let last = fill_prefix_sum(index, self.input, self.output, prev)?;
+
+		let output = index.get_scalars(self.output)?;
+		let mut output_prev = index.get_scalars_mut(self.output_prev)?;
+		output_prev[0] = prev;
+		output_prev[1..].copy_from_slice(&output[..output.len() - 1]);
+		Ok(last)
+	}
+}
+
+/// Fills `output_col` with the prefix-sum of `input_col` over the values of a segment.
+///
+/// The sum starts from `prev`, the last output of the previous segment, or zero for the first
+/// segment. Returns the last output of the segment. See [`PrefixSum`] for the constrained version
+/// and the segmentation requirements.
+pub fn fill_prefix_sum<P, FSub, const V: usize>(
+	index: &mut TableWitnessSegment<P>,
+	input_col: Col<FSub, V>,
+	output_col: Col<FSub, V>,
+	prev: FSub,
+) -> Result<FSub, Error>
+where
+	P: PackedExtension<FSub, Scalar = B128>,
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+	PackedSubfield<P, FSub>: PackedFieldIndexable,
+{
+	let input = index.get_scalars(input_col)?;
+	let mut output = index.get_scalars_mut(output_col)?;
+	let mut sum = prev;
+	for (&a, p) in input.iter().zip(output.iter_mut()) {
+		sum += a;
+		*p = sum;
+	}
+	Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_compute::{alloc::HostBumpAllocator, cpu::alloc::CpuComputeAllocator};
+	use binius_field::{Field, arch::OptimalUnderlier128b, as_packed_field::PackedType};
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::builder::{
+		B32, ConstraintSystem, WitnessIndex,
+		test_utils::{assert_rejected_across_rows, fill_table_with_carry, validate_system_witness},
+	};
+
+	const LOG_N_ROWS: usize = 6;
+	const V: usize = 4;
+
+	type Packed = PackedType<OptimalUnderlier128b, B128>;
+
+	/// Fills the table in segments of `2^log_segment_size` rows, carrying the prefix-sum across
+	/// the segments.
+	fn build_witness<'a>(
+		cs: &'a ConstraintSystem,
+		allocator: &'a HostBumpAllocator<'a, Packed>,
+		prefix_sum: &PrefixSum<B32, V>,
+		events: &[[B32; V]],
+		log_segment_size: usize,
+	) -> WitnessIndex<'a, 'a, Packed> {
+		let mut witness = WitnessIndex::new(cs, allocator);
+		fill_table_with_carry(
+			&mut witness,
+			prefix_sum.output.table_id,
+			events,
+			log_segment_size,
+			B32::ZERO,
+			|events: &[[B32; V]], index, carry| {
+				{
+					let mut input = index.get_scalars_mut(prefix_sum.input)?;
+					for (row, event) in input.chunks_mut(V).zip(events) {
+						row.copy_from_slice(event);
+					}
+				}
+				Ok(prefix_sum.populate(index, carry)?)
+			},
+		)
+		.unwrap();
+		witness
+	}
+
+	#[test]
+	fn test_prefix_sum() {
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("prefix_sum");
+		table.require_fixed_size(LOG_N_ROWS);
+		let input = table.add_committed::<B32, V>("input");
+		let prefix_sum = PrefixSum::new(&mut table, input);
+		drop(table);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let events = repeat_with(|| std::array::from_fn(|_| B32::random(&mut rng)))
+			.take(1 << LOG_N_ROWS)
+			.collect::<Vec<[B32; V]>>();
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness = build_witness(&cs, &allocator, &prefix_sum, &events, 3);
+
+		{
+			let segment = witness
+				.get_table(prefix_sum.output.table_id)
+				.unwrap()
+				.full_segment();
+			let output = segment.get_scalars(prefix_sum.output).unwrap();
+			let mut expected = B32::ZERO;
+			for (&p, &a) in output.iter().zip(events.iter().flatten()) {
+				expected += a;
+				assert_eq!(p, expected);
+			}
+		}
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+	}
+
+	#[test]
+	fn test_prefix_sum_rejects_restarted_sum() {
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("prefix_sum");
+		table.require_fixed_size(LOG_N_ROWS);
+		let input = table.add_committed::<B32, V>("input");
+		let prefix_sum = PrefixSum::new(&mut table, input);
+		drop(table);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let events = repeat_with(|| std::array::from_fn(|_| B32::random(&mut rng)))
+			.take(1 << LOG_N_ROWS)
+			.collect::<Vec<[B32; V]>>();
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness = build_witness(&cs, &allocator, &prefix_sum, &events, 3);
+
+		// Restart the sum in the middle of the table, keeping the shifted column consistent with
+		// the output, as a fill that drops the carry between segments would.
+		{
+			let segment = witness
+				.get_table(prefix_sum.output.table_id)
+				.unwrap()
+				.full_segment();
+			let start = (1 << LOG_N_ROWS) * V / 2;
+			let mut output = segment.get_scalars_mut(prefix_sum.output).unwrap();
+			let mut output_prev = segment.get_scalars_mut(prefix_sum.output_prev).unwrap();
+			let input = segment.get_scalars(prefix_sum.input).unwrap();
+			let mut sum = B32::ZERO;
+			for i in start..output.len() {
+				sum += input[i];
+				output[i] = sum;
+				output_prev[i] = if i == start { B32::ZERO } else { output[i - 1] };
+			}
+		}
+
+		assert_rejected_across_rows(&cs, witness);
+	}
+}
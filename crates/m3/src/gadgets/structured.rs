@@ -1,8 +1,20 @@
 // Copyright 2025 Irreducible Inc.
 
-use binius_field::{PackedExtension, PackedField, PackedFieldIndexable, PackedSubfield};
+use binius_core::oracle::ShiftVariant;
+use binius_field::{
+	ExtensionField, Field, PackedExtension, PackedField, PackedFieldIndexable, PackedSubfield,
+	TowerField, packed::set_packed_slice,
+};
+use binius_math::{ArithCircuit, ArithExpr};
 
-use crate::builder::{B32, B128, column::Col, error::Error, witness::TableWitnessSegment};
+use crate::builder::{
+	B1, B32, B128, Structured, StructuredDynSize, TableBuilder,
+	column::Col,
+	error::Error,
+	structured::{Error as StructuredError, incrementing_expr, morton_bit_position},
+	upcast_col,
+	witness::TableWitnessSegment,
+};
 
 /// Fills a structured [`crate::builder::structured::StructuredDynSize::Incrementing`] B32 column
 /// with values.
@@ -24,3 +36,659 @@ where
 	}
 	Ok(())
 }
+
+/// Fills a structured [`crate::builder::structured::StructuredDynSize::IncrementingBigEndian`] B32
+/// column with values.
+///
+/// `max_size_log` must match the one the column was declared with, since it determines the bit
+/// order of the values.
+pub fn fill_incrementing_b32_big_endian<P>(
+	witness: &mut TableWitnessSegment<P>,
+	col: Col<B32>,
+	max_size_log: usize,
+) -> Result<(), Error>
+where
+	P: PackedField<Scalar = B128> + PackedExtension<B32>,
+	PackedSubfield<P, B32>: PackedFieldIndexable,
+{
+	assert!(max_size_log <= 32);
+	let mut col_data = witness.get_scalars_mut(col)?;
+	let start_index = witness.index() << witness.log_size();
+	for (i, col_data_i) in col_data.iter_mut().enumerate() {
+		let reversed = ((start_index + i) as u32).reverse_bits();
+		*col_data_i = B32::new(reversed.checked_shr(32 - max_size_log as u32).unwrap_or(0));
+	}
+	Ok(())
+}
+
+/// Fills a structured [`crate::builder::StructuredTable`] column with the values of the table.
+///
+/// `values` must be the values the column was declared with. The rows past the end of the values
+/// are filled with zeros.
+pub fn fill_table_values<P, FSub>(
+	witness: &mut TableWitnessSegment<P>,
+	col: Col<FSub>,
+	values: &[FSub],
+) -> Result<(), Error>
+where
+	P: PackedField<Scalar = B128> + PackedExtension<FSub>,
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+	PackedSubfield<P, FSub>: PackedFieldIndexable,
+{
+	let mut col_data = witness.get_scalars_mut(col)?;
+	let start_index = witness.index() << witness.log_size();
+	for (i, col_data_i) in col_data.iter_mut().enumerate() {
+		*col_data_i = values.get(start_index + i).copied().unwrap_or(FSub::ZERO);
+	}
+	Ok(())
+}
+
+/// Fills a structured [`crate::builder::structured::StructuredDynSize::Parity`] column with values.
+pub fn fill_parity<P, FSub>(
+	witness: &mut TableWitnessSegment<P>,
+	col: Col<FSub>,
+) -> Result<(), Error>
+where
+	P: PackedField<Scalar = B128> + PackedExtension<FSub>,
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+	PackedSubfield<P, FSub>: PackedFieldIndexable,
+{
+	let mut col_data = witness.get_scalars_mut(col)?;
+	let start_index = witness.index() << witness.log_size();
+	for (i, col_data_i) in col_data.iter_mut().enumerate() {
+		*col_data_i = if (start_index + i) % 2 == 1 {
+			FSub::ONE
+		} else {
+			FSub::ZERO
+		};
+	}
+	Ok(())
+}
+
+/// Fills a structured [`crate::builder::structured::StructuredDynSize::Complement`] B32 column with
+/// values.
+///
+/// Row `i` is assigned `(2^max_size_log - 1) - i`, the complement of `i` over `max_size_log` bits,
+/// which must match the `max_size_log` the column was declared with.
+pub fn fill_complement_b32<P>(
+	witness: &mut TableWitnessSegment<P>,
+	col: Col<B32>,
+	max_size_log: usize,
+) -> Result<(), Error>
+where
+	P: PackedField<Scalar = B128> + PackedExtension<B32>,
+	PackedSubfield<P, B32>: PackedFieldIndexable,
+{
+	assert!(max_size_log <= 32);
+	let mask = u32::MAX.checked_shr(32 - max_size_log as u32).unwrap_or(0);
+	let mut col_data = witness.get_scalars_mut(col)?;
+	let start_index = witness.index() << witness.log_size();
+	for (i, col_data_i) in col_data.iter_mut().enumerate() {
+		*col_data_i = B32::new(!((start_index + i) as u32) & mask);
+	}
+	Ok(())
+}
+
+/// Fills a structured [`crate::builder::structured::StructuredDynSize::LowBits`] column with
+/// values.
+///
+/// Row `i` is assigned the low `bits` bits of `i` as a field element, with bit `j` weighted by the
+/// `j`-th basis element of `FSub`.
+///
+/// ## Preconditions
+///
+/// * `bits` must be at most the bit width of `FSub`.
+pub fn fill_low_bits<P, FSub>(
+	witness: &mut TableWitnessSegment<P>,
+	col: Col<FSub>,
+	bits: usize,
+) -> Result<(), Error>
+where
+	P: PackedField<Scalar = B128> + PackedExtension<FSub>,
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+	PackedSubfield<P, FSub>: PackedFieldIndexable,
+{
+	assert!(bits <= FSub::N_BITS, "bits must be at most FSub::N_BITS");
+	let mut col_data = witness.get_scalars_mut(col)?;
+	let start_index = witness.index() << witness.log_size();
+	for (i, col_data_i) in col_data.iter_mut().enumerate() {
+		let index = start_index + i;
+		*col_data_i = (0..bits.min(usize::BITS as usize))
+			.filter(|&j| (index >> j) & 1 == 1)
+			.map(|j| <FSub as ExtensionField<B1>>::basis(j))
+			.sum();
+	}
+	Ok(())
+}
+
+/// Fills a structured [`crate::builder::structured::StructuredDynSize::Morton`] column with
+/// values.
+///
+/// Row `i` is assigned the Morton code of `i` as a field element, with bit `j` of `i` weighted by
+/// the basis element of `FSub` at [`morton_bit_position`].
+///
+/// ## Preconditions
+///
+/// * `x_bits + y_bits` must be at most the bit width of `FSub`.
+pub fn fill_morton<P, FSub>(
+	witness: &mut TableWitnessSegment<P>,
+	col: Col<FSub>,
+	x_bits: usize,
+	y_bits: usize,
+) -> Result<(), Error>
+where
+	P: PackedField<Scalar = B128> + PackedExtension<FSub>,
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+	PackedSubfield<P, FSub>: PackedFieldIndexable,
+{
+	assert!(x_bits + y_bits <= FSub::N_BITS, "x_bits + y_bits must be at most FSub::N_BITS");
+	let perm = (0..x_bits + y_bits)
+		.map(|j| morton_bit_position(x_bits, y_bits, j))
+		.collect::<Vec<_>>();
+	fill_bit_permute(witness, col, &perm)
+}
+
+/// Fills a structured [`crate::builder::structured::StructuredDynSize::BitPermute`] column with
+/// values.
+///
+/// Row `i` is assigned `i` as a field element, with bit `j` of `i` weighted by the basis element
+/// of `FSub` at `perm[j]`.
+///
+/// ## Preconditions
+///
+/// * `perm` must be a permutation of `0..n` for an `n` of at most the bit width of `FSub`.
+pub fn fill_bit_permute<P, FSub>(
+	witness: &mut TableWitnessSegment<P>,
+	col: Col<FSub>,
+	perm: &[usize],
+) -> Result<(), Error>
+where
+	P: PackedField<Scalar = B128> + PackedExtension<FSub>,
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+	PackedSubfield<P, FSub>: PackedFieldIndexable,
+{
+	assert!(perm.len() <= FSub::N_BITS, "perm must have at most FSub::N_BITS bits");
+	let weights = perm
+		.iter()
+		.map(|&position| <FSub as ExtensionField<B1>>::basis(position))
+		.collect::<Vec<_>>();
+	let mut col_data = witness.get_scalars_mut(col)?;
+	let start_index = witness.index() << witness.log_size();
+	for (i, col_data_i) in col_data.iter_mut().enumerate() {
+		let index = start_index + i;
+		*col_data_i = weights
+			.iter()
+			.enumerate()
+			.filter(|&(j, _)| (index >> j) & 1 == 1)
+			.map(|(_, &weight)| weight)
+			.sum();
+	}
+	Ok(())
+}
+
+/// Fills a structured [`crate::builder::structured::StructuredDynSize::lane_id`] column with
+/// values.
+///
+/// Row `i` is assigned the lane index `i % W` as a field element, where `W` is the width of the
+/// packed elements storing the column.
+pub fn fill_lane_id<P, FSub>(
+	witness: &mut TableWitnessSegment<P>,
+	col: Col<FSub>,
+) -> Result<(), Error>
+where
+	P: PackedField<Scalar = B128> + PackedExtension<FSub>,
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+	PackedSubfield<P, FSub>: PackedFieldIndexable,
+{
+	fill_low_bits(witness, col, <PackedSubfield<P, FSub>>::LOG_WIDTH)
+}
+
+/// Fills a structured [`crate::builder::structured::StructuredDynSize::sub_element_index`] column
+/// with values.
+///
+/// Row `i` is assigned the position `i % D` as a field element, where `D` is the degree of B128
+/// over `FPacked`, the field of the values packed into B128.
+pub fn fill_sub_element_index<P, FSub, FPacked>(
+	witness: &mut TableWitnessSegment<P>,
+	col: Col<FSub>,
+) -> Result<(), Error>
+where
+	P: PackedField<Scalar = B128> + PackedExtension<FSub>,
+	FSub: TowerField,
+	FPacked: Field,
+	B128: ExtensionField<FSub> + ExtensionField<FPacked>,
+	PackedSubfield<P, FSub>: PackedFieldIndexable,
+{
+	fill_low_bits(witness, col, <B128 as ExtensionField<FPacked>>::LOG_DEGREE)
+}
+
+/// Fills a structured [`crate::builder::SubElementBasis`] column with values.
+///
+/// Row `i` is assigned the `i % D`-th basis element of B128 over `FPacked`, where `D` is the
+/// degree of B128 over `FPacked`.
+pub fn fill_sub_element_basis<P, FPacked>(
+	witness: &mut TableWitnessSegment<P>,
+	col: Col<B128>,
+) -> Result<(), Error>
+where
+	P: PackedField<Scalar = B128> + PackedExtension<B128>,
+	FPacked: Field,
+	B128: ExtensionField<FPacked>,
+	PackedSubfield<P, B128>: PackedFieldIndexable,
+{
+	let mut col_data = witness.get_scalars_mut::<B128, 1>(col)?;
+	let start_index = witness.index() << witness.log_size();
+	let degree = <B128 as ExtensionField<FPacked>>::DEGREE;
+	for (i, col_data_i) in col_data.iter_mut().enumerate() {
+		*col_data_i = <B128 as ExtensionField<FPacked>>::basis((start_index + i) % degree);
+	}
+	Ok(())
+}
+
+/// Fills a structured [`crate::builder::structured::Affine`] column with values.
+///
+/// Row `i` is assigned `slope * i + intercept`, with `i` as the field element with the bits of
+/// `i`. `slope` and `intercept` must be the ones the column was declared with.
+pub fn fill_affine<P, FSub>(
+	witness: &mut TableWitnessSegment<P>,
+	col: Col<FSub>,
+	slope: FSub,
+	intercept: FSub,
+) -> Result<(), Error>
+where
+	P: PackedField<Scalar = B128> + PackedExtension<FSub>,
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+	PackedSubfield<P, FSub>: PackedFieldIndexable,
+{
+	fill_low_bits(witness, col, FSub::N_BITS)?;
+	for value in &mut *witness.get_scalars_mut(col)? {
+		*value = slope * *value + intercept;
+	}
+	Ok(())
+}
+
+/// Fills a structured [`crate::builder::structured::Scaled`] column with values.
+///
+/// The column is first filled by `fill_inner`, the fill of the inner column, and then multiplied
+/// by `factor`, which must be the factor the column was declared with.
+pub fn fill_scaled<P, FSub>(
+	witness: &mut TableWitnessSegment<P>,
+	col: Col<FSub>,
+	factor: FSub,
+	fill_inner: impl FnOnce(&mut TableWitnessSegment<P>, Col<FSub>) -> Result<(), Error>,
+) -> Result<(), Error>
+where
+	P: PackedField<Scalar = B128> + PackedExtension<FSub>,
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+	PackedSubfield<P, FSub>: PackedFieldIndexable,
+{
+	fill_inner(witness, col)?;
+	for value in &mut *witness.get_scalars_mut(col)? {
+		*value *= factor;
+	}
+	Ok(())
+}
+
+/// Fills a structured [`crate::builder::structured::Sum`] column with values.
+///
+/// The column is filled by `fill_lhs` and `fill_rhs`, the fills of the two summed columns, one
+/// after the other, and the first values are added back into the second.
+pub fn fill_sum<P, FSub>(
+	witness: &mut TableWitnessSegment<P>,
+	col: Col<FSub>,
+	fill_lhs: impl FnOnce(&mut TableWitnessSegment<P>, Col<FSub>) -> Result<(), Error>,
+	fill_rhs: impl FnOnce(&mut TableWitnessSegment<P>, Col<FSub>) -> Result<(), Error>,
+) -> Result<(), Error>
+where
+	P: PackedField<Scalar = B128> + PackedExtension<FSub>,
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+	PackedSubfield<P, FSub>: PackedFieldIndexable,
+{
+	fill_lhs(witness, col)?;
+	let lhs = witness.get_scalars(col)?.to_vec();
+	fill_rhs(witness, col)?;
+	for (value, lhs) in witness.get_scalars_mut(col)?.iter_mut().zip(lhs) {
+		*value += lhs;
+	}
+	Ok(())
+}
+
+/// Fills a B32 column with the Hamming weight (popcount) of the row index.
+///
+/// Row `i` is assigned the integer `i.count_ones()` as a B32 element. This is a fill-only gadget:
+/// the popcount of the index bits is an integer sum, which is not $\mathbb{F}_2$-linear, so it has
+/// no structured multilinear extension and the column must be committed.
+pub fn fill_popcount<P>(witness: &mut TableWitnessSegment<P>, col: Col<B32>) -> Result<(), Error>
+where
+	P: PackedField<Scalar = B128> + PackedExtension<B32>,
+	PackedSubfield<P, B32>: PackedFieldIndexable,
+{
+	let mut col_data = witness.get_scalars_mut(col)?;
+	let start_index = witness.index() << witness.log_size();
+	for (i, col_data_i) in col_data.iter_mut().enumerate() {
+		*col_data_i = B32::new((start_index + i).count_ones());
+	}
+	Ok(())
+}
+
+/// Fills a B32 column with the position of the most significant set bit of the row index.
+///
+/// Row `i > 0` is assigned the integer `i.ilog2()` as a B32 element, and row `0`, which has no set
+/// bits, is assigned zero, the same as row `1`. Like [`fill_popcount`], this is a fill-only gadget:
+/// the position is not an $\mathbb{F}_2$-linear function of the index bits, so the column must be
+/// committed.
+pub fn fill_msb_position<P>(
+	witness: &mut TableWitnessSegment<P>,
+	col: Col<B32>,
+) -> Result<(), Error>
+where
+	P: PackedField<Scalar = B128> + PackedExtension<B32>,
+	PackedSubfield<P, B32>: PackedFieldIndexable,
+{
+	let mut col_data = witness.get_scalars_mut(col)?;
+	let start_index = witness.index() << witness.log_size();
+	for (i, col_data_i) in col_data.iter_mut().enumerate() {
+		*col_data_i = B32::new((start_index + i).checked_ilog2().unwrap_or(0));
+	}
+	Ok(())
+}
+
+/// Fills a B32 column with the row index, saturating at `max_value`.
+///
+/// Row `i` is assigned `min(i, max_value)`, so the column increments up to and including the row
+/// at index `max_value` and holds `max_value` on every row after it.
+pub fn fill_incrementing_saturating<P>(
+	witness: &mut TableWitnessSegment<P>,
+	col: Col<B32>,
+	max_value: u32,
+) -> Result<(), Error>
+where
+	P: PackedField<Scalar = B128> + PackedExtension<B32>,
+	PackedSubfield<P, B32>: PackedFieldIndexable,
+{
+	let mut col_data = witness.get_scalars_mut(col)?;
+	let start_index = witness.index() << witness.log_size();
+	for (i, col_data_i) in col_data.iter_mut().enumerate() {
+		*col_data_i = B32::new(((start_index + i) as u32).min(max_value));
+	}
+	Ok(())
+}
+
+/// Fills a B32 column with the running XOR of the row indices.
+///
+/// Row `i` is assigned `0 ^ 1 ^ ... ^ i`, which is the prefix sum of the incrementing column over
+/// the binary field. A prefix reduction carries across rows, so the column is fill-only, see
+/// [`PrefixSum`](super::scan::PrefixSum). The carry into a segment is the running XOR up to the
+/// row before its first one, which has a closed form in the index, so the segments are independent
+/// and can be filled in any order.
+pub fn fill_prefix_xor_index<P>(
+	witness: &mut TableWitnessSegment<P>,
+	col: Col<B32>,
+) -> Result<(), Error>
+where
+	P: PackedField<Scalar = B128> + PackedExtension<B32>,
+	PackedSubfield<P, B32>: PackedFieldIndexable,
+{
+	let mut col_data = witness.get_scalars_mut(col)?;
+	let start_index = witness.index() << witness.log_size();
+	let mut carry = prefix_xor_before(start_index);
+	for (i, col_data_i) in col_data.iter_mut().enumerate() {
+		carry ^= (start_index + i) as u32;
+		*col_data_i = B32::new(carry);
+	}
+	Ok(())
+}
+
+/// Returns `0 ^ 1 ^ ... ^ (n - 1)`, the running XOR of the indices before `n`.
+fn prefix_xor_before(n: usize) -> u32 {
+	// The XOR of the four indices `4k, ..., 4k + 3` is zero.
+	let n = n as u32;
+	match n % 4 {
+		0 => 0,
+		1 => n - 1,
+		2 => 1,
+		_ => n,
+	}
+}
+
+/// The running XOR of the row indices, see [`fill_prefix_xor_index`], with its recurrence.
+///
+/// The output is constrained with `output = output_prev + index` against a structured fixed-size
+/// column of the row indices, where `output_prev` is the output shifted down by one row, and so is
+/// zero on the first row.
+///
+/// ## Pre-conditions
+///
+/// * The table size must have been fixed to `2^log_size` with [`TableBuilder::require_fixed_size`],
+///   as the shift spans the whole column.
+#[derive(Debug)]
+pub struct PrefixXorIndex {
+	/// The structured fixed-size column of the row indices.
+	pub incrementing: Col<B32>,
+	/// The running XOR up to the previous row, which is zero on the first row.
+	pub output_prev: Col<B32>,
+	/// The running XOR up to and including the row.
+	pub output: Col<B32>,
+}
+
+impl PrefixXorIndex {
+	pub fn new(table: &mut TableBuilder, log_size: usize) -> Self {
+		let incrementing = table.add_fixed(
+			"incrementing",
+			ArithCircuit::from(
+				incrementing_expr(log_size).expect("log_size must fit in the bits of B128"),
+			),
+		);
+		let output = table.add_committed("prefix_xor");
+		let output_prev =
+			table.add_table_shifted("prefix_xor_prev", output, 1, ShiftVariant::LogicalLeft);
+		table.assert_zero(
+			"prefix_xor = prefix_xor_prev + incrementing",
+			output - output_prev - incrementing,
+		);
+		Self {
+			incrementing,
+			output_prev,
+			output,
+		}
+	}
+
+	pub fn populate<P>(&self, witness: &mut TableWitnessSegment<P>) -> Result<(), Error>
+	where
+		P: PackedField<Scalar = B128> + PackedExtension<B32>,
+		PackedSubfield<P, B32>: PackedFieldIndexable,
+	{
+		fill_incrementing_b32(witness, self.incrementing)?;
+		fill_prefix_xor_index(witness, self.output)?;
+
+		let mut output_prev = witness.get_scalars_mut(self.output_prev)?;
+		let start_index = witness.index() << witness.log_size();
+		for (i, output_prev_i) in output_prev.iter_mut().enumerate() {
+			*output_prev_i = B32::new(prefix_xor_before(start_index + i));
+		}
+		Ok(())
+	}
+}
+
+/// Fills a structured [`StructuredDynSize::Incrementing`] B32 column with the row indices and a
+/// committed B32 column with their multiplicative inverses.
+///
+/// The row index `i` is read as the B32 field element with the bits of `i`. By convention, the
+/// inverse of zero is zero.
+///
+/// [`StructuredDynSize::Incrementing`]: crate::builder::StructuredDynSize::Incrementing
+pub fn fill_inverse_table<P>(
+	witness: &mut TableWitnessSegment<P>,
+	index_col: Col<B32>,
+	inverse_col: Col<B32>,
+) -> Result<(), Error>
+where
+	P: PackedField<Scalar = B128> + PackedExtension<B32>,
+	PackedSubfield<P, B32>: PackedFieldIndexable,
+{
+	fill_incrementing_b32(witness, index_col)?;
+	let mut inverse = witness.get_scalars_mut(inverse_col)?;
+	let start_index = witness.index() << witness.log_size();
+	for (i, inverse_i) in inverse.iter_mut().enumerate() {
+		*inverse_i = B32::new((start_index + i) as u32).invert_or_zero();
+	}
+	Ok(())
+}
+
+/// A table of the row indices paired with their multiplicative inverses in B32.
+///
+/// The inverse function is not MLE-structured, so the inverse column is committed. It is
+/// constrained against a structured [`StructuredDynSize::Incrementing`] column by
+/// `index * (index * inverse - 1) = 0`, which forces `inverse = index^{-1}` on the nonzero rows,
+/// and `inverse * (index * inverse - 1) = 0`, which forces the inverse on the zero row to be zero.
+///
+/// [`StructuredDynSize::Incrementing`]: crate::builder::StructuredDynSize::Incrementing
+#[derive(Debug)]
+pub struct InverseTable {
+	/// The structured incrementing column of the row indices.
+	pub index: Col<B32>,
+	/// The inverse of the row index, or zero on the zero row.
+	pub inverse: Col<B32>,
+}
+
+impl InverseTable {
+	pub fn new(table: &mut TableBuilder, max_size_log: usize) -> Self {
+		let index = table.add_structured("index", StructuredDynSize::Incrementing { max_size_log });
+		let inverse = table.add_committed("inverse");
+		table.assert_zero("index * inverse = 1", index * (index * inverse - B32::ONE));
+		table.assert_zero("inverse of zero is zero", inverse * (index * inverse - B32::ONE));
+		Self { index, inverse }
+	}
+
+	pub fn populate<P>(&self, witness: &mut TableWitnessSegment<P>) -> Result<(), Error>
+	where
+		P: PackedField<Scalar = B128> + PackedExtension<B32>,
+		PackedSubfield<P, B32>: PackedFieldIndexable,
+	{
+		fill_inverse_table(witness, self.index, self.inverse)
+	}
+}
+
+/// A B1 structured column that is set on the rows whose index is at least `threshold`.
+///
+/// The variables of the expression are the bits of the row index, as for
+/// [`StructuredDynSize::Incrementing`], and the expression compares them with the bits of the
+/// threshold. `threshold` must be less than `2^max_size_log`.
+///
+/// [`StructuredDynSize::Incrementing`]: crate::builder::StructuredDynSize::Incrementing
+#[derive(Debug, Clone, Copy)]
+pub struct IndexAtLeast {
+	pub threshold: u32,
+	pub max_size_log: usize,
+}
+
+impl<F: TowerField> Structured<F> for IndexAtLeast {
+	fn expr(&self) -> Result<ArithExpr<F>, StructuredError> {
+		assert!((self.threshold as u64) < 1 << self.max_size_log);
+		// Going from the least significant bit up, the index is at least the threshold on its
+		// lowest `k + 1` bits if bit `k` of the index is greater than that of the threshold, or if
+		// the bits are equal and the index is at least the threshold on its lowest `k` bits. Each
+		// variable is used once, so the expression is multilinear and linear in size.
+		let mut at_least = ArithExpr::one();
+		for k in 0..self.max_size_log {
+			let bit = ArithExpr::Var(k);
+			at_least = if (self.threshold >> k) & 1 == 1 {
+				bit * at_least
+			} else {
+				bit.clone() + (ArithExpr::one() + bit) * at_least
+			};
+		}
+		Ok(at_least)
+	}
+
+	fn max_size_log(&self) -> usize {
+		self.max_size_log
+	}
+}
+
+/// Fills an [`IndexAtLeast`] column with values.
+pub fn fill_index_at_least<P>(
+	witness: &mut TableWitnessSegment<P>,
+	col: Col<B1>,
+	threshold: u32,
+) -> Result<(), Error>
+where
+	P: PackedField<Scalar = B128> + PackedExtension<B1>,
+{
+	let mut col_data = witness.get_mut(col)?;
+	let start_index = witness.index() << witness.log_size();
+	for i in 0..witness.size() {
+		let is_at_least = start_index + i >= threshold as usize;
+		set_packed_slice(&mut col_data, i, if is_at_least { B1::ONE } else { B1::ZERO });
+	}
+	Ok(())
+}
+
+/// A B32 column that increments with the row index and saturates at a maximum value.
+///
+/// Saturation is not MLE-structured, so the column is committed. It is constrained against a
+/// structured [`StructuredDynSize::Incrementing`] column with a structured [`IndexAtLeast`] flag:
+/// on rows where the flag is unset the column equals the row index, and on rows where it is set
+/// the column equals `max_value`, i.e. it holds the value of the previous row.
+///
+/// The flag is set exactly on the rows with index at least `max_value`, and since it is
+/// structured, the prover cannot saturate the column at another row. At the boundary row, whose
+/// index equals `max_value`, both cases agree. `max_value` must be less than `2^max_size_log`.
+///
+/// [`StructuredDynSize::Incrementing`]: crate::builder::StructuredDynSize::Incrementing
+#[derive(Debug)]
+pub struct SaturatingIncrementing {
+	/// The structured incrementing column that the saturating column follows.
+	pub incrementing: Col<B32>,
+	/// Whether the row is saturated.
+	pub saturated: Col<B1>,
+	/// The saturating incrementing column.
+	pub output: Col<B32>,
+	max_value: u32,
+}
+
+impl SaturatingIncrementing {
+	pub fn new(table: &mut TableBuilder, max_size_log: usize, max_value: u32) -> Self {
+		let incrementing =
+			table.add_structured("incrementing", StructuredDynSize::Incrementing { max_size_log });
+		let saturated = table.add_structured(
+			"saturated",
+			IndexAtLeast {
+				threshold: max_value,
+				max_size_log,
+			},
+		);
+		let output = table.add_committed("output");
+		let max = B32::new(max_value);
+		table.assert_zero(
+			"output = saturated ? max : incrementing",
+			output - (incrementing + upcast_col(saturated) * (incrementing + max)),
+		);
+		Self {
+			incrementing,
+			saturated,
+			output,
+			max_value,
+		}
+	}
+
+	pub fn populate<P>(&self, witness: &mut TableWitnessSegment<P>) -> Result<(), Error>
+	where
+		P: PackedField<Scalar = B128> + PackedExtension<B1> + PackedExtension<B32>,
+		PackedSubfield<P, B32>: PackedFieldIndexable,
+	{
+		fill_incrementing_b32(witness, self.incrementing)?;
+		fill_index_at_least(witness, self.saturated, self.max_value)?;
+		fill_incrementing_saturating(witness, self.output, self.max_value)?;
+		Ok(())
+	}
+}
@@ -77,6 +77,17 @@
 //! assumes their values are already populated during witness population. The gadget defines output
 //! and internal columns, and exposes only the output columns to the caller.
 
+// Lets the code derived with `#[derive(TableSchema)]` refer to this crate as `::binius_m3` from
+// within it too.
+extern crate self as binius_m3;
+
 pub mod builder;
 pub mod emulate;
 pub mod gadgets;
+
+/// The items that the code derived with `#[derive(TableSchema)]` refers to, so that it does not
+/// depend on the module layout of the crate.
+#[doc(hidden)]
+pub mod __private {
+	pub use crate::builder::{StructuredDynSize, TableBuilder, TableSchema};
+}
@@ -0,0 +1,237 @@
+// Copyright 2025 Irreducible Inc.
+
+//! A structured column defined outside of the crate through the [`Structured`] trait.
+
+use assert_matches::assert_matches;
+use binius_compute::cpu::alloc::CpuComputeAllocator;
+use binius_core::oracle::Error as OracleError;
+use binius_field::{arch::OptimalUnderlier128b, as_packed_field::PackedType};
+use binius_m3::builder::{
+	self, B32, B128, ConstraintSystem, Structured, StructuredCost, VarAllocator, WitnessIndex,
+	structured::Error,
+	test_utils::{ClosureFiller, ConstantStructured, validate_system_witness},
+};
+use binius_math::{ArithExpr, Error as MathError};
+
+#[test]
+fn test_custom_constant_structured_column() {
+	let log_size = 6;
+	let value = B32::new(0xdeadbeef);
+
+	let mut cs = ConstraintSystem::new();
+	let mut table = cs.add_table("constant");
+	table.require_power_of_two_size();
+	let table_id = table.id();
+	let constant = table.add_structured::<B32>(
+		"constant",
+		ConstantStructured {
+			value: value.into(),
+			max_size_log: log_size,
+		},
+	);
+	let committed = table.add_committed::<B32, 1>("committed");
+	table.assert_zero("committed = constant", committed - constant);
+	drop(table);
+
+	let mut allocator = CpuComputeAllocator::new(1 << 12);
+	let allocator = allocator.into_bump_allocator();
+	let mut witness = WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+	witness
+		.fill_table_sequential(
+			&ClosureFiller::new(table_id, |_events: &[()], index| {
+				let structured = index.get_structured_values(constant)?.collect::<Vec<_>>();
+				assert!(structured.iter().all(|&v| v == value));
+				index
+					.get_scalars_mut(constant)?
+					.copy_from_slice(&structured);
+				index
+					.get_scalars_mut(committed)?
+					.copy_from_slice(&structured);
+				Ok(())
+			}),
+			&[(); 1 << 6],
+		)
+		.unwrap();
+
+	validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+}
+
+/// A column whose expression is a product of many distinct factors, which is far too large to be
+/// practical.
+#[derive(Debug)]
+struct Oversized {
+	n_factors: usize,
+	max_size_log: usize,
+}
+
+impl Structured for Oversized {
+	fn expr(&self) -> Result<ArithExpr<B128>, Error> {
+		Ok((0..self.n_factors)
+			.map(|i| ArithExpr::Var(i % self.max_size_log) + ArithExpr::Const(B128::new(i as u128)))
+			.product())
+	}
+
+	fn max_size_log(&self) -> usize {
+		self.max_size_log
+	}
+}
+
+#[test]
+fn test_oversized_structured_column_hits_circuit_limit() {
+	let mut cs = ConstraintSystem::new();
+	cs.max_structured_circuit_steps = Some(1 << 10);
+	let mut table = cs.add_table("oversized");
+	table.require_power_of_two_size();
+	table.add_structured::<B32>(
+		"oversized",
+		Oversized {
+			n_factors: 1 << 12,
+			max_size_log: 8,
+		},
+	);
+	drop(table);
+
+	assert_matches!(
+		cs.compile(),
+		Err(builder::Error::Math(MathError::CircuitTooLarge { max_steps })) if max_steps == 1 << 10
+	);
+
+	// The same column compiles without a limit.
+	cs.max_structured_circuit_steps = None;
+	assert!(cs.compile().is_ok());
+}
+
+/// A column that reports the cost of its expression, which is too large to be built.
+#[derive(Debug)]
+struct Costly {
+	n_muls: usize,
+}
+
+impl Structured for Costly {
+	fn expr(&self) -> Result<ArithExpr<B128>, Error> {
+		panic!("the expression of a column over the circuit limit must not be built")
+	}
+
+	fn max_size_log(&self) -> usize {
+		8
+	}
+
+	fn circuit_cost(&self) -> Option<StructuredCost> {
+		Some(StructuredCost {
+			degree: 1,
+			n_muls: self.n_muls,
+			has_closed_form: true,
+		})
+	}
+}
+
+#[test]
+fn test_structured_circuit_cost_checked_before_expr() {
+	let mut cs = ConstraintSystem::new();
+	cs.max_structured_circuit_steps = Some(1 << 10);
+	let mut table = cs.add_table("costly");
+	table.require_power_of_two_size();
+	table.add_structured::<B32>("costly", Costly { n_muls: 1 << 40 });
+	drop(table);
+
+	assert_matches!(
+		cs.compile(),
+		Err(builder::Error::Math(MathError::CircuitTooLarge { max_steps })) if max_steps == 1 << 10
+	);
+}
+
+/// A column whose expression embeds a sub-expression over more variables than it allocates.
+#[derive(Debug)]
+struct Misembedded;
+
+impl Structured for Misembedded {
+	fn expr(&self) -> Result<ArithExpr<B128>, Error> {
+		let mut vars = VarAllocator::new();
+		vars.embed(2, &(ArithExpr::Var(0) * ArithExpr::Var(3)))
+	}
+
+	fn max_size_log(&self) -> usize {
+		4
+	}
+}
+
+/// A column whose expression fails with a math error propagated with `?`.
+#[derive(Debug)]
+struct Unevaluable;
+
+impl Structured for Unevaluable {
+	fn expr(&self) -> Result<ArithExpr<B128>, Error> {
+		// Three coefficients do not define a multilinear polynomial.
+		let expr = ArithExpr::from_multilinear_coeffs(&[B128::new(1); 3], 2)?;
+		Ok(expr)
+	}
+
+	fn max_size_log(&self) -> usize {
+		4
+	}
+}
+
+#[test]
+fn test_structured_math_error_context() {
+	let mut cs = ConstraintSystem::new();
+	let mut table = cs.add_table("errors");
+	table.require_power_of_two_size();
+	table.add_structured::<B32>("misembedded", Misembedded);
+	drop(table);
+
+	let err = cs.compile().unwrap_err();
+	assert_matches!(
+		&err,
+		builder::Error::Structured(Error::Math {
+			source: MathError::IncorrectArgumentLength { .. },
+			..
+		})
+	);
+	let message = err.to_string();
+	assert!(
+		message.contains(
+			"embedding of an expression over 2 variables at variable 0 of column \"misembedded\""
+		),
+		"{message}"
+	);
+
+	let mut cs = ConstraintSystem::new();
+	let mut table = cs.add_table("errors");
+	table.require_power_of_two_size();
+	table.add_structured::<B32>("unevaluable", Unevaluable);
+	drop(table);
+
+	let message = cs.compile().unwrap_err().to_string();
+	assert!(message.contains("the expression of column \"unevaluable\""), "{message}");
+}
+
+/// A column whose expression uses a variable beyond its maximum size.
+#[derive(Debug)]
+struct Overreaching;
+
+impl Structured for Overreaching {
+	fn expr(&self) -> Result<ArithExpr<B128>, Error> {
+		Ok(ArithExpr::Var(4))
+	}
+
+	fn max_size_log(&self) -> usize {
+		4
+	}
+}
+
+#[test]
+fn test_structured_expression_beyond_max_size_log() {
+	let mut cs = ConstraintSystem::new();
+	let mut table = cs.add_table("overreaching");
+	table.require_power_of_two_size();
+	table.add_structured::<B32>("overreaching", Overreaching);
+	drop(table);
+
+	assert_matches!(
+		cs.compile(),
+		Err(builder::Error::Oracle(OracleError::StructuredTooManyVariables {
+			n_vars: 5,
+			max_n_vars: 4
+		}))
+	);
+}
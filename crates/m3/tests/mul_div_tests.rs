@@ -361,7 +361,7 @@ fn test_mul_next_to_stacked_col() {
 	let mut cs = ConstraintSystem::new();
 	let mut table = cs.add_table("test");
 	let table_id = table.id();
-	let _stacked_col = table.add_committed::<B32, 2>("dummy");
+	let stacked_col = table.add_committed::<B32, 2>("dummy");
 	let mul = MulUU32::new(&mut table.with_namespace("mul1"));
 
 	let mut rng = StdRng::seed_from_u64(0);
@@ -379,6 +379,7 @@ fn test_mul_next_to_stacked_col() {
 	witness
 		.fill_table_sequential(
 			&ClosureFiller::new(table_id, |events, witness| {
+				witness.fill_zero(stacked_col)?;
 				mul.populate_with_inputs(
 					witness,
 					events.iter().map(|(a, _)| B32::new(*a)),
@@ -0,0 +1,88 @@
+// Copyright 2025 Irreducible Inc.
+
+//! A table whose columns are declared by a struct deriving [`TableSchema`].
+
+use binius_compute::cpu::alloc::CpuComputeAllocator;
+use binius_field::{arch::OptimalUnderlier128b, as_packed_field::PackedType};
+use binius_m3::{
+	builder::{
+		B1, B32, B128, Col, ConstraintSystem, TableSchema, WitnessIndex,
+		test_utils::{ClosureFiller, validate_system_witness},
+	},
+	gadgets::structured::fill_incrementing_b32,
+};
+
+#[derive(TableSchema)]
+struct IndexBits {
+	#[structured(Incrementing { max_size_log: 20 })]
+	index: Col<B32>,
+	value: Col<B32>,
+	bits: Col<B1, 32>,
+}
+
+/// A schema that names the path of binius_m3 explicitly, as a crate with a renamed dependency
+/// would.
+#[derive(TableSchema)]
+#[table_schema(crate = binius_m3)]
+struct Value {
+	value: Col<B32>,
+}
+
+#[test]
+fn test_table_from_schema() {
+	let mut cs = ConstraintSystem::new();
+	let mut table = cs.add_table("index bits");
+	table.require_power_of_two_size();
+	let table_id = table.id();
+	let cols = IndexBits::add_columns(&mut table);
+	let packed = table.add_packed::<_, 32, B32, 1>("packed bits", cols.bits);
+	table.assert_eq("value = index", cols.value(), cols.index());
+	table.assert_eq("packed bits = value", packed, cols.value);
+	drop(table);
+
+	let table = cs.tables.iter().find(|table| table.id == table_id).unwrap();
+	let column_names = table
+		.columns
+		.iter()
+		.map(|col| col.name.as_str())
+		.collect::<Vec<_>>();
+	assert_eq!(column_names[..3], ["index", "value", "bits"]);
+
+	let mut allocator = CpuComputeAllocator::new(1 << 12);
+	let allocator = allocator.into_bump_allocator();
+	let mut witness = WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+	witness
+		.fill_table_sequential(
+			&ClosureFiller::new(table_id, |events: &[u32], index| {
+				fill_incrementing_b32(index, cols.index)?;
+				index
+					.get_mut_as::<u32, _, 1>(cols.value)?
+					.copy_from_slice(events);
+				index
+					.get_mut_as::<u32, _, 32>(cols.bits)?
+					.copy_from_slice(events);
+				index
+					.get_mut_as::<u32, _, 1>(packed)?
+					.copy_from_slice(events);
+				Ok(())
+			}),
+			&(0..1 << 6).collect::<Vec<u32>>(),
+		)
+		.unwrap();
+
+	validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+}
+
+#[test]
+fn test_table_schema_with_crate_path() {
+	let mut cs = ConstraintSystem::<B128>::new();
+	let mut table = cs.add_table("value");
+	let table_id = table.id();
+	let cols = Value::add_columns(&mut table);
+	drop(table);
+
+	let table = cs.tables.iter().find(|table| table.id == table_id).unwrap();
+	assert_eq!(table.columns.len(), 1);
+	assert_eq!(table.columns[0].id, cols.value().id());
+	assert_eq!(table.columns[0].name, "value");
+}
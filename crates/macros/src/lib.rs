@@ -258,6 +258,114 @@ pub fn derive_deserialize_bytes(input: TokenStream) -> TokenStream {
 	.into()
 }
 
+/// Derives the trait binius_m3::builder::TableSchema for a struct of table columns
+///
+/// Every field must be a column, e.g. `Col<B32>` or `Col<B1, 32>`. A field is a committed column
+/// named after the field, unless it has a `#[structured(...)]` attribute holding a
+/// `StructuredDynSize` variant, e.g. `#[structured(Incrementing { max_size_log: 20 })]`, which
+/// makes it a structured column. Every field also gets an accessor method of the same name that
+/// returns the column with its type.
+///
+/// The generated code refers to binius_m3 through its hidden `__private` re-exports at
+/// `::binius_m3`. A crate that depends on binius_m3 under another name passes its path with
+/// `#[table_schema(crate = path::to::binius_m3)]`.
+///
+/// See the TableSchema trait docs for an example.
+#[proc_macro_derive(TableSchema, attributes(structured, table_schema))]
+pub fn derive_table_schema(input: TokenStream) -> TokenStream {
+	let input: DeriveInput = parse_macro_input!(input);
+	let span = input.span();
+	let name = input.ident;
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+	let mut krate: syn::Path = parse_quote!(::binius_m3);
+	for attr in input
+		.attrs
+		.iter()
+		.filter(|attr| attr.path().is_ident("table_schema"))
+	{
+		let parsed = attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("crate") {
+				krate = meta.value()?.parse()?;
+				Ok(())
+			} else {
+				Err(meta.error("expected `table_schema(crate = path)`"))
+			}
+		});
+		if let Err(e) = parsed {
+			return e.into_compile_error().into();
+		}
+	}
+	let private = quote!(#krate::__private);
+	let fields = match input.data {
+		Data::Struct(data) => match data.fields {
+			Fields::Named(fields) => fields.named,
+			_ => {
+				return syn::Error::new(span, "TableSchema requires named fields")
+					.into_compile_error()
+					.into();
+			}
+		},
+		_ => {
+			return syn::Error::new(span, "TableSchema can only be derived for structs")
+				.into_compile_error()
+				.into();
+		}
+	};
+	let columns = fields
+		.iter()
+		.map(|field| {
+			let ident = field.ident.as_ref().expect("the fields are named");
+			let col_name = ident.to_string();
+			match field
+				.attrs
+				.iter()
+				.find(|attr| attr.path().is_ident("structured"))
+			{
+				Some(attr) => {
+					let variant = &attr.meta.require_list()?.tokens;
+					Ok(quote! {
+						#ident: table.add_structured(
+							#col_name,
+							#private::StructuredDynSize::#variant,
+						)
+					})
+				}
+				None => Ok(quote!(#ident: table.add_committed(#col_name))),
+			}
+		})
+		.collect::<Result<Vec<_>, syn::Error>>();
+	let columns = match columns {
+		Ok(columns) => columns,
+		Err(e) => return e.into_compile_error().into(),
+	};
+	let accessors = fields.iter().map(|field| {
+		let ident = field.ident.as_ref().expect("the fields are named");
+		let ty = &field.ty;
+		let doc = format!("Returns the `{ident}` column.");
+		quote! {
+			#[doc = #doc]
+			#[allow(dead_code)]
+			pub fn #ident(&self) -> #ty {
+				self.#ident
+			}
+		}
+	});
+	quote! {
+		impl #impl_generics #private::TableSchema for #name #ty_generics #where_clause {
+			fn add_columns(table: &mut #private::TableBuilder) -> Self {
+				Self {
+					#(#columns,)*
+				}
+			}
+		}
+
+		impl #impl_generics #name #ty_generics #where_clause {
+			#(#accessors)*
+		}
+	}
+	.into()
+}
+
 /// Use on an impl block for MultivariatePoly, to automatically implement erased_serialize_bytes.
 ///
 /// Importantly, this will serialize the concrete instance, prefixed by the identifier of the data
@@ -42,6 +42,12 @@ impl<F: Field + Display> Display for ArithExpr<F> {
 }
 
 impl<F: Field> ArithExpr<F> {
+	/// Raises the expression to a constant power.
+	///
+	/// The power is kept as a single [`ArithCircuitStep::Pow`] step when lowered into an
+	/// [`ArithCircuit`], which is evaluated with the square-and-multiply method. This is preferable
+	/// to building a chain of multiplications, e.g. `x.pow(8)` takes three squarings instead of
+	/// seven multiplications.
 	pub fn pow(self, exp: u64) -> Self {
 		Self::Pow(Arc::new(self), exp)
 	}
@@ -53,6 +59,64 @@ impl<F: Field> ArithExpr<F> {
 	pub const fn one() -> Self {
 		Self::Const(F::ONE)
 	}
+
+	/// Builds the multilinear polynomial with the given coefficients.
+	///
+	/// The coefficient at index `i` weights the monomial that is the product of the variables in
+	/// the bits of `i`, so there must be `2^n_vars` coefficients. The monomials with a zero
+	/// coefficient are omitted, hence the expression does not refer to the variables that only
+	/// appear in such monomials.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::IncorrectArgumentLength`] if there are not `2^n_vars` coefficients.
+	pub fn from_multilinear_coeffs(coeffs: &[F], n_vars: usize) -> Result<Self, Error> {
+		if n_vars >= usize::BITS as usize || coeffs.len() != 1 << n_vars {
+			return Err(Error::IncorrectArgumentLength {
+				arg: "coeffs".into(),
+				expected: 1usize.checked_shl(n_vars as u32).unwrap_or(usize::MAX),
+			});
+		}
+		Ok(coeffs
+			.iter()
+			.enumerate()
+			.filter(|&(_, &coeff)| coeff != F::ZERO)
+			.map(|(i, &coeff)| {
+				(0..n_vars)
+					.filter(|var| (i >> var) & 1 == 1)
+					.fold(Self::Const(coeff), |monomial, var| monomial * Self::Var(var))
+			})
+			.sum())
+	}
+
+	/// Counts the additions, multiplications and constants the expression takes to evaluate.
+	///
+	/// The expression is counted in its lowered form, the [`ArithCircuit`] it converts into, so a
+	/// sub-expression shared with `Arc` is counted once. Multiplications by constants are
+	/// multiplications, and a power is counted as the squarings and multiplications of the
+	/// square-and-multiply method, see [`ArithCircuit::eval_cost`].
+	pub fn op_counts(&self) -> OpCounts {
+		ArithCircuit::from(self).op_counts()
+	}
+
+	/// Converts the expression to an expression over another field.
+	///
+	/// The structure of the expression is preserved and every constant is mapped with the `From`
+	/// conversion, which for binary tower fields is the subfield embedding. Hence the converted
+	/// expression evaluated at an embedded point equals the embedding of the original evaluation.
+	pub fn map_field<G: Field + From<F>>(&self) -> ArithExpr<G> {
+		match self {
+			Self::Const(value) => ArithExpr::Const((*value).into()),
+			Self::Var(index) => ArithExpr::Var(*index),
+			Self::Add(left, right) => {
+				ArithExpr::Add(Arc::new(left.map_field()), Arc::new(right.map_field()))
+			}
+			Self::Mul(left, right) => {
+				ArithExpr::Mul(Arc::new(left.map_field()), Arc::new(right.map_field()))
+			}
+			Self::Pow(base, exp) => ArithExpr::Pow(Arc::new(base.map_field()), *exp),
+		}
+	}
 }
 
 impl<F> Default for ArithExpr<F>
@@ -331,6 +395,23 @@ impl<F: Field> ArithCircuit<F> {
 			})
 	}
 
+	/// Counts the additions, multiplications and constants in the circuit.
+	///
+	/// Unlike [`Self::eval_cost`], squarings are counted as multiplications, and the constant
+	/// steps are counted as well.
+	pub fn op_counts(&self) -> OpCounts {
+		let cost = self.eval_cost();
+		OpCounts {
+			adds: cost.n_adds,
+			muls: cost.n_muls + cost.n_squares,
+			consts: self
+				.steps
+				.iter()
+				.filter(|step| matches!(step, ArithCircuitStep::Const(_)))
+				.count(),
+		}
+	}
+
 	/// Return a new arithmetic expression that contains only the terms of highest degree
 	/// (useful for interpolation at Karatsuba infinity point).
 	pub fn leading_term(&self) -> Self {
@@ -382,6 +463,74 @@ impl<F: Field> ArithCircuit<F> {
 		Ok(step_evals.pop().unwrap_or_default())
 	}
 
+	/// Evaluates the circuit at a point that may have fewer coordinates than the circuit has
+	/// variables.
+	///
+	/// Variables with an index beyond the end of `query` are assigned zero, so this is equivalent
+	/// to calling [`Self::evaluate`] with `query` padded with zeros to [`Self::n_vars`]
+	/// coordinates. This is useful when a sub-expression over the low variables of a table is
+	/// evaluated at a point that only specifies those variables.
+	pub fn evaluate_partial(&self, query: &[F]) -> F {
+		let mut step_evals = Vec::<F>::with_capacity(self.steps.len());
+		for step in &self.steps {
+			let eval = match step {
+				ArithCircuitStep::Add(left, right) => step_evals[*left] + step_evals[*right],
+				ArithCircuitStep::Mul(left, right) => step_evals[*left] * step_evals[*right],
+				ArithCircuitStep::Pow(base, exp) => step_evals[*base].pow(*exp),
+				ArithCircuitStep::Const(value) => *value,
+				ArithCircuitStep::Var(index) => query.get(*index).copied().unwrap_or(F::ZERO),
+			};
+			step_evals.push(eval);
+		}
+		step_evals.pop().unwrap_or_default()
+	}
+
+	/// Evaluates the circuit at each of several points.
+	///
+	/// The circuit is traversed once, and every step is evaluated at all the points before the
+	/// next one. This is equivalent to calling [`Self::evaluate`] for every point, but avoids
+	/// repeating the traversal, e.g. when a verifier evaluates a constraint at several
+	/// independent challenges.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::IncorrectQuerySize`] if a point has fewer coordinates than the circuit has
+	///   variables.
+	pub fn evaluate_at_points(&self, points: &[Vec<F>]) -> Result<Vec<F>, Error> {
+		let n_vars = self.n_vars();
+		if let Some(point) = points.iter().find(|point| point.len() < n_vars) {
+			return Err(Error::IncorrectQuerySize {
+				expected: n_vars,
+				actual: point.len(),
+			});
+		}
+
+		let n_points = points.len();
+		// The evaluations of step `i` at all the points are at `i * n_points..(i + 1) * n_points`.
+		let mut step_evals = Vec::<F>::with_capacity(self.steps.len() * n_points);
+		for step in &self.steps {
+			for j in 0..n_points {
+				let eval = match step {
+					ArithCircuitStep::Add(left, right) => {
+						step_evals[*left * n_points + j] + step_evals[*right * n_points + j]
+					}
+					ArithCircuitStep::Mul(left, right) => {
+						step_evals[*left * n_points + j] * step_evals[*right * n_points + j]
+					}
+					ArithCircuitStep::Pow(base, exp) => step_evals[*base * n_points + j].pow(*exp),
+					ArithCircuitStep::Const(value) => *value,
+					ArithCircuitStep::Var(index) => points[j][*index],
+				};
+				step_evals.push(eval);
+			}
+		}
+
+		if self.steps.is_empty() {
+			return Ok(vec![F::default(); n_points]);
+		}
+		Ok(step_evals.split_off(step_evals.len() - n_points))
+	}
+
 	pub fn convert_field<FTgt: Field + From<F>>(&self) -> ArithCircuit<FTgt> {
 		ArithCircuit {
 			steps: self
@@ -699,65 +848,92 @@ impl<F: Field> ArithCircuit<F> {
 
 impl<F: Field> From<&ArithExpr<F>> for ArithCircuit<F> {
 	fn from(expr: &ArithExpr<F>) -> Self {
+		Self::from_expr_with_limit(expr, usize::MAX)
+			.expect("the number of steps cannot exceed usize::MAX")
+	}
+}
+
+impl<F: Field> ArithCircuit<F> {
+	/// Converts an expression into a circuit with at most `max_steps` steps.
+	///
+	/// This is the same conversion as [`ArithCircuit::from`], which shares the steps of the
+	/// sub-expressions that are shared with `Arc`. The conversion stops as soon as the limit is
+	/// exceeded, so that an unexpectedly large expression, e.g. a user-defined structured column,
+	/// is rejected before the circuit takes up all of the memory.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::CircuitTooLarge`] if the circuit has more than `max_steps` steps.
+	pub fn from_expr_with_limit(expr: &ArithExpr<F>, max_steps: usize) -> Result<Self, Error> {
+		fn push_step<F: Field>(
+			step: ArithCircuitStep<F>,
+			steps: &mut Vec<ArithCircuitStep<F>>,
+			max_steps: usize,
+		) -> Result<usize, Error> {
+			if steps.len() >= max_steps {
+				return Err(Error::CircuitTooLarge { max_steps });
+			}
+			steps.push(step);
+			Ok(steps.len() - 1)
+		}
+
 		fn visit_node<F: Field>(
 			node: &Arc<ArithExpr<F>>,
 			node_to_index: &mut HashMap<*const ArithExpr<F>, usize>,
 			steps: &mut Vec<ArithCircuitStep<F>>,
-		) -> usize {
+			max_steps: usize,
+		) -> Result<usize, Error> {
 			if let Some(index) = node_to_index.get(&Arc::as_ptr(node)) {
-				return *index;
+				return Ok(*index);
 			}
 
 			let step = match &**node {
 				ArithExpr::Const(value) => ArithCircuitStep::Const(*value),
 				ArithExpr::Var(index) => ArithCircuitStep::Var(*index),
 				ArithExpr::Add(left, right) => {
-					let left = visit_node(left, node_to_index, steps);
-					let right = visit_node(right, node_to_index, steps);
+					let left = visit_node(left, node_to_index, steps, max_steps)?;
+					let right = visit_node(right, node_to_index, steps, max_steps)?;
 					ArithCircuitStep::Add(left, right)
 				}
 				ArithExpr::Mul(left, right) => {
-					let left = visit_node(left, node_to_index, steps);
-					let right = visit_node(right, node_to_index, steps);
+					let left = visit_node(left, node_to_index, steps, max_steps)?;
+					let right = visit_node(right, node_to_index, steps, max_steps)?;
 					ArithCircuitStep::Mul(left, right)
 				}
 				ArithExpr::Pow(base, exp) => {
-					let base = visit_node(base, node_to_index, steps);
+					let base = visit_node(base, node_to_index, steps, max_steps)?;
 					ArithCircuitStep::Pow(base, *exp)
 				}
 			};
 
-			steps.push(step);
-			node_to_index.insert(Arc::as_ptr(node), steps.len() - 1);
-			steps.len() - 1
+			let index = push_step(step, steps, max_steps)?;
+			node_to_index.insert(Arc::as_ptr(node), index);
+			Ok(index)
 		}
 
 		let mut steps = Vec::new();
 		let mut node_to_index = HashMap::new();
-		match expr {
-			ArithExpr::Const(c) => {
-				steps.push(ArithCircuitStep::Const(*c));
-			}
-			ArithExpr::Var(var) => {
-				steps.push(ArithCircuitStep::Var(*var));
-			}
+		let step = match expr {
+			ArithExpr::Const(c) => ArithCircuitStep::Const(*c),
+			ArithExpr::Var(var) => ArithCircuitStep::Var(*var),
 			ArithExpr::Add(left, right) => {
-				let left = visit_node(left, &mut node_to_index, &mut steps);
-				let right = visit_node(right, &mut node_to_index, &mut steps);
-				steps.push(ArithCircuitStep::Add(left, right));
+				let left = visit_node(left, &mut node_to_index, &mut steps, max_steps)?;
+				let right = visit_node(right, &mut node_to_index, &mut steps, max_steps)?;
+				ArithCircuitStep::Add(left, right)
 			}
 			ArithExpr::Mul(left, right) => {
-				let left = visit_node(left, &mut node_to_index, &mut steps);
-				let right = visit_node(right, &mut node_to_index, &mut steps);
-				steps.push(ArithCircuitStep::Mul(left, right));
+				let left = visit_node(left, &mut node_to_index, &mut steps, max_steps)?;
+				let right = visit_node(right, &mut node_to_index, &mut steps, max_steps)?;
+				ArithCircuitStep::Mul(left, right)
 			}
 			ArithExpr::Pow(base, exp) => {
-				let base = visit_node(base, &mut node_to_index, &mut steps);
-				steps.push(ArithCircuitStep::Pow(base, *exp));
+				let base = visit_node(base, &mut node_to_index, &mut steps, max_steps)?;
+				ArithCircuitStep::Pow(base, *exp)
 			}
-		}
+		};
+		push_step(step, &mut steps, max_steps)?;
 
-		Self { steps }
+		Ok(Self { steps })
 	}
 }
 
@@ -1146,6 +1322,17 @@ impl EvalCost {
 	}
 }
 
+/// The number of operations of each type in a circuit, as returned by [`ArithExpr::op_counts`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OpCounts {
+	/// Number of additions.
+	pub adds: usize,
+	/// Number of multiplications, including squarings and multiplications by constants.
+	pub muls: usize,
+	/// Number of constants.
+	pub consts: usize,
+}
+
 impl Add for EvalCost {
 	type Output = Self;
 	fn add(self, other: Self) -> Self {
@@ -1162,7 +1349,9 @@ mod tests {
 	use std::collections::HashSet;
 
 	use assert_matches::assert_matches;
-	use binius_field::{BinaryField, BinaryField1b, BinaryField8b, BinaryField128b};
+	use binius_field::{
+		BinaryField, BinaryField1b, BinaryField8b, BinaryField32b, BinaryField128b, ExtensionField,
+	};
 	use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
 
 	use super::*;
@@ -1180,6 +1369,35 @@ mod tests {
 		assert_eq!(expr.degree(), 14);
 	}
 
+	#[test]
+	fn test_from_multilinear_coeffs() {
+		type F = BinaryField8b;
+		let coeffs = (0..8).map(|i| F::new(3 * i + 1)).collect::<Vec<_>>();
+		let circuit = ArithCircuit::from(ArithExpr::from_multilinear_coeffs(&coeffs, 3).unwrap());
+		let point = [F::new(2), F::new(7), F::new(11)];
+		let expected = coeffs
+			.iter()
+			.enumerate()
+			.map(|(i, &coeff)| {
+				(0..3)
+					.filter(|var| (i >> var) & 1 == 1)
+					.fold(coeff, |acc, var| acc * point[var])
+			})
+			.sum::<F>();
+		assert_eq!(circuit.evaluate(&point).unwrap(), expected);
+
+		// The zero coefficients are omitted.
+		let sparse = [F::ZERO, F::new(5), F::ZERO, F::ZERO];
+		assert_eq!(
+			ArithExpr::from_multilinear_coeffs(&sparse, 2).unwrap(),
+			ArithExpr::Const(F::new(5)) * ArithExpr::Var(0)
+		);
+		assert_matches!(
+			ArithExpr::from_multilinear_coeffs(&sparse, 3),
+			Err(Error::IncorrectArgumentLength { expected: 8, .. })
+		);
+	}
+
 	#[test]
 	fn test_n_vars() {
 		type F = BinaryField8b;
@@ -1206,6 +1424,29 @@ mod tests {
 		assert_eq!(expr.leading_term_with_degree(expr.steps().len() - 1), (3, expected_expr));
 	}
 
+	#[test]
+	fn test_from_expr_with_limit() {
+		type F = BinaryField128b;
+		// The sum of 128 weighted variables, like the incrementing expression over 128 bits.
+		let expr = (0..128)
+			.map(|i| {
+				ArithExpr::Var(i) * ArithExpr::Const(<F as ExtensionField<BinaryField1b>>::basis(i))
+			})
+			.sum::<ArithExpr<F>>();
+		let circuit = ArithCircuit::from(&expr);
+
+		let n_steps = circuit.steps().len();
+		assert_eq!(ArithCircuit::from_expr_with_limit(&expr, n_steps).unwrap(), circuit);
+		assert_matches!(
+			ArithCircuit::from_expr_with_limit(&expr, 100),
+			Err(Error::CircuitTooLarge { max_steps: 100 })
+		);
+		assert_matches!(
+			ArithCircuit::from_expr_with_limit(&expr, n_steps - 1),
+			Err(Error::CircuitTooLarge { .. })
+		);
+	}
+
 	#[test]
 	fn test_remap_vars_with_too_few_vars() {
 		type F = BinaryField8b;
@@ -1532,4 +1773,104 @@ mod tests {
 			.unwrap();
 		assert_eq!(result, F::new(2) + F::new(3) * (F::new(4) + F::new(5)).pow(5));
 	}
+
+	#[test]
+	fn test_pow_matches_repeated_mul() {
+		type F = BinaryField8b;
+		let pow_expr = ArithCircuit::from(ArithExpr::<F>::Var(0).pow(5));
+		let mul_expr = ArithCircuit::from(
+			ArithExpr::Var(0)
+				* ArithExpr::Var(0)
+				* ArithExpr::Var(0)
+				* ArithExpr::Var(0)
+				* ArithExpr::Var(0),
+		);
+		for i in 0..=255 {
+			let query = [F::new(i)];
+			assert_eq!(pow_expr.evaluate(&query).unwrap(), mul_expr.evaluate(&query).unwrap());
+		}
+
+		// x^5 = (x^2)^2 * x
+		let cost = pow_expr.eval_cost();
+		assert_eq!(cost.n_squares, 2);
+		assert_eq!(cost.n_muls, 1);
+
+		// x^8 = ((x^2)^2)^2
+		let cost = ArithCircuit::from(ArithExpr::<F>::Var(0).pow(8)).eval_cost();
+		assert_eq!(cost.n_squares, 3);
+		assert_eq!(cost.n_muls, 0);
+	}
+
+	#[test]
+	fn test_op_counts() {
+		type F = BinaryField32b;
+
+		// The incrementing expression over n variables has a constant weight per variable.
+		let n_vars = 10;
+		let incrementing = (0..n_vars)
+			.map(|i| {
+				ArithExpr::Var(i) * ArithExpr::Const(<F as ExtensionField<BinaryField1b>>::basis(i))
+			})
+			.sum::<ArithExpr<F>>();
+		assert_eq!(
+			incrementing.op_counts(),
+			OpCounts {
+				adds: n_vars - 1,
+				muls: n_vars,
+				consts: n_vars,
+			}
+		);
+
+		// The AND of bits is the product of the variables.
+		let and = (0..4).map(ArithExpr::<F>::Var).product::<ArithExpr<F>>();
+		assert_eq!(
+			and.op_counts(),
+			OpCounts {
+				adds: 0,
+				muls: 3,
+				consts: 0,
+			}
+		);
+
+		// A shared sub-expression is counted once, and x^5 takes two squarings and a
+		// multiplication.
+		let shared = Arc::new(ArithExpr::<F>::Var(0) + ArithExpr::Var(1));
+		let expr = ArithExpr::Mul(shared.clone(), shared) + ArithExpr::Var(2).pow(5);
+		assert_eq!(
+			expr.op_counts(),
+			OpCounts {
+				adds: 2,
+				muls: 4,
+				consts: 0,
+			}
+		);
+	}
+
+	#[test]
+	fn test_map_field_to_extension() {
+		type F = BinaryField32b;
+		type G = BinaryField128b;
+
+		// The incrementing expression over 5 variables, plus a non-linear term.
+		let incrementing = (0..5)
+			.map(|i| {
+				ArithExpr::Var(i) * ArithExpr::Const(<F as ExtensionField<BinaryField1b>>::basis(i))
+			})
+			.sum::<ArithExpr<F>>();
+		let expr = incrementing + (ArithExpr::Var(0) * ArithExpr::Var(3)).pow(3);
+		let mapped = expr.map_field::<G>();
+
+		let expr = ArithCircuit::from(&expr);
+		let mapped = ArithCircuit::from(&mapped);
+		for i in 0..1 << 5 {
+			let point = (0..5)
+				.map(|j| if (i >> j) & 1 == 1 { F::ONE } else { F::ZERO })
+				.collect::<Vec<_>>();
+			let embedded_point = point.iter().copied().map(G::from).collect::<Vec<_>>();
+			assert_eq!(
+				mapped.evaluate(&embedded_point).unwrap(),
+				G::from(expr.evaluate(&point).unwrap())
+			);
+		}
+	}
 }